@@ -0,0 +1,166 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::common::{impl_extensions, Extensions};
+
+/// A postal address.
+///
+/// An address has up to six street lines, written as `street`, `street2`, ..,
+/// `street6`.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    DeserializeXml,
+    SerializeXml,
+)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+pub struct Address {
+    #[xml(text_element)]
+    city: Option<String>,
+    #[xml(text_element)]
+    country: Option<String>,
+    #[xml(text_element)]
+    postal_code: Option<String>,
+    #[xml(text_element)]
+    state_or_province: Option<String>,
+    #[xml(text_element)]
+    street: Option<String>,
+    #[xml(text_element)]
+    street2: Option<String>,
+    #[xml(text_element)]
+    street3: Option<String>,
+    #[xml(text_element)]
+    street4: Option<String>,
+    #[xml(text_element)]
+    street5: Option<String>,
+    #[xml(text_element)]
+    street6: Option<String>,
+    /// The whole address as a single value.
+    #[xml(text_element)]
+    value: Option<String>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
+}
+
+impl_extensions!(Address);
+
+impl Address {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+// Builder lite
+impl Address {
+    pub fn city<S: Into<String>>(mut self, city: S) -> Self {
+        self.set_city(Some(city.into()));
+        self
+    }
+
+    pub fn country<S: Into<String>>(mut self, country: S) -> Self {
+        self.set_country(Some(country.into()));
+        self
+    }
+
+    pub fn postal_code<S: Into<String>>(mut self, postal_code: S) -> Self {
+        self.set_postal_code(Some(postal_code.into()));
+        self
+    }
+
+    pub fn state_or_province<S: Into<String>>(mut self, state_or_province: S) -> Self {
+        self.set_state_or_province(Some(state_or_province.into()));
+        self
+    }
+
+    /// Adds a street line after the existing ones, see
+    /// [`add_street`](Self::add_street). Lines after the sixth are ignored.
+    pub fn street<S: Into<String>>(mut self, street: S) -> Self {
+        self.add_street(street.into());
+        self
+    }
+
+    pub fn value<S: Into<String>>(mut self, value: S) -> Self {
+        self.set_value(Some(value.into()));
+        self
+    }
+}
+
+impl Address {
+    pub fn set_city(&mut self, city: Option<String>) {
+        self.city = city;
+    }
+    pub fn set_country(&mut self, country: Option<String>) {
+        self.country = country;
+    }
+    pub fn set_postal_code(&mut self, postal_code: Option<String>) {
+        self.postal_code = postal_code;
+    }
+    pub fn set_state_or_province(&mut self, state_or_province: Option<String>) {
+        self.state_or_province = state_or_province;
+    }
+    /// Sets the first free street line.
+    ///
+    /// Returns `false`, leaving the address as it is, if all six street lines
+    /// are already set.
+    pub fn add_street(&mut self, street: String) -> bool {
+        match self
+            .street_lines_mut()
+            .into_iter()
+            .find(|line| line.is_none())
+        {
+            Some(line) => {
+                *line = Some(street);
+                true
+            }
+            None => false,
+        }
+    }
+    pub fn set_value(&mut self, value: Option<String>) {
+        self.value = value;
+    }
+
+    pub fn get_city(&self) -> Option<&str> {
+        self.city.as_deref()
+    }
+    pub fn get_country(&self) -> Option<&str> {
+        self.country.as_deref()
+    }
+    pub fn get_postal_code(&self) -> Option<&str> {
+        self.postal_code.as_deref()
+    }
+    pub fn get_state_or_province(&self) -> Option<&str> {
+        self.state_or_province.as_deref()
+    }
+    /// The street lines that are set, in order.
+    pub fn streets(&self) -> impl Iterator<Item = &str> {
+        [
+            &self.street,
+            &self.street2,
+            &self.street3,
+            &self.street4,
+            &self.street5,
+            &self.street6,
+        ]
+        .into_iter()
+        .filter_map(|line| line.as_deref())
+    }
+    pub fn get_value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    fn street_lines_mut(&mut self) -> [&mut Option<String>; 6] {
+        [
+            &mut self.street,
+            &mut self.street2,
+            &mut self.street3,
+            &mut self.street4,
+            &mut self.street5,
+            &mut self.street6,
+        ]
+    }
+}
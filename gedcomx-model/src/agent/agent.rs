@@ -1,28 +1,84 @@
 use std::fmt;
 
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, Event};
+use deserx::{DeserializeXml, SerializeXml};
 
 use crate::{
-    common::{IriRef, ResourceReference, TextValue},
-    ser::{xml, SerError, SerializeXml},
+    common::{
+        identifier_map, impl_extensions, Extensions, Identifier, IriRef, ResourceReference,
+        TextValue,
+    },
     Result,
 };
 
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+use super::{Address, OnlineAccount};
+
+/// Someone or something that curates genealogical data, e.g. a contributor or
+/// a repository.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(order(
+    identifiers,
+    emails,
+    names,
+    homepage,
+    openid,
+    accounts,
+    phones,
+    addresses,
+    person
+))]
 pub struct Agent {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        with = "identifier_map"
+    )]
+    #[xml(rename = "identifier")]
+    identifiers: Vec<Identifier>,
+    #[xml(rename = "name")]
     names: Vec<TextValue>,
+    homepage: Option<ResourceReference>,
+    openid: Option<ResourceReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "account")]
+    accounts: Vec<OnlineAccount>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "email")]
     emails: Vec<ResourceReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "phone")]
+    phones: Vec<ResourceReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "address")]
+    addresses: Vec<Address>,
+    /// The person that describes this agent.
+    person: Option<ResourceReference>,
+    #[xml(attribute)]
     id: IriRef,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(Agent);
+
 impl Agent {
     pub fn new(id: IriRef) -> Self {
         Self {
             id,
+            identifiers: Vec::new(),
             names: Vec::new(),
+            homepage: None,
+            openid: None,
+            accounts: Vec::new(),
             emails: Vec::new(),
+            phones: Vec::new(),
+            addresses: Vec::new(),
+            person: None,
+            extensions: Extensions::default(),
         }
     }
 }
@@ -49,6 +105,48 @@ impl Agent {
         ))?));
         Ok(self)
     }
+
+    pub fn identifier(mut self, identifier: Identifier) -> Self {
+        self.add_identifier(identifier);
+        self
+    }
+
+    pub fn homepage(mut self, homepage: IriRef) -> Self {
+        self.set_homepage(Some(ResourceReference::new(homepage)));
+        self
+    }
+
+    pub fn openid(mut self, openid: IriRef) -> Self {
+        self.set_openid(Some(ResourceReference::new(openid)));
+        self
+    }
+
+    pub fn account(mut self, account: OnlineAccount) -> Self {
+        self.add_account(account);
+        self
+    }
+
+    pub fn phone(mut self, phone: IriRef) -> Self {
+        self.add_phone(ResourceReference::new(phone));
+        self
+    }
+    pub fn try_phone(mut self, phone: impl fmt::Display) -> Result<Self> {
+        self.add_phone(ResourceReference::new(IriRef::parse(format!(
+            "tel:{}",
+            phone
+        ))?));
+        Ok(self)
+    }
+
+    pub fn address(mut self, address: Address) -> Self {
+        self.add_address(address);
+        self
+    }
+
+    pub fn person<R: Into<ResourceReference>>(mut self, person: R) -> Self {
+        self.set_person(Some(person.into()));
+        self
+    }
 }
 impl Agent {
     // pub fn set_id(&mut self, id: String) {
@@ -60,6 +158,27 @@ impl Agent {
     pub fn add_email(&mut self, email: ResourceReference) {
         self.emails.push(email);
     }
+    pub fn add_identifier(&mut self, identifier: Identifier) {
+        self.identifiers.push(identifier);
+    }
+    pub fn set_homepage(&mut self, homepage: Option<ResourceReference>) {
+        self.homepage = homepage;
+    }
+    pub fn set_openid(&mut self, openid: Option<ResourceReference>) {
+        self.openid = openid;
+    }
+    pub fn add_account(&mut self, account: OnlineAccount) {
+        self.accounts.push(account);
+    }
+    pub fn add_phone(&mut self, phone: ResourceReference) {
+        self.phones.push(phone);
+    }
+    pub fn add_address(&mut self, address: Address) {
+        self.addresses.push(address);
+    }
+    pub fn set_person(&mut self, person: Option<ResourceReference>) {
+        self.person = person;
+    }
     pub fn get_id(&self) -> &str {
         self.id.as_str()
     }
@@ -69,148 +188,31 @@ impl Agent {
     pub fn emails(&self) -> &[ResourceReference] {
         self.emails.as_slice()
     }
-}
-
-impl From<&Agent> for ResourceReference {
-    fn from(agent: &Agent) -> Self {
-        ResourceReference::new(agent.id.clone())
+    pub fn identifiers(&self) -> &[Identifier] {
+        self.identifiers.as_slice()
     }
-}
-
-pub fn verify_agents(aas: &[Agent], bs: &[Agent]) -> std::result::Result<(), String> {
-    if aas.len() != bs.len() {
-        return Err(format!("length mismatch: {} != {}", aas.len(), bs.len()));
+    pub fn get_homepage(&self) -> Option<&ResourceReference> {
+        self.homepage.as_ref()
     }
-    todo!()
-}
-impl SerializeXml for Agent {
-    fn tag(&self) -> &str {
-        "agent"
+    pub fn get_openid(&self) -> Option<&ResourceReference> {
+        self.openid.as_ref()
     }
-
-    fn serialize_xml<W: std::io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> std::result::Result<(), SerError> {
-        let mut elem = BytesStart::new(self.tag());
-        elem.push_attribute(("id", self.id.as_str()));
-        ser.write_event(Event::Start(elem))?;
-
-        for email in &self.emails {
-            xml::write_elem_w_attribute(ser, "email", ("resource", email.resource()))?;
-        }
-        for name in &self.names {
-            xml::write_elem_w_text(ser, "name", name.value())?;
-        }
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
+    pub fn accounts(&self) -> &[OnlineAccount] {
+        self.accounts.as_slice()
+    }
+    pub fn phones(&self) -> &[ResourceReference] {
+        self.phones.as_slice()
+    }
+    pub fn addresses(&self) -> &[Address] {
+        self.addresses.as_slice()
+    }
+    pub fn get_person(&self) -> Option<&ResourceReference> {
+        self.person.as_ref()
     }
 }
 
-impl DeserializeXml for Agent {
-    fn deserialize_xml_with_start<'de, R: std::io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-        start: &quick_xml::events::BytesStart<'de>,
-    ) -> std::result::Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
-        let attr = start.try_get_attribute("id")?;
-        let id: IriRef = if let Some(id) = attr {
-            IriRef::parse(id.unescape_value()?.into()).expect("iri")
-            // agent.set_contributor(ResourceReference::with_resource(
-            //     resource.unescape_value()?.into(),
-            // ));
-        } else {
-            todo!("handle no 'id'")
-        };
-        let mut agent = Self::new(id);
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Empty(e) => {
-                    log::debug!("read Empty={:?}", e);
-                    match e.name().as_ref() {
-                        b"analysis" => {
-                            let attr = e.try_get_attribute("resource")?;
-                            if let Some(_value) = attr {
-                                // agent.set_analysis(DocumentReference::new(
-                                //     value.unescape_value()?.into(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"email" => {
-                            let attr = e.try_get_attribute("resource")?;
-                            if let Some(value) = attr {
-                                agent.add_email(ResourceReference::new(
-                                    IriRef::parse(value.unescape_value()?.into()).expect("iri"),
-                                ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"gender" => {
-                            let attr = e.try_get_attribute("type")?;
-                            if let Some(_value) = attr {
-                                // agent.set_gender(Gender::from_qname_uri(
-                                //     value.unescape_value()?.as_ref(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"source" => {
-                            let attr = e.try_get_attribute("description")?;
-                            if let Some(_source) = attr {
-                                // agent.add_source(SourceReference::new(
-                                //     Uri::new(source.unescape_value()?.to_string()),
-                                //     String::new(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::Start(e) => {
-                    log::debug!("read Start={:?}", e);
-                    match e.name().as_ref() {
-                        b"citation" => {
-                            log::trace!("found 'fact'");
-                            // let citation =
-                            //     SourceCitation::deserialize_xml_with_start(deserializer, &e)?;
-                            // agent.add_citation(citation);
-                        }
-                        b"name" => {
-                            log::trace!("found 'name'");
-                            if let Event::Text(name_text) =
-                                deserializer.read_event_into(&mut buf)?
-                            {
-                                agent.add_name(name_text.unescape()?.as_ref().into());
-                            }
-                        }
-                        b"title" => {
-                            log::trace!("found 'title'");
-                            if let Event::Text(_e_title) = deserializer.read_event_into(&mut buf)? {
-                                // agent.add_title(e_title.unescape()?.into());
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::End(e) => match e.name().as_ref() {
-                    b"agent" => {
-                        log::trace!("found end of 'agent' returning ...");
-                        break;
-                    }
-                    _tag => log::trace!("skipping '{:?}' ...", e),
-                },
-                e => {
-                    log::trace!("got: {:?} skipping ...", e);
-                }
-            }
-        }
-        log::debug!("agent = {:?}", agent);
-        Ok(agent)
+impl From<&Agent> for ResourceReference {
+    fn from(agent: &Agent) -> Self {
+        ResourceReference::new(agent.id.clone())
     }
 }
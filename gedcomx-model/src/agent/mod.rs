@@ -1,3 +1,8 @@
+mod address;
+#[allow(clippy::module_inception)]
 mod agent;
+mod online_account;
 
+pub use address::Address;
 pub use agent::Agent;
+pub use online_account::OnlineAccount;
@@ -0,0 +1,41 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::common::{impl_extensions, Extensions, IriRef, ResourceReference};
+
+/// An account of an agent in an online service, e.g. a user name on a
+/// genealogy site.
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+#[xml(rename = "account")]
+pub struct OnlineAccount {
+    /// The home page of the service.
+    service_homepage: ResourceReference,
+    #[xml(text_element)]
+    account_name: String,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
+}
+
+impl_extensions!(OnlineAccount);
+
+impl OnlineAccount {
+    pub fn new<S: Into<String>>(service_homepage: IriRef, account_name: S) -> Self {
+        Self {
+            service_homepage: ResourceReference::new(service_homepage),
+            account_name: account_name.into(),
+            extensions: Extensions::default(),
+        }
+    }
+}
+
+impl OnlineAccount {
+    pub fn get_service_homepage(&self) -> &ResourceReference {
+        &self.service_homepage
+    }
+    pub fn get_account_name(&self) -> &str {
+        self.account_name.as_str()
+    }
+}
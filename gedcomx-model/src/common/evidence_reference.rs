@@ -1,14 +1,16 @@
-use quick_xml::events::{BytesStart, Event};
+use deserx::{DeserializeXml, SerializeXml};
 
 use crate::common::IriRef;
-use crate::ser::SerializeXml;
-
 
 use super::Reference;
 
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[xml(rename = "evidence")]
 pub struct EvidenceReference {
     #[serde(rename = "resource", flatten)]
+    #[xml(flatten)]
     reference: Reference,
 }
 
@@ -30,20 +32,3 @@ impl EvidenceReference {
         self.reference.resource()
     }
 }
-
-impl SerializeXml for EvidenceReference {
-    fn tag(&self) -> &str {
-        "evidence"
-    }
-
-    fn serialize_xml<W: std::io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> std::result::Result<(), crate::ser::SerError> {
-        let mut elem = BytesStart::new(self.tag());
-        elem.push_attribute(("resource", self.resource()));
-        ser.write_event(Event::Empty(elem))?;
-        // ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
-    }
-}
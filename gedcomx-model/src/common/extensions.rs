@@ -0,0 +1,267 @@
+use deserx::{AsXmlExtensions, XmlExtensions};
+use serde::ser::{self, Error as _, Impossible, SerializeMap};
+use serde_json::{Map, Value};
+
+/// Data that is not part of GEDCOM X, e.g. vendor extensions, kept so that
+/// reading and writing a document does not drop it.
+///
+/// Unknown XML attributes and elements are kept as XML, unknown JSON fields
+/// as JSON, each is only written back in its own format.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Extensions {
+    xml: XmlExtensions,
+    json: Map<String, Value>,
+}
+
+impl Extensions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn xml(&self) -> &XmlExtensions {
+        &self.xml
+    }
+    pub fn xml_mut(&mut self) -> &mut XmlExtensions {
+        &mut self.xml
+    }
+    pub fn json(&self) -> &Map<String, Value> {
+        &self.json
+    }
+    pub fn json_mut(&mut self) -> &mut Map<String, Value> {
+        &mut self.json
+    }
+    pub fn is_empty(&self) -> bool {
+        self.xml.is_empty() && self.json.is_empty()
+    }
+}
+
+impl AsXmlExtensions for Extensions {
+    fn as_xml_extensions(&self) -> &XmlExtensions {
+        &self.xml
+    }
+    fn as_xml_extensions_mut(&mut self) -> &mut XmlExtensions {
+        &mut self.xml
+    }
+}
+
+/// Written as the unknown JSON fields, see `impl_extensions!`.
+impl serde::Serialize for Extensions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.json.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Extensions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Self {
+            xml: XmlExtensions::new(),
+            json: Map::deserialize(deserializer)?,
+        })
+    }
+}
+
+/// Implements the `extensions()` and `extensions_mut()` accessors and serde
+/// for types with an `extensions` field, which must be
+/// `#[serde(flatten, skip_serializing)]` in a type with
+/// `#[serde(remote = "Self")]`.
+///
+/// A type is written as a struct, as if it had no extensions, unless it has
+/// JSON extensions, which are then written after its fields as one map. This
+/// keeps the output of serializers that tell structs and maps apart, e.g.
+/// quick-xml's, the same for the types without extensions.
+macro_rules! impl_extensions {
+    ($name:ident) => {
+        impl $name {
+            /// Data that is not part of GEDCOM X, see
+            /// [`Extensions`]($crate::common::Extensions).
+            pub fn extensions(&self) -> &$crate::common::Extensions {
+                &self.extensions
+            }
+            pub fn extensions_mut(&mut self) -> &mut $crate::common::Extensions {
+                &mut self.extensions
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                match self.extensions.json() {
+                    json if json.is_empty() => $name::serialize(self, serializer),
+                    json => $name::serialize(self, $crate::common::WithJson::new(serializer, json)),
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                $name::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_extensions;
+
+/// Writes a struct or map with `json` as extra fields after its own.
+pub(crate) struct WithJson<'a, S> {
+    serializer: S,
+    json: &'a Map<String, Value>,
+}
+
+impl<'a, S> WithJson<'a, S> {
+    pub(crate) fn new(serializer: S, json: &'a Map<String, Value>) -> Self {
+        Self { serializer, json }
+    }
+}
+
+/// The methods for everything but structs and maps, which cannot have
+/// extensions.
+macro_rules! unsupported {
+    ($($method:ident($($arg:ty),*) -> $ok:ty,)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$ok, S::Error> {
+                Err(S::Error::custom("only a struct or a map can have extensions"))
+            }
+        )*
+    };
+}
+
+impl<'a, S: ser::Serializer> ser::Serializer for WithJson<'a, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Impossible<S::Ok, S::Error>;
+    type SerializeTuple = Impossible<S::Ok, S::Error>;
+    type SerializeTupleStruct = Impossible<S::Ok, S::Error>;
+    type SerializeTupleVariant = Impossible<S::Ok, S::Error>;
+    type SerializeMap = WithJsonMap<'a, S::SerializeMap>;
+    type SerializeStruct = WithJsonMap<'a, S::SerializeMap>;
+    type SerializeStructVariant = Impossible<S::Ok, S::Error>;
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        Ok(WithJsonMap {
+            map: self.serializer.serialize_map(None)?,
+            json: self.json,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        self.serialize_map(Some(len))
+    }
+
+    unsupported! {
+        serialize_bool(bool) -> S::Ok,
+        serialize_i8(i8) -> S::Ok,
+        serialize_i16(i16) -> S::Ok,
+        serialize_i32(i32) -> S::Ok,
+        serialize_i64(i64) -> S::Ok,
+        serialize_u8(u8) -> S::Ok,
+        serialize_u16(u16) -> S::Ok,
+        serialize_u32(u32) -> S::Ok,
+        serialize_u64(u64) -> S::Ok,
+        serialize_f32(f32) -> S::Ok,
+        serialize_f64(f64) -> S::Ok,
+        serialize_char(char) -> S::Ok,
+        serialize_str(&str) -> S::Ok,
+        serialize_bytes(&[u8]) -> S::Ok,
+        serialize_none() -> S::Ok,
+        serialize_unit() -> S::Ok,
+        serialize_unit_struct(&'static str) -> S::Ok,
+        serialize_unit_variant(&'static str, u32, &'static str) -> S::Ok,
+        serialize_seq(Option<usize>) -> Self::SerializeSeq,
+        serialize_tuple(usize) -> Self::SerializeTuple,
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct,
+        serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant,
+        serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant,
+    }
+
+    fn serialize_some<T: ?Sized + ser::Serialize>(self, _value: &T) -> Result<S::Ok, S::Error> {
+        Err(S::Error::custom(
+            "only a struct or a map can have extensions",
+        ))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + ser::Serialize>(
+        self,
+        _name: &'static str,
+        _value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        Err(S::Error::custom(
+            "only a struct or a map can have extensions",
+        ))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + ser::Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        Err(S::Error::custom(
+            "only a struct or a map can have extensions",
+        ))
+    }
+}
+
+pub(crate) struct WithJsonMap<'a, M> {
+    map: M,
+    json: &'a Map<String, Value>,
+}
+
+impl<M: SerializeMap> WithJsonMap<'_, M> {
+    fn end_with_json(mut self) -> Result<M::Ok, M::Error> {
+        for (key, value) in self.json {
+            self.map.serialize_entry(key, value)?;
+        }
+        self.map.end()
+    }
+}
+
+impl<M: SerializeMap> SerializeMap for WithJsonMap<'_, M> {
+    type Ok = M::Ok;
+    type Error = M::Error;
+
+    fn serialize_key<T: ?Sized + ser::Serialize>(&mut self, key: &T) -> Result<(), M::Error> {
+        self.map.serialize_key(key)
+    }
+
+    fn serialize_value<T: ?Sized + ser::Serialize>(&mut self, value: &T) -> Result<(), M::Error> {
+        self.map.serialize_value(value)
+    }
+
+    fn end(self) -> Result<M::Ok, M::Error> {
+        self.end_with_json()
+    }
+}
+
+impl<M: SerializeMap> ser::SerializeStruct for WithJsonMap<'_, M> {
+    type Ok = M::Ok;
+    type Error = M::Error;
+
+    fn serialize_field<T: ?Sized + ser::Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        self.map.serialize_entry(key, value)
+    }
+
+    fn end(self) -> Result<M::Ok, M::Error> {
+        self.end_with_json()
+    }
+}
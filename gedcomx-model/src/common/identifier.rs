@@ -0,0 +1,131 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::{
+    common::{QnameUri, Uri},
+    types::IdentifierType,
+};
+
+/// An identifier of a resource, e.g. its id in FamilySearch or in another
+/// database.
+///
+/// In XML every identifier is an element,
+/// `<identifier type="http://gedcomx.org/Primary">..</identifier>`. In JSON the
+/// identifiers of a resource are one object from type to values, see
+/// [`identifier_map`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, DeserializeXml, SerializeXml)]
+pub struct Identifier {
+    #[xml(attribute)]
+    r#type: Option<IdentifierType>,
+    #[xml(text)]
+    value: Uri,
+}
+
+impl Identifier {
+    pub fn new(value: Uri) -> Self {
+        Self {
+            r#type: None,
+            value,
+        }
+    }
+}
+
+// Builder lite
+impl Identifier {
+    pub fn r#type(mut self, r#type: IdentifierType) -> Self {
+        self.set_type(Some(r#type));
+        self
+    }
+}
+
+impl Identifier {
+    pub fn set_type(&mut self, r#type: Option<IdentifierType>) {
+        self.r#type = r#type;
+    }
+
+    pub fn get_type(&self) -> Option<&IdentifierType> {
+        self.r#type.as_ref()
+    }
+    pub fn get_value(&self) -> &Uri {
+        &self.value
+    }
+
+    /// Whether `self` has the value of `other`, and its type if `other` has
+    /// one.
+    pub fn matches(&self, other: &Identifier) -> bool {
+        self.value == other.value && (other.r#type.is_none() || self.r#type == other.r#type)
+    }
+}
+
+/// Serde for a list of identifiers in the JSON form, an object from identifier
+/// type to values where identifiers without a type are under `$`:
+///
+/// ```json
+/// {"http://gedcomx.org/Primary": ["https://example.org/P-1"], "$": ["12345"]}
+/// ```
+///
+/// Use with `#[serde(with = "identifier_map")]`.
+pub mod identifier_map {
+    use std::fmt;
+
+    use serde::{
+        de::{MapAccess, Visitor},
+        ser::SerializeMap,
+        Deserializer, Serializer,
+    };
+
+    use super::*;
+
+    const UNTYPED: &str = "$";
+
+    pub fn serialize<S: Serializer>(
+        identifiers: &[Identifier],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // Types are written in the order they first appear.
+        let mut by_type: Vec<(&str, Vec<&str>)> = Vec::new();
+        for identifier in identifiers {
+            let key = identifier
+                .r#type
+                .as_ref()
+                .map_or(UNTYPED, QnameUri::as_qname_uri);
+            let value = identifier.value.as_str();
+            match by_type.iter_mut().find(|(r#type, _)| *r#type == key) {
+                Some((_, values)) => values.push(value),
+                None => by_type.push((key, vec![value])),
+            }
+        }
+        let mut map = serializer.serialize_map(Some(by_type.len()))?;
+        for (key, values) in &by_type {
+            map.serialize_entry(key, values)?;
+        }
+        map.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Identifier>, D::Error> {
+        deserializer.deserialize_map(IdentifierMapVisitor)
+    }
+
+    struct IdentifierMapVisitor;
+
+    impl<'de> Visitor<'de> for IdentifierMapVisitor {
+        type Value = Vec<Identifier>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map from identifier type to values")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut identifiers = Vec::new();
+            while let Some((key, values)) = map.next_entry::<String, Vec<String>>()? {
+                let r#type = (key != UNTYPED).then(|| IdentifierType::from_qname_uri(&key));
+                identifiers.extend(values.into_iter().map(|value| Identifier {
+                    r#type: r#type.clone(),
+                    value: Uri::new(value),
+                }));
+            }
+            Ok(identifiers)
+        }
+    }
+}
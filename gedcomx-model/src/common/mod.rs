@@ -1,12 +1,23 @@
 mod evidence_reference;
+mod extensions;
+mod identifier;
+mod note;
 mod qname_uri;
+mod qualifier;
 mod reference;
 mod resource_reference;
 mod text_value;
 mod uri;
 
 pub use evidence_reference::EvidenceReference;
+pub use extensions::Extensions;
+pub(crate) use extensions::{impl_extensions, WithJson};
+pub use identifier::{identifier_map, Identifier};
+pub use note::Note;
+pub(crate) use qname_uri::vocabulary;
 pub use qname_uri::QnameUri;
+pub use qualifier::Qualifier;
+pub(crate) use reference::refers_to;
 pub use reference::Reference;
 pub use resource_reference::ResourceReference;
 pub use text_value::TextValue;
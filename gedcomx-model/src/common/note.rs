@@ -0,0 +1,91 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::common::{impl_extensions, Extensions};
+use crate::gedcomx::Attribution;
+
+/// A note about a genealogical resource, e.g. a research log entry.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(order(subject, text, attribution))]
+pub struct Note {
+    #[xml(attribute, rename = "xml:lang")]
+    lang: Option<String>,
+    /// A short summary of the note.
+    #[xml(text_element)]
+    subject: Option<String>,
+    #[xml(text_element)]
+    text: String,
+    attribution: Option<Attribution>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
+}
+
+impl_extensions!(Note);
+
+impl Note {
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            lang: None,
+            subject: None,
+            text: text.into(),
+            attribution: None,
+            extensions: Extensions::default(),
+        }
+    }
+}
+
+// Builder lite
+impl Note {
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn subject<S: Into<String>>(mut self, subject: S) -> Self {
+        self.set_subject(Some(subject.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.set_attribution(Some(attribution));
+        self
+    }
+}
+
+impl Note {
+    pub fn set_lang(&mut self, lang: Option<String>) {
+        self.lang = lang;
+    }
+    pub fn set_subject(&mut self, subject: Option<String>) {
+        self.subject = subject;
+    }
+    pub fn set_text(&mut self, text: String) {
+        self.text = text;
+    }
+    pub fn set_attribution(&mut self, attribution: Option<Attribution>) {
+        self.attribution = attribution;
+    }
+
+    pub fn get_lang(&self) -> Option<&str> {
+        self.lang.as_deref()
+    }
+    pub fn get_subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+    pub fn get_text(&self) -> &str {
+        self.text.as_str()
+    }
+    pub fn get_attribution(&self) -> Option<&Attribution> {
+        self.attribution.as_ref()
+    }
+}
+
+impl<S: Into<String>> From<S> for Note {
+    fn from(text: S) -> Self {
+        Self::new(text)
+    }
+}
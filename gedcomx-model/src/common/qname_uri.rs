@@ -1,15 +1,169 @@
-pub trait QnameUri {
+/// A value of a GEDCOM X vocabulary, identified by a URI such as
+/// `http://gedcomx.org/Birth`.
+///
+/// URIs outside the standard vocabulary are kept as they are, so reading and
+/// writing a value never changes it.
+pub trait QnameUri: Sized {
     fn as_qname_uri(&self) -> &str;
+    fn from_qname_uri(qname_uri: &str) -> Self;
 }
 
-// impl<T: QnameUri> serde::Serialize for T {
-//     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-//     where
-//         S: serde::Serializer,
-//     {
-//         use serde::ser::SerializeMap;
-//         let mut map = serializer.serialize_map(Some(1))?;
-//         map.serialize_entry("type", self.as_qname_uri())?;
-//         map.end()
-//     }
-// }
+/// Defines a vocabulary enum from its variants and their URIs, with a
+/// `Custom(Uri)` variant for everything else.
+///
+/// The enum implements [`QnameUri`], `FromStr` and `Display`, and is written
+/// as its URI in XML attributes and in JSON.
+macro_rules! vocabulary {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $uri:literal,
+            )*
+        }
+    ) => {
+        $crate::common::vocabulary!(@enum $(#[$meta])* $name { $($(#[$variant_meta])* $variant = $uri,)* });
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str($crate::common::QnameUri::as_qname_uri(self))
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let qname_uri = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                Ok($crate::common::QnameUri::from_qname_uri(&qname_uri))
+            }
+        }
+
+        impl deserx::XmlValue for $name {
+            fn from_xml_value(value: &str) -> deserx::Result<Self> {
+                Ok($crate::common::QnameUri::from_qname_uri(value))
+            }
+
+            fn to_xml_value(&self) -> std::borrow::Cow<'_, str> {
+                $crate::common::QnameUri::as_qname_uri(self).into()
+            }
+        }
+    };
+    // The enum itself, without serialization.
+    (
+        @enum
+        $(#[$meta:meta])*
+        $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $uri:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A URI outside the standard vocabulary.
+            Custom($crate::common::Uri),
+        }
+
+        impl $name {
+            /// The standard values of the vocabulary.
+            pub const STANDARD: &'static [$name] = &[$($name::$variant,)*];
+        }
+
+        impl $crate::common::QnameUri for $name {
+            fn as_qname_uri(&self) -> &str {
+                match self {
+                    $(Self::$variant => $uri,)*
+                    Self::Custom(uri) => uri.as_str(),
+                }
+            }
+
+            fn from_qname_uri(qname_uri: &str) -> Self {
+                match qname_uri {
+                    $($uri => Self::$variant,)*
+                    _ => Self::Custom($crate::common::Uri::new(qname_uri.into())),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok($crate::common::QnameUri::from_qname_uri(s))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str($crate::common::QnameUri::as_qname_uri(self))
+            }
+        }
+    };
+}
+
+pub(crate) use vocabulary;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Uri;
+    use crate::types::{
+        ConfidenceLevel, DocumentType, EventRoleType, EventType, FactQualifierType, FactType,
+        GenderType, IdentifierType, NamePartQualifierType, NamePartType, NameType,
+        RelationshipType, ResourceType, SourceReferenceQualifierType,
+    };
+
+    fn assert_round_trips<T: QnameUri + PartialEq + std::fmt::Debug>(standard: &[T]) {
+        for value in standard {
+            assert!(value.as_qname_uri().starts_with("http://gedcomx.org/"));
+            assert_eq!(&T::from_qname_uri(value.as_qname_uri()), value);
+        }
+    }
+
+    #[test]
+    fn standard_values_map_both_ways() {
+        assert_round_trips(DocumentType::STANDARD);
+        assert_round_trips(EventType::STANDARD);
+        assert_round_trips(EventRoleType::STANDARD);
+        assert_round_trips(FactType::STANDARD);
+        assert_round_trips(FactQualifierType::STANDARD);
+        assert_round_trips(GenderType::STANDARD);
+        assert_round_trips(ConfidenceLevel::STANDARD);
+        assert_round_trips(IdentifierType::STANDARD);
+        assert_round_trips(NameType::STANDARD);
+        assert_round_trips(NamePartType::STANDARD);
+        assert_round_trips(NamePartQualifierType::STANDARD);
+        assert_round_trips(RelationshipType::STANDARD);
+        assert_round_trips(ResourceType::STANDARD);
+        assert_round_trips(SourceReferenceQualifierType::STANDARD);
+    }
+
+    #[test]
+    fn unknown_uris_are_kept_as_custom() {
+        let uri = "http://example.org/Baptism";
+        let fact_type: FactType = uri.parse().unwrap();
+        assert_eq!(fact_type, FactType::Custom(Uri::new(uri.into())));
+        assert_eq!(fact_type.to_string(), uri);
+        assert_eq!(
+            serde_json::to_string(&fact_type).unwrap(),
+            format!("{:?}", uri)
+        );
+        assert_eq!(deserx::XmlValue::to_xml_value(&fact_type), uri);
+        assert_eq!(
+            serde_json::from_str::<FactType>(&format!("{:?}", uri)).unwrap(),
+            fact_type
+        );
+    }
+}
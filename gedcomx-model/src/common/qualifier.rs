@@ -0,0 +1,66 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::common::{QnameUri, Uri};
+
+/// Adds detail to a value, e.g. that a given name is the primary one.
+///
+/// The name is a URI of the vocabulary for the context of the qualifier, e.g.
+/// [`FactQualifierType`](crate::types::FactQualifierType) for facts, some
+/// qualifiers also have a value. Names outside the vocabulary are kept as they
+/// are.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+pub struct Qualifier {
+    #[xml(attribute)]
+    name: Uri,
+    #[xml(text)]
+    value: Option<String>,
+}
+
+impl Qualifier {
+    pub fn new(name: Uri) -> Self {
+        Self { name, value: None }
+    }
+}
+
+// Builder lite
+impl Qualifier {
+    pub fn value<S: Into<String>>(mut self, value: S) -> Self {
+        self.set_value(Some(value.into()));
+        self
+    }
+}
+
+impl Qualifier {
+    pub fn set_value(&mut self, value: Option<String>) {
+        self.value = value;
+    }
+
+    pub fn get_name(&self) -> &Uri {
+        &self.name
+    }
+
+    pub fn get_value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Whether the name of this qualifier is `name`.
+    pub fn is<Q: QnameUri>(&self, name: &Q) -> bool {
+        self.name.as_str() == name.as_qname_uri()
+    }
+
+    /// The name of this qualifier in the vocabulary `Q`.
+    pub fn name_as<Q: QnameUri>(&self) -> Q {
+        Q::from_qname_uri(self.name.as_str())
+    }
+}
+
+/// A qualifier named by a vocabulary value, e.g.
+/// `Qualifier::from(FactQualifierType::Age).value("23")`.
+impl<Q: QnameUri> From<Q> for Qualifier {
+    fn from(name: Q) -> Self {
+        Self::new(Uri::new(name.as_qname_uri().into()))
+    }
+}
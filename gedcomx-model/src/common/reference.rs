@@ -1,10 +1,16 @@
+use deserx::{DeserializeXml, SerializeXml};
+
 use crate::common::IriRef;
 use crate::Result;
 
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
 pub struct Reference {
+    #[xml(attribute)]
     resource: IriRef,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[xml(attribute)]
     resource_id: Option<String>,
 }
 
@@ -31,4 +37,16 @@ impl Reference {
     pub fn resource(&self) -> &str {
         self.resource.as_str()
     }
+    pub fn iri(&self) -> &IriRef {
+        &self.resource
+    }
+}
+
+/// Whether `resource` refers to the item with `id`, either by the full id or,
+/// for a reference within the same document like `#P-1`, by fragment.
+///
+/// A fragment of another document, e.g. `http://example.org/tree#P-1`, does
+/// not refer to a local item.
+pub(crate) fn refers_to(resource: &IriRef, id: &str) -> bool {
+    resource.as_str() == id || resource.as_str().strip_prefix('#') == Some(id)
 }
@@ -1,8 +1,12 @@
+use deserx::{DeserializeXml, SerializeXml};
+
 use crate::common::{IriRef, Reference};
 use crate::Result;
 
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
-pub struct ResourceReference(Reference);
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+pub struct ResourceReference(#[xml(flatten)] Reference);
 
 impl ResourceReference {
     pub fn new(iri: IriRef) -> Self {
@@ -20,4 +24,7 @@ impl ResourceReference {
     pub fn resource(&self) -> &str {
         self.0.resource()
     }
+    pub fn iri(&self) -> &IriRef {
+        self.0.iri()
+    }
 }
@@ -1,7 +1,13 @@
+use deserx::{DeserializeXml, SerializeXml};
+
 #[serde_with::skip_serializing_none]
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
 pub struct TextValue {
+    #[xml(text)]
     value: String,
+    #[xml(attribute, rename = "xml:lang")]
     lang: Option<String>,
 }
 
@@ -11,7 +17,7 @@ impl TextValue {
     }
 
     pub fn lang(&self) -> Option<&str> {
-        self.lang.as_ref().map(|s| s.as_str())
+        self.lang.as_deref()
     }
 }
 
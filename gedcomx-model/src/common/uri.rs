@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
 pub struct Uri(String);
 
 impl Uri {
@@ -10,3 +10,13 @@ impl Uri {
         self.0.as_str()
     }
 }
+
+impl deserx::XmlValue for Uri {
+    fn from_xml_value(value: &str) -> deserx::Result<Self> {
+        Ok(Self::new(value.into()))
+    }
+
+    fn to_xml_value(&self) -> std::borrow::Cow<'_, str> {
+        self.as_str().into()
+    }
+}
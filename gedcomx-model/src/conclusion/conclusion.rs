@@ -1,17 +1,31 @@
+use deserx::{DeserializeXml, SerializeXml};
+
 use crate::{
-    common::{ResourceReference},
-    ser::{xml, SerError, SerializeXml},
+    common::{Note, ResourceReference},
+    gedcomx::Attribution,
     source::SourceReference,
+    types::ConfidenceLevel,
 };
 
-use std::io;
-
+/// The fields shared by all conclusions.
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[xml(order(attribution, sources, analysis, notes))]
 pub struct Conclusion {
+    #[xml(attribute)]
+    confidence: Option<ConfidenceLevel>,
+    #[xml(attribute, rename = "xml:lang")]
+    lang: Option<String>,
+    attribution: Option<Attribution>,
     analysis: Option<ResourceReference>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "source")]
     sources: Vec<SourceReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "note")]
+    notes: Vec<Note>,
     // gender: Option<Gender>,
     // names: Vec<Name>,
     // facts: Vec<Fact>,
@@ -20,8 +34,12 @@ pub struct Conclusion {
 impl Conclusion {
     pub fn new() -> Self {
         Self {
+            confidence: None,
+            lang: None,
+            attribution: None,
             analysis: None,
             sources: Vec::new(),
+            notes: Vec::new(),
             // id: String::new(),
             // gender: None,
             // names: Vec::new(),
@@ -38,6 +56,26 @@ impl Default for Conclusion {
 
 // Builder lite
 impl Conclusion {
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.add_note(note.into());
+        self
+    }
+
     // pub fn extracted(mut self, yes: bool) -> Self {
     //     self.extracted = yes;
     //     self
@@ -77,22 +115,35 @@ impl Conclusion {
     pub fn add_source(&mut self, source: SourceReference) {
         self.sources.push(source);
     }
-}
-impl SerializeXml for Conclusion {
-    fn tag(&self) -> &str {
-        "conclusion"
+    pub fn set_confidence(&mut self, confidence: Option<ConfidenceLevel>) {
+        self.confidence = confidence;
+    }
+    pub fn set_lang(&mut self, lang: Option<String>) {
+        self.lang = lang;
+    }
+    pub fn set_attribution(&mut self, attribution: Option<Attribution>) {
+        self.attribution = attribution;
+    }
+    pub fn add_note(&mut self, note: Note) {
+        self.notes.push(note);
     }
 
-    fn serialize_xml<W: io::Write>(&self, ser: &mut quick_xml::Writer<W>) -> Result<(), SerError> {
-        for source in &self.sources {
-            source.serialize_xml(ser)?;
-        }
-        if let Some(analysis) = self.analysis.as_ref() {
-            xml::write_elem_w_attribute(ser, "analysis", ("resource", analysis.resource()))?;
-        }
-        // for evidence in &self.e3xvidence {
-        //     evidence.serialize_xml(ser)?;
-        // }
-        Ok(())
+    pub fn get_analysis(&self) -> Option<&ResourceReference> {
+        self.analysis.as_ref()
+    }
+    pub fn sources(&self) -> &[SourceReference] {
+        self.sources.as_slice()
+    }
+    pub fn get_confidence(&self) -> Option<&ConfidenceLevel> {
+        self.confidence.as_ref()
+    }
+    pub fn get_lang(&self) -> Option<&str> {
+        self.lang.as_deref()
+    }
+    pub fn get_attribution(&self) -> Option<&Attribution> {
+        self.attribution.as_ref()
+    }
+    pub fn notes(&self) -> &[Note] {
+        self.notes.as_slice()
     }
 }
@@ -1,28 +1,56 @@
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, Event};
+use std::cmp::Ordering;
 
-use crate::ser::{xml, SerializeXml};
+use deserx::{DeserializeXml, SerializeXml};
 
-/// A name conclusion
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+use crate::common::{impl_extensions, Extensions};
+use crate::date::{DateNormalizer, FormalDate, NormalizeError};
+
+/// A date conclusion
+#[serde_with::skip_serializing_none]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
 pub struct Date {
+    #[xml(text_element, default, skip_serializing_if = "String::is_empty")]
     original: String,
+    /// The standardized date, see [`crate::date`].
+    #[xml(text_element)]
+    formal: Option<FormalDate>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(Date);
+
 impl Date {
     pub fn new() -> Self {
         Self {
             original: String::new(),
+            formal: None,
+            extensions: Extensions::default(),
         }
     }
 }
 
+impl Default for Date {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Builder lite
 impl Date {
     pub fn original<S: Into<String>>(mut self, original: S) -> Self {
         self.set_original(original.into());
         self
     }
+
+    pub fn formal<F: Into<FormalDate>>(mut self, formal: F) -> Self {
+        self.set_formal(Some(formal.into()));
+        self
+    }
 }
 
 impl Date {
@@ -32,106 +60,38 @@ impl Date {
     pub fn get_original(&self) -> &str {
         self.original.as_str()
     }
-}
+    pub fn set_formal(&mut self, formal: Option<FormalDate>) {
+        self.formal = formal;
+    }
+    pub fn get_formal(&self) -> Option<&FormalDate> {
+        self.formal.as_ref()
+    }
 
-impl<S: Into<String>> From<S> for Date {
-    fn from(s: S) -> Self {
-        Date::new().original(s)
+    /// Sets the formal date from `original`, see [`DateNormalizer`].
+    ///
+    /// The formal date is left unchanged if `original` cannot be normalized.
+    pub fn normalize(&mut self) -> Result<&FormalDate, NormalizeError> {
+        self.normalize_with(&DateNormalizer::new())
     }
-}
-impl SerializeXml for Date {
-    fn tag(&self) -> &str {
-        "date"
+    pub fn normalize_with(
+        &mut self,
+        normalizer: &DateNormalizer,
+    ) -> Result<&FormalDate, NormalizeError> {
+        let formal = normalizer.normalize(&self.original)?;
+        Ok(self.formal.insert(formal))
     }
-    fn serialize_xml<W: std::io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> Result<(), crate::ser::SerError> {
-        let elem = BytesStart::new(self.tag());
-        ser.write_event(Event::Start(elem))?;
 
-        if !self.original.is_empty() {
-            xml::write_elem_w_text(ser, "original", &self.original)?;
+    /// Orders dates by their formal value, dates without one come last.
+    pub fn chronological_cmp(&self, other: &Date) -> Ordering {
+        match (&self.formal, &other.formal) {
+            (Some(formal), Some(other)) => formal.chronological_cmp(other),
+            (formal, other) => formal.is_none().cmp(&other.is_none()),
         }
-        // for name_form in &self.name_forms {
-        //     name_form.serialize_xml(ser)?;
-        // }
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
     }
 }
 
-impl DeserializeXml for Date {
-    fn deserialize_xml_with_start<'de, R: std::io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-        _start: &quick_xml::events::BytesStart<'de>,
-    ) -> Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
-        let mut date = Self::new();
-        // let attr = start.try_get_attribute("extracted")?;
-        // let extracted = if let Some(extracted) = attr {
-        //     match extracted.unescape_value()?.as_ref() {
-        //         "true" | "1" => true,
-        //         _ => false,
-        //     }
-        // } else {
-        //     false
-        // };
-        // date.set_extracted(extracted);
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Empty(e) => {
-                    log::debug!("read Empty={:?}", e);
-                    match e.name().as_ref() {
-                        b"gender" => {
-                            let attr = e.try_get_attribute("type")?;
-                            if let Some(_value) = attr {
-                                // date.set_gender(Gender::from_qdate_uri(
-                                //     value.unescape_value()?.as_ref(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"source" => {
-                            let attr = e.try_get_attribute("description")?;
-                            if let Some(_source) = attr {
-                                // date.add_source(SourceReference::new(
-                                //     Uri::new(source.unescape_value()?.to_string()),
-                                //     String::new(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::Start(e) => {
-                    log::debug!("read Start={:?}", e);
-                    match e.name().as_ref() {
-                        b"original" => {
-                            log::trace!("found 'original'");
-                            if let Event::Text(e_text) = deserializer.read_event_into(&mut buf)? {
-                                date.set_original(e_text.unescape()?.into());
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::End(e) => match e.name().as_ref() {
-                    b"date" => {
-                        log::trace!("found end of 'date' returning ...");
-                        break;
-                    }
-                    _tag => log::trace!("skipping '{:?}' ...", e),
-                },
-                e => {
-                    log::trace!("got: {:?} skipping ...", e);
-                }
-            }
-        }
-        log::debug!("date = {:?}", date);
-        Ok(date)
+impl<S: Into<String>> From<S> for Date {
+    fn from(s: S) -> Self {
+        Date::new().original(s)
     }
 }
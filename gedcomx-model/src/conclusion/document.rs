@@ -1,128 +1,245 @@
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use std::io;
+
+use deserx::{
+    de::DeserializeXmlFields,
+    ser::{SerError, SerializeXmlFields, XmlSerializer},
+    DeserializeXml, SerializeXml, XmlValue,
+};
+use quick_xml::events::BytesStart;
 
 use crate::{
-    common::{IriRef, ResourceReference},
-    ser::{SerializeXml},
+    common::{impl_extensions, Extensions, IriRef, Note, ResourceReference},
+    conclusion::Conclusion,
+    gedcomx::Attribution,
+    source::SourceReference,
+    types::{ConfidenceLevel, DocumentType, TextType},
 };
 
-/// An abstract document that contains derived (conclusionary) text -- for example, a transcription or researcher analysis.
+use super::subject::is_default;
 
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+/// An abstract document that contains derived (conclusionary) text -- for example, a transcription or researcher analysis.
+///
+/// XHTML text, see [`TextType::Xhtml`], is written as markup in XML rather
+/// than escaped, and read back as it is.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(order(conclusion, content))]
 pub struct Document {
-    text: String,
+    #[serde(flatten)]
+    #[xml(flatten)]
+    conclusion: Conclusion,
+    #[serde(flatten)]
+    #[xml(flatten)]
+    content: DocumentText,
+    #[xml(attribute)]
+    r#type: Option<DocumentType>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    #[xml(attribute, default, skip_serializing_if = "is_default")]
+    extracted: bool,
+    #[xml(attribute)]
     id: IriRef,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(Document);
+
 impl Document {
     pub fn new(id: IriRef) -> Self {
         Self {
+            conclusion: Conclusion::default(),
+            content: DocumentText::default(),
+            r#type: None,
+            extracted: false,
             id,
-            text: String::new(),
+            extensions: Extensions::default(),
         }
     }
 }
 
 // Builder lite
 impl Document {
-    // pub fn id<S: Into<String>>(mut self, id: S) -> Self {
-    //     self.set_id(id.into());
-    //     self
-    // }
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.conclusion.set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.conclusion.set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.conclusion.set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.conclusion.add_note(note.into());
+        self
+    }
+
+    pub fn source<S: Into<SourceReference>>(mut self, source: S) -> Self {
+        self.conclusion.add_source(source.into());
+        self
+    }
+
+    pub fn r#type(mut self, document_type: DocumentType) -> Self {
+        self.set_type(Some(document_type));
+        self
+    }
+
+    pub fn extracted(mut self, yes: bool) -> Self {
+        self.set_extracted(yes);
+        self
+    }
+
     pub fn text<S: Into<String>>(mut self, text: S) -> Self {
         self.set_text(text.into());
         self
     }
+
+    /// Sets XHTML text, which is written as markup in XML.
+    ///
+    /// Writing the document as XML fails if the text is not well-formed.
+    pub fn xhtml<S: Into<String>>(mut self, xhtml: S) -> Self {
+        self.set_text(xhtml.into());
+        self.set_text_type(Some(TextType::Xhtml));
+        self
+    }
 }
 
 impl Document {
-    // pub fn set_id(&mut self, id: String) {
-    //     self.id = id;
-    // }
+    pub fn conclusion(&self) -> &Conclusion {
+        &self.conclusion
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        &mut self.conclusion
+    }
+
+    pub fn set_type(&mut self, document_type: Option<DocumentType>) {
+        self.r#type = document_type;
+    }
+    pub fn set_extracted(&mut self, yes: bool) {
+        self.extracted = yes;
+    }
     pub fn set_text(&mut self, text: String) {
-        self.text = text;
+        self.content.text = text;
+    }
+    pub fn set_text_type(&mut self, text_type: Option<TextType>) {
+        self.content.text_type = text_type;
     }
-}
 
-// impl From<&str> for Document {
-//     fn from(s: &str) -> Self {
-//         Self::new().name_form(NameForm::new().full_text(s.into()))
-//     }
-// }
-
-impl SerializeXml for Document {
-    fn tag(&self) -> &str {
-        "document"
-    }
-    fn serialize_xml<W: std::io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> Result<(), crate::ser::SerError> {
-        let mut elem = BytesStart::new(self.tag());
-        elem.push_attribute(("id", self.id.as_str()));
-        ser.write_event(Event::Start(elem))?;
-
-        ser.write_event(Event::Start(BytesStart::new("text")))?;
-        ser.write_event(Event::Text(BytesText::from_escaped(self.text.as_str())))?;
-        ser.write_event(Event::End(BytesEnd::new("text")))?;
-        // xml::write_elem_w_text(ser, "text", self.text.as_str())?;
-
-        // for name_form in &self.name_forms {
-        //     name_form.serialize_xml(ser)?;
-        // }
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
+    pub fn id(&self) -> &IriRef {
+        &self.id
+    }
+    pub fn get_type(&self) -> Option<&DocumentType> {
+        self.r#type.as_ref()
+    }
+    pub fn is_extracted(&self) -> bool {
+        self.extracted
+    }
+    pub fn get_text(&self) -> &str {
+        self.content.text.as_str()
+    }
+    /// The type of the text, plain text if not given.
+    pub fn get_text_type(&self) -> TextType {
+        self.content.text_type.unwrap_or_default()
+    }
+    pub fn is_xhtml(&self) -> bool {
+        self.get_text_type() == TextType::Xhtml
     }
 }
 
-impl DeserializeXml for Document {
-    fn deserialize_xml_with_start<'de, R: std::io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-        start: &quick_xml::events::BytesStart<'de>,
-    ) -> Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
+/// The `textType` attribute and `text` element of a [`Document`], kept
+/// together since the text is written as markup when it is XHTML.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DocumentText {
+    text_type: Option<TextType>,
+    text: String,
+}
+
+#[derive(Default)]
+struct DocumentTextBuilder {
+    text_type: Option<TextType>,
+    text: Option<String>,
+}
+
+impl DeserializeXmlFields for DocumentText {
+    type Builder = DocumentTextBuilder;
 
-        let id = if let Some(id) = start.try_get_attribute("id")? {
-            IriRef::parse(id.unescape_value()?.into()).expect("iri")
+    fn deserialize_attribute(
+        builder: &mut Self::Builder,
+        name: &[u8],
+        value: &str,
+    ) -> deserx::Result<bool> {
+        if name != b"textType" {
+            return Ok(false);
+        }
+        builder.text_type = Some(TextType::from_xml_value(value)?);
+        Ok(true)
+    }
+
+    fn deserialize_child<R: io::BufRead>(
+        builder: &mut Self::Builder,
+        deserializer: &mut quick_xml::Reader<R>,
+        start: &BytesStart<'_>,
+        empty: bool,
+    ) -> deserx::Result<bool> {
+        if start.local_name().as_ref() != b"text" {
+            return Ok(false);
+        }
+        if builder.text.is_some() {
+            return Err(deserx::Error::unexpected_element(start.name()));
+        }
+        // Attributes are read before children, so the text type is known.
+        let text = if empty {
+            String::new()
+        } else if builder.text_type == Some(TextType::Xhtml) {
+            deserx::de::read_inner_xml(deserializer, start)?
         } else {
-            todo!("handle no id")
+            deserx::de::read_text(deserializer, start)?
         };
-        let mut document = Self::new(id);
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::End(e) => {
-                    log::trace!("got End={:?}", e);
-                    match e.name().as_ref() {
-                        b"document" => {
-                            log::trace!("found end of 'document', returning ...");
-                            break;
-                        }
-                        _tag => log::trace!("found End={:?}, skipping ...", e),
-                    }
-                }
-                Event::Start(e) => {
-                    log::trace!("got Start={:?}", e);
-                    match e.name().as_ref() {
-                        b"text" => {
-                            log::trace!("found 'text', reading ...");
-                            if let Event::Text(text) = deserializer.read_event_into(&mut buf)? {
-                                document.set_text(text.unescape()?.into());
-                            }
-                        }
-                        _tag => todo!("handle Start={:?}", e),
-                    }
-                }
-                Event::Text(e) => log::trace!("got Text={:?}, skipping ...", e),
-                e => {
-                    log::trace!("got {:?}", e);
-                    todo!("handle {:?}", e)
-                }
-            }
+        builder.text = Some(text);
+        Ok(true)
+    }
+
+    fn build(builder: Self::Builder) -> deserx::Result<Self> {
+        Ok(Self {
+            text_type: builder.text_type,
+            text: builder
+                .text
+                .ok_or_else(|| deserx::Error::missing_element("text"))?,
+        })
+    }
+}
+
+impl SerializeXmlFields for DocumentText {
+    fn serialize_attributes(&self, elem: &mut BytesStart<'_>) {
+        if let Some(text_type) = &self.text_type {
+            elem.push_attribute(("textType", text_type.as_str()));
+        }
+    }
+
+    fn has_children(&self) -> bool {
+        true
+    }
+
+    fn serialize_children<W: io::Write>(&self, ser: &mut XmlSerializer<W>) -> Result<(), SerError> {
+        if self.text_type == Some(TextType::Xhtml) {
+            deserx::ser::write_raw_text_element(ser, "text", &self.text)
+        } else {
+            deserx::ser::write_text_element(ser, "text", &self.text)
         }
-        log::debug!("document={:?}", document);
-        Ok(document)
     }
 }
+
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct DocumentReference(ResourceReference);
 
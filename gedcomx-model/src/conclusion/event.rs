@@ -0,0 +1,190 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::{
+    common::{
+        impl_extensions, EvidenceReference, Extensions, Identifier, IriRef, Note, ResourceReference,
+    },
+    conclusion::{Conclusion, Date, EventRole, PlaceReference},
+    gedcomx::Attribution,
+    source::SourceReference,
+    types::{ConfidenceLevel, EventType},
+    Result,
+};
+
+use super::{DocumentReference, Subject};
+
+/// A historical event, e.g. a marriage, and the persons that took part in it.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(order(subject, date, place, roles))]
+pub struct Event {
+    #[serde(flatten)]
+    #[xml(flatten)]
+    subject: Subject,
+    #[xml(attribute)]
+    r#type: Option<EventType>,
+    date: Option<Date>,
+    place: Option<PlaceReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "role")]
+    roles: Vec<EventRole>,
+    #[xml(attribute)]
+    id: IriRef,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
+}
+
+impl_extensions!(Event);
+
+impl Event {
+    pub fn new(id: IriRef) -> Self {
+        Self {
+            id,
+            subject: Subject::default(),
+            r#type: None,
+            date: None,
+            place: None,
+            roles: Vec::new(),
+            extensions: Extensions::default(),
+        }
+    }
+    pub fn with_id<S: Into<String>>(id: S) -> Result<Self> {
+        Ok(Self::new(IriRef::parse(id.into())?))
+    }
+}
+
+// Builder lite
+impl Event {
+    pub fn identifier(mut self, identifier: Identifier) -> Self {
+        self.add_identifier(identifier);
+        self
+    }
+
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.subject
+            .conclusion_mut()
+            .set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.subject.conclusion_mut().set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.subject
+            .conclusion_mut()
+            .set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.subject.conclusion_mut().add_note(note.into());
+        self
+    }
+
+    pub fn extracted(mut self, yes: bool) -> Self {
+        self.subject.set_extracted(yes);
+        self
+    }
+
+    pub fn source<S: Into<SourceReference>>(mut self, source: S) -> Self {
+        self.subject.add_source(source.into());
+        self
+    }
+    pub fn analysis<S: Into<DocumentReference>>(mut self, analysis: S) -> Self {
+        let analysis: DocumentReference = analysis.into();
+        self.subject.set_analysis(analysis.into());
+        self
+    }
+    pub fn evidence<S: Into<EvidenceReference>>(mut self, evidence: S) -> Self {
+        self.subject.add_evidence(evidence.into());
+        self
+    }
+
+    pub fn r#type(mut self, event_type: EventType) -> Self {
+        self.set_type(Some(event_type));
+        self
+    }
+
+    pub fn date(mut self, date: Date) -> Self {
+        self.set_date(date);
+        self
+    }
+
+    pub fn place(mut self, place: PlaceReference) -> Self {
+        self.set_place(place);
+        self
+    }
+
+    pub fn role(mut self, role: EventRole) -> Self {
+        self.add_role(role);
+        self
+    }
+}
+
+impl Event {
+    pub fn add_identifier(&mut self, identifier: Identifier) {
+        self.subject.add_identifier(identifier);
+    }
+    pub fn identifiers(&self) -> &[Identifier] {
+        self.subject.identifiers()
+    }
+    pub fn subject(&self) -> &Subject {
+        &self.subject
+    }
+    pub fn conclusion(&self) -> &Conclusion {
+        self.subject.conclusion()
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        self.subject.conclusion_mut()
+    }
+
+    pub fn set_type(&mut self, event_type: Option<EventType>) {
+        self.r#type = event_type;
+    }
+    pub fn set_date(&mut self, date: Date) {
+        self.date = Some(date);
+    }
+    pub fn set_place(&mut self, place: PlaceReference) {
+        self.place = Some(place);
+    }
+    pub fn add_role(&mut self, role: EventRole) {
+        self.roles.push(role);
+    }
+
+    pub fn id(&self) -> &IriRef {
+        &self.id
+    }
+    pub fn is_extracted(&self) -> bool {
+        self.subject.is_extracted()
+    }
+    pub fn get_type(&self) -> Option<&EventType> {
+        self.r#type.as_ref()
+    }
+    pub fn get_date(&self) -> Option<&Date> {
+        self.date.as_ref()
+    }
+    pub fn get_place(&self) -> Option<&PlaceReference> {
+        self.place.as_ref()
+    }
+    pub fn roles(&self) -> &[EventRole] {
+        self.roles.as_slice()
+    }
+}
+
+impl From<&Event> for EvidenceReference {
+    fn from(e: &Event) -> Self {
+        EvidenceReference::new(e.id.clone())
+    }
+}
+impl From<&Event> for ResourceReference {
+    fn from(e: &Event) -> Self {
+        ResourceReference::new(e.id.clone())
+    }
+}
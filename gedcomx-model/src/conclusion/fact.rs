@@ -1,36 +1,81 @@
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, Event};
+use deserx::{DeserializeXml, SerializeXml};
 
 use crate::{
-    conclusion::Date,
-    ser::{xml, SerializeXml},
-    types::FactType,
+    common::{impl_extensions, Extensions, Note, Qualifier},
+    conclusion::{Conclusion, Date},
+    gedcomx::Attribution,
+    types::{ConfidenceLevel, FactQualifierType, FactType},
 };
 
 use super::PlaceReference;
 
+/// A data item presumed to be true about a subject, e.g. a birth.
+///
+/// Qualifiers add detail such as the age of the person at the event, see
+/// [`FactQualifierType`].
 #[serde_with::skip_serializing_none]
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(order(conclusion, date, place, value, qualifiers))]
 pub struct Fact {
+    #[serde(flatten)]
+    #[xml(flatten)]
+    conclusion: Conclusion,
     #[serde(default, skip_serializing_if = "String::is_empty")]
+    #[xml(text_element, default, skip_serializing_if = "String::is_empty")]
     value: String,
+    #[xml(attribute)]
     r#type: FactType,
     date: Option<Date>,
     place: Option<PlaceReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "qualifier")]
+    qualifiers: Vec<Qualifier>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(Fact);
+
 impl Fact {
     pub fn new(fact_type: FactType) -> Self {
         Self {
+            conclusion: Conclusion::default(),
             r#type: fact_type,
             date: None,
             place: None,
             value: String::new(),
+            qualifiers: Vec::new(),
+            extensions: Extensions::default(),
         }
     }
 }
 
+// Builder lite
 impl Fact {
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.conclusion.set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.conclusion.set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.conclusion.set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.conclusion.add_note(note.into());
+        self
+    }
+
     pub fn date(mut self, date: Date) -> Self {
         self.set_date(date);
         self
@@ -45,9 +90,21 @@ impl Fact {
         self.set_value(s.into());
         self
     }
+
+    pub fn qualifier<Q: Into<Qualifier>>(mut self, qualifier: Q) -> Self {
+        self.add_qualifier(qualifier.into());
+        self
+    }
 }
 
 impl Fact {
+    pub fn conclusion(&self) -> &Conclusion {
+        &self.conclusion
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        &mut self.conclusion
+    }
+
     pub fn set_date(&mut self, date: Date) {
         self.date = Some(date);
     }
@@ -58,122 +115,49 @@ impl Fact {
     pub fn set_place(&mut self, place: PlaceReference) {
         self.place = Some(place);
     }
-
-    pub fn r#type(&self) -> FactType {
-        self.r#type
+    pub fn add_qualifier(&mut self, qualifier: Qualifier) {
+        self.qualifiers.push(qualifier);
     }
-}
 
-impl SerializeXml for Fact {
-    fn tag(&self) -> &str {
-        "fact"
+    pub fn r#type(&self) -> &FactType {
+        &self.r#type
+    }
+    pub fn get_date(&self) -> Option<&Date> {
+        self.date.as_ref()
+    }
+    pub fn get_place(&self) -> Option<&PlaceReference> {
+        self.place.as_ref()
+    }
+    pub fn qualifiers(&self) -> &[Qualifier] {
+        self.qualifiers.as_slice()
     }
 
-    fn serialize_xml<W: std::io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> Result<(), crate::ser::SerError> {
-        let mut elem = BytesStart::new(self.tag());
-        elem.push_attribute(("type", self.r#type.as_qname_uri()));
-        ser.write_event(Event::Start(elem))?;
-        self.date.serialize_xml(ser)?;
-        self.place.serialize_xml(ser)?;
-        xml::write_elem_w_text_if_not_empty(ser, "value", &self.value)?;
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
+    /// The value of the first qualifier named `name`.
+    pub fn get_qualifier(&self, name: &FactQualifierType) -> Option<&str> {
+        self.qualifiers
+            .iter()
+            .find(|qualifier| qualifier.is(name))
+            .and_then(Qualifier::get_value)
+    }
+    pub fn has_qualifier(&self, name: &FactQualifierType) -> bool {
+        self.qualifiers.iter().any(|qualifier| qualifier.is(name))
     }
-}
 
-impl DeserializeXml for Fact {
-    fn deserialize_xml_with_start<'de, R: std::io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-        start: &quick_xml::events::BytesStart<'de>,
-    ) -> Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
-        let attr = start.try_get_attribute("type")?;
-        let fact_type: FactType = if let Some(fact_type) = attr {
-            FactType::from_qname_uri(fact_type.unescape_value()?.as_ref())
-        } else {
-            todo!("handle no 'fact_type'")
-        };
-        let mut fact = Self::new(fact_type);
-        // let attr = start.try_get_attribute("extracted")?;
-        // let extracted = if let Some(extracted) = attr {
-        //     match extracted.unescape_value()?.as_ref() {
-        //         "true" | "1" => true,
-        //         _ => false,
-        //     }
-        // } else {
-        //     false
-        // };
-        // fact.set_extracted(extracted);
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Empty(e) => {
-                    log::debug!("read Empty={:?}", e);
-                    match e.name().as_ref() {
-                        b"gender" => {
-                            let attr = e.try_get_attribute("type")?;
-                            if let Some(_value) = attr {
-                                // fact.set_gender(Gender::from_qfact_uri(
-                                //     value.unescape_value()?.as_ref(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"source" => {
-                            let attr = e.try_get_attribute("description")?;
-                            if let Some(_source) = attr {
-                                // fact.add_source(SourceReference::new(
-                                //     Uri::new(source.unescape_value()?.to_string()),
-                                //     String::new(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::Start(e) => {
-                    log::debug!("read Start={:?}", e);
-                    match e.name().as_ref() {
-                        b"date" => {
-                            log::trace!("found 'date'");
-                            let date = Date::deserialize_xml_with_start(deserializer, &e)?;
-                            fact.set_date(date);
-                        }
-                        b"place" => {
-                            log::trace!("found 'place'");
-                            let place =
-                                PlaceReference::deserialize_xml_with_start(deserializer, &e)?;
-                            fact.set_place(place);
-                        }
-                        b"value" => {
-                            log::trace!("found 'value'");
-                            if let Event::Text(value_text) =
-                                deserializer.read_event_into(&mut buf)?
-                            {
-                                fact.set_value(value_text.unescape()?.into());
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::End(e) => match e.name().as_ref() {
-                    b"fact" => {
-                        log::trace!("found end of 'nameForm' returning ...");
-                        break;
-                    }
-                    _tag => log::trace!("skipping '{:?}' ...", e),
-                },
-                e => {
-                    log::trace!("got: {:?} skipping ...", e);
-                }
-            }
-        }
-        log::debug!("fact = {:?}", fact);
-        Ok(fact)
+    /// The age of the person at the time of the fact.
+    pub fn age(&self) -> Option<&str> {
+        self.get_qualifier(&FactQualifierType::Age)
+    }
+    /// The cause of the fact, e.g. the cause of death.
+    pub fn cause(&self) -> Option<&str> {
+        self.get_qualifier(&FactQualifierType::Cause)
+    }
+    pub fn religion(&self) -> Option<&str> {
+        self.get_qualifier(&FactQualifierType::Religion)
+    }
+    pub fn transport(&self) -> Option<&str> {
+        self.get_qualifier(&FactQualifierType::Transport)
+    }
+    pub fn is_non_consensual(&self) -> bool {
+        self.has_qualifier(&FactQualifierType::NonConsensual)
     }
 }
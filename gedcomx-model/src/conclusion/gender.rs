@@ -0,0 +1,82 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::{
+    common::{impl_extensions, Extensions, Note},
+    conclusion::Conclusion,
+    gedcomx::Attribution,
+    types::{ConfidenceLevel, GenderType},
+};
+
+/// A gender conclusion.
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
+pub struct Gender {
+    #[serde(flatten)]
+    #[xml(flatten)]
+    conclusion: Conclusion,
+    #[xml(attribute)]
+    r#type: GenderType,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
+}
+
+impl_extensions!(Gender);
+
+impl Gender {
+    pub fn new(r#type: GenderType) -> Self {
+        Self {
+            conclusion: Conclusion::default(),
+            r#type,
+            extensions: Extensions::default(),
+        }
+    }
+}
+
+// Builder lite
+impl Gender {
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.conclusion.set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.conclusion.set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.conclusion.set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.conclusion.add_note(note.into());
+        self
+    }
+}
+
+impl Gender {
+    pub fn conclusion(&self) -> &Conclusion {
+        &self.conclusion
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        &mut self.conclusion
+    }
+
+    pub fn set_type(&mut self, r#type: GenderType) {
+        self.r#type = r#type;
+    }
+
+    pub fn get_type(&self) -> &GenderType {
+        &self.r#type
+    }
+}
+
+impl From<GenderType> for Gender {
+    fn from(r#type: GenderType) -> Self {
+        Self::new(r#type)
+    }
+}
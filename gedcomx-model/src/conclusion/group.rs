@@ -0,0 +1,191 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::{
+    common::{
+        impl_extensions, EvidenceReference, Extensions, Identifier, IriRef, Note,
+        ResourceReference, TextValue,
+    },
+    conclusion::{Conclusion, Date, GroupRole, PlaceReference},
+    gedcomx::Attribution,
+    source::SourceReference,
+    types::ConfidenceLevel,
+    Result,
+};
+
+use super::Subject;
+
+/// A group of persons, e.g. a household, a regiment or a congregation.
+///
+/// Part of the GEDCOM X Groups extension.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(order(subject, names, date, place, roles))]
+pub struct Group {
+    #[serde(flatten)]
+    #[xml(flatten)]
+    subject: Subject,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "name")]
+    names: Vec<TextValue>,
+    /// When the group existed.
+    date: Option<Date>,
+    /// Where the group was, e.g. the residence of a household.
+    place: Option<PlaceReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "role")]
+    roles: Vec<GroupRole>,
+    #[xml(attribute)]
+    id: IriRef,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
+}
+
+impl_extensions!(Group);
+
+impl Group {
+    pub fn new(id: IriRef) -> Self {
+        Self {
+            id,
+            subject: Subject::default(),
+            names: Vec::new(),
+            date: None,
+            place: None,
+            roles: Vec::new(),
+            extensions: Extensions::default(),
+        }
+    }
+    pub fn with_id<S: Into<String>>(id: S) -> Result<Self> {
+        Ok(Self::new(IriRef::parse(id.into())?))
+    }
+}
+
+// Builder lite
+impl Group {
+    pub fn identifier(mut self, identifier: Identifier) -> Self {
+        self.add_identifier(identifier);
+        self
+    }
+
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.subject
+            .conclusion_mut()
+            .set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.subject.conclusion_mut().set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.subject
+            .conclusion_mut()
+            .set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.subject.conclusion_mut().add_note(note.into());
+        self
+    }
+
+    pub fn extracted(mut self, yes: bool) -> Self {
+        self.subject.set_extracted(yes);
+        self
+    }
+
+    pub fn source<S: Into<SourceReference>>(mut self, source: S) -> Self {
+        self.subject.add_source(source.into());
+        self
+    }
+    pub fn evidence<S: Into<EvidenceReference>>(mut self, evidence: S) -> Self {
+        self.subject.add_evidence(evidence.into());
+        self
+    }
+
+    pub fn name<T: Into<TextValue>>(mut self, name: T) -> Self {
+        self.add_name(name.into());
+        self
+    }
+
+    pub fn date(mut self, date: Date) -> Self {
+        self.set_date(date);
+        self
+    }
+
+    pub fn place(mut self, place: PlaceReference) -> Self {
+        self.set_place(place);
+        self
+    }
+
+    pub fn role(mut self, role: GroupRole) -> Self {
+        self.add_role(role);
+        self
+    }
+}
+
+impl Group {
+    pub fn add_identifier(&mut self, identifier: Identifier) {
+        self.subject.add_identifier(identifier);
+    }
+    pub fn identifiers(&self) -> &[Identifier] {
+        self.subject.identifiers()
+    }
+    pub fn subject(&self) -> &Subject {
+        &self.subject
+    }
+    pub fn conclusion(&self) -> &Conclusion {
+        self.subject.conclusion()
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        self.subject.conclusion_mut()
+    }
+
+    pub fn add_name(&mut self, name: TextValue) {
+        self.names.push(name);
+    }
+    pub fn set_date(&mut self, date: Date) {
+        self.date = Some(date);
+    }
+    pub fn set_place(&mut self, place: PlaceReference) {
+        self.place = Some(place);
+    }
+    pub fn add_role(&mut self, role: GroupRole) {
+        self.roles.push(role);
+    }
+
+    pub fn id(&self) -> &IriRef {
+        &self.id
+    }
+    pub fn is_extracted(&self) -> bool {
+        self.subject.is_extracted()
+    }
+    pub fn names(&self) -> &[TextValue] {
+        self.names.as_slice()
+    }
+    pub fn get_date(&self) -> Option<&Date> {
+        self.date.as_ref()
+    }
+    pub fn get_place(&self) -> Option<&PlaceReference> {
+        self.place.as_ref()
+    }
+    pub fn roles(&self) -> &[GroupRole] {
+        self.roles.as_slice()
+    }
+}
+
+impl From<&Group> for EvidenceReference {
+    fn from(group: &Group) -> Self {
+        EvidenceReference::new(group.id.clone())
+    }
+}
+impl From<&Group> for ResourceReference {
+    fn from(group: &Group) -> Self {
+        ResourceReference::new(group.id.clone())
+    }
+}
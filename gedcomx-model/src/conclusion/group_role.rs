@@ -0,0 +1,125 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::{
+    common::{impl_extensions, Extensions, Note, ResourceReference, Uri},
+    gedcomx::Attribution,
+    source::SourceReference,
+    types::ConfidenceLevel,
+};
+
+use super::{Conclusion, Date};
+
+/// The role a person plays in a [`Group`](super::Group), e.g. the head of a
+/// household.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(rename = "role", order(conclusion, person, date, details))]
+pub struct GroupRole {
+    #[serde(flatten)]
+    #[xml(flatten)]
+    conclusion: Conclusion,
+    person: ResourceReference,
+    #[xml(attribute)]
+    r#type: Option<Uri>,
+    /// When the person had the role in the group.
+    date: Option<Date>,
+    /// Details about the role of the person in the group.
+    #[xml(text_element)]
+    details: Option<String>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
+}
+
+impl_extensions!(GroupRole);
+
+impl GroupRole {
+    pub fn new<R: Into<ResourceReference>>(person: R) -> Self {
+        Self {
+            conclusion: Conclusion::default(),
+            person: person.into(),
+            r#type: None,
+            date: None,
+            details: None,
+            extensions: Extensions::default(),
+        }
+    }
+}
+
+// Builder lite
+impl GroupRole {
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.conclusion.set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.conclusion.set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.conclusion.set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.conclusion.add_note(note.into());
+        self
+    }
+
+    pub fn r#type(mut self, role_type: Uri) -> Self {
+        self.set_type(Some(role_type));
+        self
+    }
+
+    pub fn date(mut self, date: Date) -> Self {
+        self.set_date(date);
+        self
+    }
+
+    pub fn details<S: Into<String>>(mut self, details: S) -> Self {
+        self.set_details(details.into());
+        self
+    }
+
+    pub fn source<S: Into<SourceReference>>(mut self, source: S) -> Self {
+        self.conclusion.add_source(source.into());
+        self
+    }
+}
+
+impl GroupRole {
+    pub fn conclusion(&self) -> &Conclusion {
+        &self.conclusion
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        &mut self.conclusion
+    }
+
+    pub fn set_type(&mut self, role_type: Option<Uri>) {
+        self.r#type = role_type;
+    }
+    pub fn set_date(&mut self, date: Date) {
+        self.date = Some(date);
+    }
+    pub fn set_details(&mut self, details: String) {
+        self.details = Some(details);
+    }
+
+    pub fn person(&self) -> &ResourceReference {
+        &self.person
+    }
+    pub fn get_type(&self) -> Option<&Uri> {
+        self.r#type.as_ref()
+    }
+    pub fn get_date(&self) -> Option<&Date> {
+        self.date.as_ref()
+    }
+    pub fn get_details(&self) -> Option<&str> {
+        self.details.as_deref()
+    }
+}
@@ -1,10 +1,18 @@
+#[allow(clippy::module_inception)]
 mod conclusion;
 mod date;
 mod document;
+mod event;
+mod event_role;
 mod fact;
+mod gender;
+mod group;
+mod group_role;
 mod name;
 mod name_form;
+mod name_part;
 mod person;
+mod place_description;
 mod place_reference;
 mod relationship;
 mod subject;
@@ -12,10 +20,17 @@ mod subject;
 pub use conclusion::Conclusion;
 pub use date::Date;
 pub use document::{Document, DocumentReference};
+pub use event::Event;
+pub use event_role::EventRole;
 pub use fact::Fact;
+pub use gender::Gender;
+pub use group::Group;
+pub use group_role::GroupRole;
 pub use name::Name;
 pub use name_form::NameForm;
+pub use name_part::NamePart;
 pub use person::Person;
+pub use place_description::PlaceDescription;
 pub use place_reference::PlaceReference;
-pub use relationship::Relationship;
+pub use relationship::{Relationship, RelationshipError};
 pub use subject::Subject;
@@ -1,29 +1,41 @@
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, Event};
+use deserx::{DeserializeXml, SerializeXml};
 
 use crate::{
-    conclusion::NameForm,
-    ser::SerializeXml,
-    types::{NamePartType, NameType},
+    common::{impl_extensions, Extensions, Note},
+    conclusion::{Conclusion, NameForm},
+    gedcomx::Attribution,
+    types::{ConfidenceLevel, NamePartType, NameType},
 };
 
 /// A name conclusion
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    DeserializeXml,
+    SerializeXml,
+)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+#[xml(order(conclusion, name_forms))]
 pub struct Name {
+    #[serde(flatten)]
+    #[xml(flatten)]
+    conclusion: Conclusion,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[xml(attribute, rename = "type")]
     name_type: Option<NameType>,
+    #[xml(rename = "nameForm")]
     name_forms: Vec<NameForm>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
-impl Default for Name {
-    fn default() -> Self {
-        Self {
-            name_type: None,
-            name_forms: Vec::default(),
-        }
-    }
-}
+impl_extensions!(Name);
+
 impl Name {
     pub fn new() -> Self {
         Self::default()
@@ -32,6 +44,26 @@ impl Name {
 
 // Builder lite
 impl Name {
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.conclusion.set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.conclusion.set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.conclusion.set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.conclusion.add_note(note.into());
+        self
+    }
+
     pub fn name_form(mut self, name_form: NameForm) -> Self {
         self.add_name_form(name_form);
         self
@@ -39,11 +71,20 @@ impl Name {
 }
 
 impl Name {
+    pub fn conclusion(&self) -> &Conclusion {
+        &self.conclusion
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        &mut self.conclusion
+    }
+
     pub fn add_name_form(&mut self, name_form: NameForm) {
         self.name_forms.push(name_form);
     }
-    pub fn get_part(&self, _part: NamePartType) -> Option<&str> {
-        None
+    /// The value of the first part of type `part` in the preferred, i.e. first,
+    /// name form.
+    pub fn get_part(&self, part: NamePartType) -> Option<&str> {
+        self.name_forms.first()?.get_part(&part)
     }
 
     pub fn name_forms(&self) -> &[NameForm] {
@@ -65,106 +106,10 @@ impl From<&str> for Name {
     }
 }
 
-impl SerializeXml for Name {
-    fn tag(&self) -> &str {
-        "name"
-    }
-    fn serialize_xml<W: std::io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> Result<(), crate::ser::SerError> {
-        let elem = BytesStart::new(self.tag());
-        ser.write_event(Event::Start(elem))?;
-
-        for name_form in &self.name_forms {
-            name_form.serialize_xml(ser)?;
-        }
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
-    }
-}
-impl DeserializeXml for Name {
-    fn deserialize_xml_with_start<'de, R: std::io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-        _start: &quick_xml::events::BytesStart<'de>,
-    ) -> Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
-        // let attr = start.try_get_attribute("id")?;
-        // let id: String = if let Some(id) = attr {
-        //     id.unescape_value()?.into()
-        // } else {
-        //     todo!("handle no 'id'")
-        // };
-        let mut name = Self::new();
-        // let attr = start.try_get_attribute("extracted")?;
-        // let extracted = if let Some(extracted) = attr {
-        //     match extracted.unescape_value()?.as_ref() {
-        //         "true" | "1" => true,
-        //         _ => false,
-        //     }
-        // } else {
-        //     false
-        // };
-        // name.set_extracted(extracted);
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Empty(e) => {
-                    log::debug!("read Empty={:?}", e);
-                    match e.name().as_ref() {
-                        b"gender" => {
-                            let attr = e.try_get_attribute("type")?;
-                            if let Some(_value) = attr {
-                                // name.set_gender(Gender::from_qname_uri(
-                                //     value.unescape_value()?.as_ref(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"source" => {
-                            let attr = e.try_get_attribute("description")?;
-                            if let Some(_source) = attr {
-                                // name.add_source(SourceReference::new(
-                                //     Uri::new(source.unescape_value()?.to_string()),
-                                //     String::new(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::Start(e) => {
-                    log::debug!("read Start={:?}", e);
-                    match e.name().as_ref() {
-                        b"nameForm" => {
-                            log::trace!("found 'name'");
-                            let name_form = NameForm::deserialize_xml_with_start(deserializer, &e)?;
-                            name.add_name_form(name_form);
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::End(e) => match e.name().as_ref() {
-                    b"name" => {
-                        log::trace!("found end of 'name' returning ...");
-                        break;
-                    }
-                    _tag => log::trace!("skipping '{:?}' ...", e),
-                },
-                e => {
-                    log::trace!("got: {:?} skipping ...", e);
-                }
-            }
-        }
-        log::debug!("name = {:?}", name);
-        Ok(name)
-    }
-}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::conclusion::NamePart;
 
     mod get_part {
         use super::*;
@@ -191,24 +136,43 @@ mod tests {
             assert!(name.get_part(NamePartType::Given).is_none());
             assert!(name.get_part(NamePartType::Surname).is_none());
         }
-        // NameForm nameForm = new NameForm("John Fitzgerald Kennedy")
-        //   .lang("en")
-        //   .part(NamePartType.Given, "John")
-        //   .part(NamePartType.Given, "Fitzgerald")
-        //   .part(NamePartType.Surname, "Kennedy");
-        // Name name = new Name().nameForm(nameForm);
-        // assertEquals("John", name.getPart(NamePartType.Given));
-        // assertEquals("Kennedy", name.getPart(NamePartType.Surname));
-
-        // assertNull(nameNoParts.getPart(NamePartType.Given));
-        // assertNull(nameNoParts.getPart(NamePartType.Surname));
-
-        // NameForm nameFormNullParts = new NameForm("John Fitzgerald Kennedy")
-        //   .lang("en")
-        //   .part(NamePartType.Given, null)
-        //   .part(NamePartType.Surname, null);
-        // Name nameNullParts = new Name().nameForm(nameFormNullParts);
-        // assertNull(nameNullParts.getPart(NamePartType.Given));
-        // assertNull(nameNullParts.getPart(NamePartType.Surname));
+
+        #[test]
+        fn form_with_parts() {
+            let name_form = NameForm::new()
+                .full_text("John Fitzgerald Kennedy".into())
+                .lang("en".into())
+                .part(NamePart::new(NamePartType::Given, "John".into()))
+                .part(NamePart::new(NamePartType::Given, "Fitzgerald".into()))
+                .part(NamePart::new(NamePartType::Surname, "Kennedy".into()));
+            let name = Name::new().name_form(name_form);
+            assert_eq!(name.get_part(NamePartType::Given), Some("John"));
+            assert_eq!(name.get_part(NamePartType::Surname), Some("Kennedy"));
+            assert!(name.get_part(NamePartType::Prefix).is_none());
+        }
+
+        #[test]
+        fn form_empty_parts() {
+            let name_form = NameForm::new()
+                .full_text("John Fitzgerald Kennedy".into())
+                .lang("en".into())
+                .part(NamePart::new(NamePartType::Given, String::new()))
+                .part(NamePart::new(NamePartType::Surname, String::new()));
+            let name = Name::new().name_form(name_form);
+            assert!(name.get_part(NamePartType::Given).is_none());
+            assert!(name.get_part(NamePartType::Surname).is_none());
+        }
+
+        #[test]
+        fn uses_preferred_form() {
+            let name = Name::new()
+                .name_form(
+                    NameForm::new().part(NamePart::new(NamePartType::Given, "Anders".into())),
+                )
+                .name_form(
+                    NameForm::new().part(NamePart::new(NamePartType::Given, "Andrew".into())),
+                );
+            assert_eq!(name.get_part(NamePartType::Given), Some("Anders"));
+        }
     }
 }
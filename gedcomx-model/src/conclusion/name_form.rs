@@ -1,26 +1,56 @@
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use deserx::{DeserializeXml, SerializeXml};
 
-use crate::ser::SerializeXml;
+use crate::common::{impl_extensions, Extensions};
+
+use crate::{
+    conclusion::NamePart,
+    name::{NameParser, StandardNameParser},
+    types::NamePartType,
+};
 
 /// A name form conclusion
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(rename_all = "camelCase", remote = "Self")]
 pub struct NameForm {
+    #[xml(text_element, default, skip_serializing_if = "String::is_empty")]
     full_text: String,
     #[serde(default, skip_serializing_if = "String::is_empty")]
+    #[xml(
+        attribute,
+        rename = "xml:lang",
+        default,
+        skip_serializing_if = "String::is_empty"
+    )]
     lang: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "part")]
+    parts: Vec<NamePart>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(NameForm);
+
 impl NameForm {
     pub fn new() -> Self {
         Self {
             full_text: String::new(),
             lang: String::new(),
+            parts: Vec::new(),
+            extensions: Extensions::default(),
         }
     }
 }
 
+impl Default for NameForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Builder lite
 impl NameForm {
     pub fn full_text(mut self, full_text: String) -> Self {
@@ -32,9 +62,18 @@ impl NameForm {
         self.lang = lang;
         self
     }
+
+    pub fn part(mut self, part: NamePart) -> Self {
+        self.add_part(part);
+        self
+    }
 }
 
 impl NameForm {
+    pub fn set_full_text(&mut self, full_text: String) {
+        self.full_text = full_text;
+    }
+
     pub fn get_full_text(&self) -> &str {
         self.full_text.as_str()
     }
@@ -42,113 +81,39 @@ impl NameForm {
     pub fn get_lang(&self) -> &str {
         self.lang.as_str()
     }
-}
 
-impl SerializeXml for NameForm {
-    fn tag(&self) -> &str {
-        "nameForm"
+    pub fn add_part(&mut self, part: NamePart) {
+        self.parts.push(part);
     }
-    fn serialize_xml<W: std::io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> Result<(), crate::ser::SerError> {
-        let elem = BytesStart::new(self.tag());
-        ser.write_event(Event::Start(elem))?;
-
-        if !self.full_text.is_empty() {
-            ser.write_event(Event::Start(BytesStart::new("fullText")))?;
-            ser.write_event(Event::Text(BytesText::new(&self.full_text)))?;
-            ser.write_event(Event::End(BytesEnd::new("fullText")))?;
-        }
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
+
+    pub fn parts(&self) -> &[NamePart] {
+        self.parts.as_slice()
     }
-}
 
-impl DeserializeXml for NameForm {
-    fn deserialize_xml_with_start<'de, R: std::io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-        _start: &quick_xml::events::BytesStart<'de>,
-    ) -> Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
-        // let attr = start.try_get_attribute("id")?;
-        // let id: String = if let Some(id) = attr {
-        //     id.unescape_value()?.into()
-        // } else {
-        //     todo!("handle no 'id'")
-        // };
-        let mut name_form = Self::new();
-        // let attr = start.try_get_attribute("extracted")?;
-        // let extracted = if let Some(extracted) = attr {
-        //     match extracted.unescape_value()?.as_ref() {
-        //         "true" | "1" => true,
-        //         _ => false,
-        //     }
-        // } else {
-        //     false
-        // };
-        // name_form.set_extracted(extracted);
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Empty(e) => {
-                    log::debug!("read Empty={:?}", e);
-                    match e.name().as_ref() {
-                        b"gender" => {
-                            let attr = e.try_get_attribute("type")?;
-                            if let Some(_value) = attr {
-                                // name_form.set_gender(Gender::from_qname_form_uri(
-                                //     value.unescape_value()?.as_ref(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"source" => {
-                            let attr = e.try_get_attribute("description")?;
-                            if let Some(_source) = attr {
-                                // name_form.add_source(SourceReference::new(
-                                //     Uri::new(source.unescape_value()?.to_string()),
-                                //     String::new(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::Start(e) => {
-                    log::debug!("read Start={:?}", e);
-                    match e.name().as_ref() {
-                        b"fullText" => {
-                            log::trace!("found 'fullText'");
-                            let mut text_buf = Vec::new();
-                            if let Event::Text(e_text) =
-                                deserializer.read_event_into(&mut text_buf)?
-                            {
-                                name_form.full_text = e_text.unescape()?.into();
-                            } else {
-                                todo!("handle not Text")
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::End(e) => match e.name().as_ref() {
-                    b"nameForm" => {
-                        log::trace!("found end of 'nameForm' returning ...");
-                        break;
-                    }
-                    _tag => log::trace!("skipping '{:?}' ...", e),
-                },
-                e => {
-                    log::trace!("got: {:?} skipping ...", e);
-                }
-            }
-        }
-        log::debug!("name_form = {:?}", name_form);
-        Ok(name_form)
+    /// The value of the first part of type `part`, parts without a value are
+    /// ignored.
+    pub fn get_part(&self, part: &NamePartType) -> Option<&str> {
+        self.parts
+            .iter()
+            .find(|p| p.get_type() == Some(part) && !p.get_value().is_empty())
+            .map(NamePart::get_value)
+    }
+
+    /// Replaces the parts with those split from the full text, see
+    /// [`StandardNameParser`].
+    ///
+    /// Returns how sure the parser is of the split, from 0 to 1.
+    pub fn parse_parts(&mut self) -> f32 {
+        self.parse_parts_with(&StandardNameParser::new())
+    }
+    pub fn parse_parts_with<P: NameParser + ?Sized>(&mut self, parser: &P) -> f32 {
+        let lang = Some(self.lang.as_str()).filter(|lang| !lang.is_empty());
+        let parsed = parser.parse(&self.full_text, lang);
+        let confidence = parsed.confidence();
+        self.parts = parsed.into_parts();
+        confidence
     }
 }
+
 #[cfg(test)]
 mod tests {}
@@ -0,0 +1,81 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::{
+    common::{impl_extensions, Extensions, Qualifier},
+    types::{NamePartQualifierType, NamePartType},
+};
+
+/// A part of a name form, e.g. a given name or a surname.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    DeserializeXml,
+    SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(rename = "part")]
+pub struct NamePart {
+    #[xml(attribute)]
+    r#type: Option<NamePartType>,
+    #[xml(attribute, default)]
+    #[serde(default)]
+    value: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "qualifier")]
+    qualifiers: Vec<Qualifier>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
+}
+
+impl_extensions!(NamePart);
+
+impl NamePart {
+    pub fn new(r#type: NamePartType, value: String) -> Self {
+        Self {
+            r#type: Some(r#type),
+            value,
+            qualifiers: Vec::new(),
+            extensions: Extensions::default(),
+        }
+    }
+}
+
+// Builder lite
+impl NamePart {
+    pub fn qualifier<Q: Into<Qualifier>>(mut self, qualifier: Q) -> Self {
+        self.add_qualifier(qualifier.into());
+        self
+    }
+}
+
+impl NamePart {
+    pub fn set_type(&mut self, r#type: Option<NamePartType>) {
+        self.r#type = r#type;
+    }
+    pub fn set_value(&mut self, value: String) {
+        self.value = value;
+    }
+    pub fn add_qualifier(&mut self, qualifier: Qualifier) {
+        self.qualifiers.push(qualifier);
+    }
+
+    pub fn get_type(&self) -> Option<&NamePartType> {
+        self.r#type.as_ref()
+    }
+    pub fn get_value(&self) -> &str {
+        self.value.as_str()
+    }
+    pub fn qualifiers(&self) -> &[Qualifier] {
+        self.qualifiers.as_slice()
+    }
+
+    pub fn has_qualifier(&self, qualifier: &NamePartQualifierType) -> bool {
+        self.qualifiers.iter().any(|q| q.is(qualifier))
+    }
+}
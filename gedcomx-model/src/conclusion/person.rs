@@ -1,30 +1,44 @@
 use crate::{
-    common::{EvidenceReference, IriRef, ResourceReference},
-    conclusion::{Fact, Name},
-    ser::{SerError, SerializeXml},
+    common::{
+        impl_extensions, refers_to, EvidenceReference, Extensions, Identifier, IriRef, Note,
+        ResourceReference,
+    },
+    conclusion::{Conclusion, Fact, Gender, Name},
+    gedcomx::Attribution,
     source::SourceReference,
-    types::{Gender, NameType},
+    types::{ConfidenceLevel, NameType},
     Result,
 };
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, Event};
-use std::io;
+use deserx::{DeserializeXml, SerializeXml};
 
 use super::{DocumentReference, Subject};
 
-#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(order(subject, gender, names, facts))]
 pub struct Person {
     #[serde(flatten)]
+    #[xml(flatten)]
     subject: Subject,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "name")]
     names: Vec<Name>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     gender: Option<Gender>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "fact")]
     facts: Vec<Fact>,
+    #[xml(attribute)]
     id: IriRef,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(Person);
+
 impl Person {
     pub fn new(id: IriRef) -> Self {
         Self {
@@ -33,6 +47,7 @@ impl Person {
             gender: None,
             names: Vec::new(),
             facts: Vec::new(),
+            extensions: Extensions::default(),
         }
     }
     pub fn with_id<S: Into<String>>(id: S) -> Result<Self> {
@@ -42,6 +57,35 @@ impl Person {
 
 // Builder lite
 impl Person {
+    pub fn identifier(mut self, identifier: Identifier) -> Self {
+        self.add_identifier(identifier);
+        self
+    }
+
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.subject
+            .conclusion_mut()
+            .set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.subject.conclusion_mut().set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.subject
+            .conclusion_mut()
+            .set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.subject.conclusion_mut().add_note(note.into());
+        self
+    }
+
     pub fn extracted(mut self, yes: bool) -> Self {
         self.set_extracted(yes);
         self
@@ -59,8 +103,8 @@ impl Person {
         self.subject.add_evidence(evidence.into());
         self
     }
-    pub fn gender(mut self, gender: Gender) -> Self {
-        self.set_gender(gender);
+    pub fn gender<G: Into<Gender>>(mut self, gender: G) -> Self {
+        self.set_gender(gender.into());
         self
     }
 
@@ -76,6 +120,22 @@ impl Person {
 }
 
 impl Person {
+    pub fn add_identifier(&mut self, identifier: Identifier) {
+        self.subject.add_identifier(identifier);
+    }
+    pub fn identifiers(&self) -> &[Identifier] {
+        self.subject.identifiers()
+    }
+    pub fn subject(&self) -> &Subject {
+        &self.subject
+    }
+    pub fn conclusion(&self) -> &Conclusion {
+        self.subject.conclusion()
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        self.subject.conclusion_mut()
+    }
+
     // pub fn set_id(&mut self, id: String) {
     //     self.id = id;
     // }
@@ -107,6 +167,10 @@ impl Person {
         self.facts.as_slice()
     }
 
+    pub fn get_gender(&self) -> Option<&Gender> {
+        self.gender.as_ref()
+    }
+
     pub fn names(&self) -> &[Name] {
         self.names.as_slice()
     }
@@ -114,18 +178,15 @@ impl Person {
         &self.id
     }
 
+    /// Whether `reference` refers to this person, either by the full id or,
+    /// for local references like `#P-1`, by fragment.
+    pub fn is_referenced_by(&self, reference: &ResourceReference) -> bool {
+        refers_to(reference.iri(), self.id.as_str())
+    }
+
     pub fn first_name_of_type(&self, name_type: NameType) -> Option<&Name> {
-        if self.names.is_empty() {
-            return None;
-        }
         let name_type = Some(&name_type);
-
-        for name in &self.names {
-            if name.get_type() == name_type {
-                return Some(name);
-            }
-        }
-        None
+        self.names.iter().find(|name| name.get_type() == name_type)
     }
 }
 
@@ -139,149 +200,6 @@ impl From<&Person> for ResourceReference {
         ResourceReference::new(p.id.clone())
     }
 }
-impl SerializeXml for Person {
-    fn tag(&self) -> &str {
-        "person"
-    }
-
-    fn serialize_xml<W: io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> std::result::Result<(), SerError> {
-        let mut root = BytesStart::new(self.tag());
-        if self.is_extracted() {
-            root.push_attribute(("extracted", self.subject.extracted_as_str()));
-        }
-        root.push_attribute(("id", self.id.as_str()));
-        ser.write_event(Event::Start(root))?;
-
-        self.subject.serialize_xml(ser)?;
-
-        if let Some(gender) = self.gender.as_ref() {
-            gender.serialize_xml(ser)?;
-        }
-        for name in &self.names {
-            name.serialize_xml(ser)?;
-        }
-        for fact in &self.facts {
-            fact.serialize_xml(ser)?;
-        }
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
-    }
-}
-
-impl DeserializeXml for Person {
-    fn deserialize_xml_with_start<'de, R: std::io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-        start: &quick_xml::events::BytesStart<'de>,
-    ) -> std::result::Result<Self, quick_xml::Error> {
-        //     <R: std::io::BufRead>(
-        //     deserializer: &mut quick_xml::Reader<R>,
-        // ) -> Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
-        let attr = start.try_get_attribute("id")?;
-        let id: String = if let Some(id) = attr {
-            id.unescape_value()?.into()
-            // person.set_contributor(ResourceReference::with_resource(
-            //     resource.unescape_value()?.into(),
-            // ));
-        } else {
-            todo!("handle no 'id'")
-        };
-        let mut person = Self::new(IriRef::parse(id).expect("iri"));
-        let attr = start.try_get_attribute("extracted")?;
-        let extracted = if let Some(extracted) = attr {
-            match extracted.unescape_value()?.as_ref() {
-                "true" | "1" => true,
-                _ => false,
-            }
-        } else {
-            false
-        };
-        person.set_extracted(extracted);
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Empty(e) => {
-                    log::debug!("read Empty={:?}", e);
-                    match e.name().as_ref() {
-                        b"analysis" => {
-                            let attr = e.try_get_attribute("resource")?;
-                            if let Some(value) = attr {
-                                person.set_analysis(DocumentReference::new(
-                                    IriRef::parse(value.unescape_value()?.into()).expect("iri"),
-                                ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"evidence" => {
-                            let attr = e.try_get_attribute("resource")?;
-                            if let Some(value) = attr {
-                                person.subject.add_evidence(EvidenceReference::new(
-                                    IriRef::parse(value.unescape_value()?.into()).expect("iri"),
-                                ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"gender" => {
-                            let attr = e.try_get_attribute("type")?;
-                            if let Some(value) = attr {
-                                person.set_gender(Gender::from_qname_uri(
-                                    value.unescape_value()?.as_ref(),
-                                ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"source" => {
-                            let attr = e.try_get_attribute("description")?;
-                            if let Some(source) = attr {
-                                person.add_source(SourceReference::new(
-                                    IriRef::parse(source.unescape_value()?.to_string())
-                                        .expect("iri"),
-                                    String::new(),
-                                ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::Start(e) => {
-                    log::debug!("read Start={:?}", e);
-                    match e.name().as_ref() {
-                        b"fact" => {
-                            log::trace!("found 'fact'");
-                            let fact = Fact::deserialize_xml_with_start(deserializer, &e)?;
-                            person.add_fact(fact);
-                        }
-                        b"name" => {
-                            log::trace!("found 'name'");
-                            let name = Name::deserialize_xml_with_start(deserializer, &e)?;
-                            person.add_name(name);
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::End(e) => match e.name().as_ref() {
-                    b"person" => {
-                        log::trace!("found end of 'person' returning ...");
-                        break;
-                    }
-                    _tag => log::trace!("skipping '{:?}' ...", e),
-                },
-                e => {
-                    log::trace!("got: {:?} skipping ...", e);
-                }
-            }
-        }
-        log::debug!("person = {:?}", person);
-        Ok(person)
-    }
-}
 
 #[cfg(test)]
 mod tests {
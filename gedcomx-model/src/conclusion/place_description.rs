@@ -0,0 +1,254 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::{
+    common::{
+        impl_extensions, refers_to, Extensions, Identifier, IriRef, Note, ResourceReference,
+        TextValue, Uri,
+    },
+    conclusion::{Conclusion, Date, PlaceReference},
+    gedcomx::Attribution,
+    source::SourceReference,
+    types::ConfidenceLevel,
+    Result,
+};
+
+use super::Subject;
+
+/// A description of a place, e.g. an entry in a gazetteer.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+#[xml(order(
+    subject,
+    names,
+    temporal_description,
+    latitude,
+    longitude,
+    spatial_description,
+    place,
+    jurisdiction
+))]
+pub struct PlaceDescription {
+    #[serde(flatten)]
+    #[xml(flatten)]
+    subject: Subject,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "name")]
+    names: Vec<TextValue>,
+    #[xml(attribute)]
+    r#type: Option<Uri>,
+    /// The place itself, e.g. a reference to an external gazetteer entry.
+    place: Option<ResourceReference>,
+    /// The place that has jurisdiction over this place.
+    jurisdiction: Option<ResourceReference>,
+    #[xml(text_element)]
+    latitude: Option<f64>,
+    #[xml(text_element)]
+    longitude: Option<f64>,
+    /// The period of time this description is relevant to.
+    temporal_description: Option<Date>,
+    /// A geographic area, e.g. a KML document, describing the extent of the place.
+    spatial_description: Option<ResourceReference>,
+    #[xml(attribute)]
+    id: IriRef,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
+}
+
+impl_extensions!(PlaceDescription);
+
+impl PlaceDescription {
+    pub fn new(id: IriRef) -> Self {
+        Self {
+            id,
+            subject: Subject::default(),
+            names: Vec::new(),
+            r#type: None,
+            place: None,
+            jurisdiction: None,
+            latitude: None,
+            longitude: None,
+            temporal_description: None,
+            spatial_description: None,
+            extensions: Extensions::default(),
+        }
+    }
+    pub fn with_id<S: Into<String>>(id: S) -> Result<Self> {
+        Ok(Self::new(IriRef::parse(id.into())?))
+    }
+}
+
+// Builder lite
+impl PlaceDescription {
+    pub fn identifier(mut self, identifier: Identifier) -> Self {
+        self.add_identifier(identifier);
+        self
+    }
+
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.subject
+            .conclusion_mut()
+            .set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.subject.conclusion_mut().set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.subject
+            .conclusion_mut()
+            .set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.subject.conclusion_mut().add_note(note.into());
+        self
+    }
+
+    pub fn extracted(mut self, yes: bool) -> Self {
+        self.subject.set_extracted(yes);
+        self
+    }
+
+    pub fn source<S: Into<SourceReference>>(mut self, source: S) -> Self {
+        self.subject.add_source(source.into());
+        self
+    }
+
+    pub fn name<T: Into<TextValue>>(mut self, name: T) -> Self {
+        self.add_name(name.into());
+        self
+    }
+
+    pub fn r#type(mut self, place_type: Uri) -> Self {
+        self.set_type(Some(place_type));
+        self
+    }
+
+    pub fn place<R: Into<ResourceReference>>(mut self, place: R) -> Self {
+        self.set_place(place.into());
+        self
+    }
+
+    pub fn jurisdiction<R: Into<ResourceReference>>(mut self, jurisdiction: R) -> Self {
+        self.set_jurisdiction(jurisdiction.into());
+        self
+    }
+
+    pub fn coordinates(mut self, latitude: f64, longitude: f64) -> Self {
+        self.set_coordinates(latitude, longitude);
+        self
+    }
+
+    pub fn temporal_description<D: Into<Date>>(mut self, date: D) -> Self {
+        self.set_temporal_description(date.into());
+        self
+    }
+
+    pub fn spatial_description<R: Into<ResourceReference>>(mut self, spatial: R) -> Self {
+        self.set_spatial_description(spatial.into());
+        self
+    }
+}
+
+impl PlaceDescription {
+    pub fn add_identifier(&mut self, identifier: Identifier) {
+        self.subject.add_identifier(identifier);
+    }
+    pub fn identifiers(&self) -> &[Identifier] {
+        self.subject.identifiers()
+    }
+    pub fn subject(&self) -> &Subject {
+        &self.subject
+    }
+    pub fn conclusion(&self) -> &Conclusion {
+        self.subject.conclusion()
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        self.subject.conclusion_mut()
+    }
+
+    pub fn add_name(&mut self, name: TextValue) {
+        self.names.push(name);
+    }
+    pub fn set_type(&mut self, place_type: Option<Uri>) {
+        self.r#type = place_type;
+    }
+    pub fn set_place(&mut self, place: ResourceReference) {
+        self.place = Some(place);
+    }
+    pub fn set_jurisdiction(&mut self, jurisdiction: ResourceReference) {
+        self.jurisdiction = Some(jurisdiction);
+    }
+    pub fn set_coordinates(&mut self, latitude: f64, longitude: f64) {
+        self.latitude = Some(latitude);
+        self.longitude = Some(longitude);
+    }
+    pub fn set_temporal_description(&mut self, date: Date) {
+        self.temporal_description = Some(date);
+    }
+    pub fn set_spatial_description(&mut self, spatial: ResourceReference) {
+        self.spatial_description = Some(spatial);
+    }
+
+    pub fn id(&self) -> &IriRef {
+        &self.id
+    }
+    pub fn names(&self) -> &[TextValue] {
+        self.names.as_slice()
+    }
+    pub fn get_type(&self) -> Option<&Uri> {
+        self.r#type.as_ref()
+    }
+    pub fn get_place(&self) -> Option<&ResourceReference> {
+        self.place.as_ref()
+    }
+    pub fn get_jurisdiction(&self) -> Option<&ResourceReference> {
+        self.jurisdiction.as_ref()
+    }
+    pub fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+    pub fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+    pub fn get_temporal_description(&self) -> Option<&Date> {
+        self.temporal_description.as_ref()
+    }
+    pub fn get_spatial_description(&self) -> Option<&ResourceReference> {
+        self.spatial_description.as_ref()
+    }
+
+    /// Whether `place` refers to this description, either by the full id or,
+    /// for local references like `#PD-1`, by fragment.
+    pub fn is_referenced_by(&self, place: &PlaceReference) -> bool {
+        match place.get_description_ref() {
+            Some(description_ref) => refers_to(description_ref, self.id.as_str()),
+            None => false,
+        }
+    }
+}
+
+impl From<&PlaceDescription> for ResourceReference {
+    fn from(place: &PlaceDescription) -> Self {
+        ResourceReference::new(place.id.clone())
+    }
+}
+
+/// A reference to the place, with its first name as the original text.
+impl From<&PlaceDescription> for PlaceReference {
+    fn from(place: &PlaceDescription) -> Self {
+        let reference = PlaceReference::new().description_ref(place.id.clone());
+        match place.names.first() {
+            Some(name) => reference.original(name.value()),
+            None => reference,
+        }
+    }
+}
@@ -1,57 +1,129 @@
+use std::{error::Error as StdError, fmt};
+
 use crate::{
-    common::{EvidenceReference, IriRef, ResourceReference},
-    conclusion::{Fact},
-    ser::{xml, SerError, SerializeXml},
-    types::{RelationshipType},
+    common::{
+        impl_extensions, refers_to, EvidenceReference, Extensions, Identifier, IriRef, Note,
+        ResourceReference,
+    },
+    conclusion::{Conclusion, Fact, Person},
+    gedcomx::{Attribution, GedcomX},
+    source::SourceReference,
+    types::{ConfidenceLevel, RelationshipType},
 };
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, Event};
-use std::io;
+use deserx::{DeserializeXml, SerializeXml};
 
 use super::{DocumentReference, Subject};
 
+/// A relationship between two persons.
+///
+/// What the participants mean depends on the type, e.g. for
+/// [`ParentChild`](RelationshipType::ParentChild) `person1` is the parent and
+/// `person2` the child, see [`parent_child`](Self::parent_child).
 #[serde_with::skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(order(subject, person1, person2, facts))]
 pub struct Relationship {
-    // #[serde(default)]
-    // id: Option<String>,
+    #[xml(attribute)]
     r#type: RelationshipType,
     #[serde(flatten)]
+    #[xml(flatten)]
     subject: Subject,
-    person1: Option<ResourceReference>,
-    person2: Option<ResourceReference>,
-    source: Option<ResourceReference>,
+    person1: ResourceReference,
+    person2: ResourceReference,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "fact")]
     facts: Vec<Fact>,
+    #[xml(attribute)]
+    id: Option<IriRef>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(Relationship);
+
 impl Relationship {
-    pub fn new(r#type: RelationshipType) -> Self {
+    pub fn new<R1, R2>(r#type: RelationshipType, person1: R1, person2: R2) -> Self
+    where
+        R1: Into<ResourceReference>,
+        R2: Into<ResourceReference>,
+    {
         Self {
-            // id: None,
             r#type,
             subject: Subject::default(),
-            person1: None,
-            person2: None,
-            source: None,
+            person1: person1.into(),
+            person2: person2.into(),
             facts: Vec::new(),
+            id: None,
+            extensions: Extensions::default(),
         }
     }
+
+    /// A [`ParentChild`](RelationshipType::ParentChild) relationship.
+    pub fn parent_child<P, C>(parent: P, child: C) -> Self
+    where
+        P: Into<ResourceReference>,
+        C: Into<ResourceReference>,
+    {
+        Self::new(RelationshipType::ParentChild, parent, child)
+    }
+
+    /// A [`Couple`](RelationshipType::Couple) relationship.
+    pub fn couple<A, B>(a: A, b: B) -> Self
+    where
+        A: Into<ResourceReference>,
+        B: Into<ResourceReference>,
+    {
+        Self::new(RelationshipType::Couple, a, b)
+    }
 }
 
 // Builder lite
 impl Relationship {
-    // pub fn id<S: Into<String>>(mut self, id: S) -> Self {
-    //     self.set_id(id.into());
-    //     self
-    // }
+    pub fn id(mut self, id: IriRef) -> Self {
+        self.set_id(Some(id));
+        self
+    }
+
+    pub fn identifier(mut self, identifier: Identifier) -> Self {
+        self.add_identifier(identifier);
+        self
+    }
+
+    pub fn confidence(mut self, confidence: ConfidenceLevel) -> Self {
+        self.subject
+            .conclusion_mut()
+            .set_confidence(Some(confidence));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.subject.conclusion_mut().set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.subject
+            .conclusion_mut()
+            .set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.subject.conclusion_mut().add_note(note.into());
+        self
+    }
+
     pub fn extracted(mut self, yes: bool) -> Self {
         self.subject = self.subject.extracted(yes);
         self
     }
 
-    pub fn source<S: Into<ResourceReference>>(mut self, source: S) -> Self {
-        self.source = Some(source.into());
+    pub fn source<S: Into<SourceReference>>(mut self, source: S) -> Self {
+        self.subject.add_source(source.into());
         self
     }
     pub fn analysis<S: Into<DocumentReference>>(mut self, analysis: S) -> Self {
@@ -62,31 +134,41 @@ impl Relationship {
         self.subject.add_evidence(evidence.into());
         self
     }
-    pub fn person1<R: Into<ResourceReference>>(mut self, person1: R) -> Self {
-        self.set_person1(person1.into());
-        self
-    }
-
-    pub fn person2<R: Into<ResourceReference>>(mut self, person2: R) -> Self {
-        self.set_person2(person2.into());
-        self
-    }
 
     pub fn fact(mut self, fact: Fact) -> Self {
-        self.facts.push(fact);
+        self.add_fact(fact);
         self
     }
 }
 
 impl Relationship {
-    // pub fn set_id(&mut self, id: String) {
-    //     self.id = Some(id);
-    // }
+    pub fn add_identifier(&mut self, identifier: Identifier) {
+        self.subject.add_identifier(identifier);
+    }
+    pub fn identifiers(&self) -> &[Identifier] {
+        self.subject.identifiers()
+    }
+    pub fn subject(&self) -> &Subject {
+        &self.subject
+    }
+    pub fn conclusion(&self) -> &Conclusion {
+        self.subject.conclusion()
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        self.subject.conclusion_mut()
+    }
+
+    pub fn set_id(&mut self, id: Option<IriRef>) {
+        self.id = id;
+    }
     pub fn set_person1(&mut self, person1: ResourceReference) {
-        self.person1 = Some(person1);
+        self.person1 = person1;
     }
     pub fn set_person2(&mut self, person2: ResourceReference) {
-        self.person2 = Some(person2);
+        self.person2 = person2;
+    }
+    pub fn add_fact(&mut self, fact: Fact) {
+        self.facts.push(fact);
     }
     pub fn is_extracted(&self) -> bool {
         self.subject.is_extracted()
@@ -94,156 +176,169 @@ impl Relationship {
     pub fn set_analysis(&mut self, analysis: DocumentReference) {
         self.subject.set_analysis(analysis.into());
     }
-}
 
-// impl From<&Relationship> for EvidenceReference {
-//     fn from(p: &Relationship) -> Self {
-//         EvidenceReference::with_resource(format!("#{}", p.id))
-//     }
-// }
-impl SerializeXml for Relationship {
-    fn tag(&self) -> &str {
-        "relationship"
+    pub fn get_id(&self) -> Option<&IriRef> {
+        self.id.as_ref()
+    }
+    pub fn get_type(&self) -> &RelationshipType {
+        &self.r#type
+    }
+    pub fn person1(&self) -> &ResourceReference {
+        &self.person1
+    }
+    pub fn person2(&self) -> &ResourceReference {
+        &self.person2
+    }
+    pub fn facts(&self) -> &[Fact] {
+        self.facts.as_slice()
     }
 
-    fn serialize_xml<W: io::Write>(&self, ser: &mut quick_xml::Writer<W>) -> Result<(), SerError> {
-        let mut root = BytesStart::new(self.tag());
-        if self.is_extracted() {
-            root.push_attribute(("extracted", self.subject.extracted_as_str()));
-        }
-        root.push_attribute(("type", self.r#type.as_qname_uri()));
-        ser.write_event(Event::Start(root))?;
+    /// The parent, if this is a parent-child relationship.
+    pub fn parent(&self) -> Option<&ResourceReference> {
+        self.is_parent_child().then_some(&self.person1)
+    }
+    /// The child, if this is a parent-child relationship.
+    pub fn child(&self) -> Option<&ResourceReference> {
+        self.is_parent_child().then_some(&self.person2)
+    }
+    /// Both partners, if this is a couple relationship.
+    pub fn partners(&self) -> Option<[&ResourceReference; 2]> {
+        (self.r#type == RelationshipType::Couple).then_some([&self.person1, &self.person2])
+    }
+    fn is_parent_child(&self) -> bool {
+        self.r#type == RelationshipType::ParentChild
+    }
 
-        if let Some(person1) = self.person1.as_ref() {
-            xml::write_elem_w_attribute(ser, "person1", ("resource", person1.resource()))?;
+    /// Whether `reference` refers to this relationship, either by the full id
+    /// or, for local references like `#R-1`, by fragment.
+    pub fn is_referenced_by(&self, reference: &ResourceReference) -> bool {
+        match &self.id {
+            Some(id) => refers_to(reference.iri(), id.as_str()),
+            None => false,
         }
-        if let Some(person2) = self.person2.as_ref() {
-            xml::write_elem_w_attribute(ser, "person2", ("resource", person2.resource()))?;
-        }
-        self.subject.serialize_xml(ser)?;
+    }
+
+    /// Whether `person` takes part in this relationship.
+    pub fn involves(&self, person: &Person) -> bool {
+        person.is_referenced_by(&self.person1) || person.is_referenced_by(&self.person2)
+    }
+
+    pub fn resolve_person1<'a>(&self, gedcomx: &'a GedcomX) -> Option<&'a Person> {
+        gedcomx.resolve_person(&self.person1)
+    }
+    pub fn resolve_person2<'a>(&self, gedcomx: &'a GedcomX) -> Option<&'a Person> {
+        gedcomx.resolve_person(&self.person2)
+    }
+    pub fn resolve_parent<'a>(&self, gedcomx: &'a GedcomX) -> Option<&'a Person> {
+        self.parent()
+            .and_then(|parent| gedcomx.resolve_person(parent))
+    }
+    pub fn resolve_child<'a>(&self, gedcomx: &'a GedcomX) -> Option<&'a Person> {
+        self.child().and_then(|child| gedcomx.resolve_person(child))
+    }
 
-        if let Some(source) = self.source.as_ref() {
-            let mut source_elem = BytesStart::new("source");
-            source_elem.push_attribute(("description", source.resource()));
-            ser.write_event(Event::Empty(source_elem))?;
+    /// Checks that the participants are two different persons.
+    ///
+    /// References are compared as written, except that `#P-1` and `P-1` are
+    /// taken to be the same person, see [`validate_in`](Self::validate_in)
+    /// to compare the persons they resolve to.
+    pub fn validate(&self) -> Result<(), RelationshipError> {
+        if local_id(&self.person1) == local_id(&self.person2) {
+            return Err(RelationshipError::SameParticipant(
+                self.person1.iri().clone(),
+            ));
         }
+        Ok(())
+    }
 
-        for fact in &self.facts {
-            fact.serialize_xml(ser)?;
+    /// Checks the participants against `gedcomx`.
+    ///
+    /// Besides [`validate`](Self::validate), both participants must be
+    /// different persons in the document and, for directed types like
+    /// [`ParentChild`](RelationshipType::ParentChild), the document must not
+    /// have the same relationship in the other direction, e.g. a child that is
+    /// also the parent of its parent.
+    pub fn validate_in(&self, gedcomx: &GedcomX) -> Result<(), RelationshipError> {
+        self.validate()?;
+        let resolve = |participant: &ResourceReference| {
+            gedcomx
+                .resolve_person(participant)
+                .ok_or_else(|| RelationshipError::UnresolvedParticipant(participant.iri().clone()))
+        };
+        let person1 = resolve(&self.person1)?;
+        let person2 = resolve(&self.person2)?;
+        if std::ptr::eq(person1, person2) {
+            return Err(RelationshipError::SameParticipant(
+                self.person1.iri().clone(),
+            ));
+        }
+        if self.is_directed() {
+            let is_person = |reference: &ResourceReference, person: &Person| {
+                gedcomx
+                    .resolve_person(reference)
+                    .is_some_and(|resolved| std::ptr::eq(resolved, person))
+            };
+            let reversed = gedcomx.relationships().iter().any(|other| {
+                other.r#type == self.r#type
+                    && is_person(&other.person1, person2)
+                    && is_person(&other.person2, person1)
+            });
+            if reversed {
+                return Err(RelationshipError::Reversed(self.r#type.clone()));
+            }
         }
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
         Ok(())
     }
+
+    fn is_directed(&self) -> bool {
+        matches!(
+            self.r#type,
+            RelationshipType::ParentChild
+                | RelationshipType::AncestorDescendant
+                | RelationshipType::Godparent
+                | RelationshipType::EnslavedBy
+        )
+    }
 }
-impl DeserializeXml for Relationship {
-    fn deserialize_xml_with_start<'de, R: std::io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-        start: &quick_xml::events::BytesStart<'de>,
-    ) -> Result<Self, quick_xml::Error> {
-        //     <R: std::io::BufRead>(
-        //     deserializer: &mut quick_xml::Reader<R>,
-        // ) -> Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
-        let attr = start.try_get_attribute("type")?;
-        let relationship_type = if let Some(value) = attr {
-            RelationshipType::from_qname_uri(value.unescape_value()?.as_ref())
-        } else {
-            todo!("handle no 'id'")
-        };
-        let mut relationship = Self::new(relationship_type);
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Empty(e) => {
-                    log::debug!("read Empty={:?}", e);
-                    match e.name().as_ref() {
-                        b"person1" => {
-                            let attr = e.try_get_attribute("resource")?;
-                            if let Some(value) = attr {
-                                relationship.set_person1(ResourceReference::new(
-                                    IriRef::parse(value.unescape_value()?.into()).expect("iri"),
-                                ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"person2" => {
-                            let attr = e.try_get_attribute("resource")?;
-                            if let Some(value) = attr {
-                                relationship.set_person2(ResourceReference::new(
-                                    IriRef::parse(value.unescape_value()?.into()).expect("iri"),
-                                ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"evidence" => {
-                            let attr = e.try_get_attribute("resource")?;
-                            if let Some(_value) = attr {
-                                // relationship.subject.add_evidence(
-                                //     EvidenceReference::with_resource(
-                                //         value.unescape_value()?.into(),
-                                //     ),
-                                // );
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"gender" => {
-                            let attr = e.try_get_attribute("type")?;
-                            if let Some(_value) = attr {
-                                // relationship.set_gender(Gender::from_qname_uri(
-                                //     value.unescape_value()?.as_ref(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"source" => {
-                            let attr = e.try_get_attribute("description")?;
-                            if let Some(_source) = attr {
-                                // relationship.add_source(SourceReference::new(
-                                //     Uri::new(source.unescape_value()?.to_string()),
-                                //     String::new(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::Start(e) => {
-                    log::debug!("read Start={:?}", e);
-                    match e.name().as_ref() {
-                        b"fact" => {
-                            log::trace!("found 'fact'");
-                            // let fact = Fact::deserialize_xml_with_start(deserializer, &e)?;
-                            // relationship.add_fact(fact);
-                        }
-                        b"name" => {
-                            log::trace!("found 'name'");
-                            // let name = Name::deserialize_xml_with_start(deserializer, &e)?;
-                            // relationship.add_name(name);
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::End(e) => match e.name().as_ref() {
-                    b"relationship" => {
-                        log::trace!("found end of 'relationship' returning ...");
-                        break;
-                    }
-                    _tag => log::trace!("skipping '{:?}' ...", e),
-                },
-                e => {
-                    log::trace!("got: {:?} skipping ...", e);
-                }
+
+/// The resource of `reference` without the `#` of a reference within the
+/// same document.
+fn local_id(reference: &ResourceReference) -> &str {
+    let resource = reference.resource();
+    resource.strip_prefix('#').unwrap_or(resource)
+}
+
+/// Why the participants of a [`Relationship`] do not make sense.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelationshipError {
+    /// Both participants are the same person.
+    SameParticipant(IriRef),
+    /// A participant is not a person in the document.
+    UnresolvedParticipant(IriRef),
+    /// The document has the same relationship with the participants swapped.
+    Reversed(RelationshipType),
+}
+
+impl fmt::Display for RelationshipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SameParticipant(person) => {
+                write!(f, "'{}' is both participants of a relationship", person)
+            }
+            Self::UnresolvedParticipant(person) => {
+                write!(f, "relationship participant '{}' is not a person", person)
             }
+            Self::Reversed(relationship_type) => write!(
+                f,
+                "a '{}' relationship is also given in the other direction",
+                relationship_type
+            ),
         }
-        log::debug!("relationship = {:?}", relationship);
-        Ok(relationship)
     }
 }
+
+impl StdError for RelationshipError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
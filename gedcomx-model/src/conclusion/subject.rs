@@ -1,46 +1,89 @@
+use deserx::{DeserializeXml, SerializeXml};
+
 use crate::{
-    common::{EvidenceReference, ResourceReference},
+    common::{identifier_map, EvidenceReference, Identifier, Note, ResourceReference},
     conclusion::Conclusion,
-    ser::{SerError, SerializeXml},
+    gedcomx::Attribution,
     source::SourceReference,
+    types::ConfidenceLevel,
 };
 
-use serde::Deserializer;
-use std::{borrow::Cow, io};
-
 pub fn is_default<T: Default + PartialEq>(t: &T) -> bool {
     t == &T::default()
 }
-pub fn deserialize_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    use serde::Deserialize;
-
-    let s: Cow<String> = Deserialize::deserialize(deserializer)?;
-
-    match s.as_str() {
-        "true" => Ok(true),
-        "false" => Ok(false),
-        _ => Err(serde::de::Error::unknown_variant(
-            s.as_str(),
-            &["true", "false"],
-        )),
-    }
-}
-#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(from = "SubjectFields")]
+#[xml(order(conclusion, evidence, identifiers))]
 pub struct Subject {
     #[serde(default, skip_serializing_if = "is_default")]
+    #[xml(attribute, default, skip_serializing_if = "is_default")]
     extracted: bool,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     evidence: Vec<EvidenceReference>,
     #[serde(flatten)]
+    #[xml(flatten)]
     conclusion: Conclusion,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        with = "identifier_map"
+    )]
+    #[xml(rename = "identifier")]
+    identifiers: Vec<Identifier>,
     // gender: Option<Gender>,
     // names: Vec<Name>,
     // facts: Vec<Fact>,
 }
 
+/// The JSON fields of a [`Subject`] with the conclusion fields inlined.
+///
+/// Reading them as one struct rather than through a nested `flatten` lets
+/// serde mark them as consumed, so they are not also captured as extensions
+/// by the types that flatten a subject.
+#[derive(serde::Deserialize)]
+struct SubjectFields {
+    #[serde(default)]
+    extracted: bool,
+    #[serde(default)]
+    evidence: Vec<EvidenceReference>,
+    confidence: Option<ConfidenceLevel>,
+    lang: Option<String>,
+    attribution: Option<Attribution>,
+    analysis: Option<ResourceReference>,
+    #[serde(default)]
+    sources: Vec<SourceReference>,
+    #[serde(default)]
+    notes: Vec<Note>,
+    #[serde(default, with = "identifier_map")]
+    identifiers: Vec<Identifier>,
+}
+
+impl From<SubjectFields> for Subject {
+    fn from(fields: SubjectFields) -> Self {
+        let mut conclusion = Conclusion::new();
+        conclusion.set_confidence(fields.confidence);
+        conclusion.set_lang(fields.lang);
+        conclusion.set_attribution(fields.attribution);
+        if let Some(analysis) = fields.analysis {
+            conclusion.set_analysis(analysis);
+        }
+        for source in fields.sources {
+            conclusion.add_source(source);
+        }
+        for note in fields.notes {
+            conclusion.add_note(note);
+        }
+        Self {
+            extracted: fields.extracted,
+            evidence: fields.evidence,
+            conclusion,
+            identifiers: fields.identifiers,
+        }
+    }
+}
+
 impl Subject {
     pub fn new() -> Self {
         Self {
@@ -48,6 +91,7 @@ impl Subject {
             // id: String::new(),
             extracted: false,
             evidence: Vec::new(),
+            identifiers: Vec::new(),
             // gender: None,
             // names: Vec::new(),
             // facts: Vec::new(),
@@ -76,6 +120,10 @@ impl Subject {
         self.add_evidence(evidence.into());
         self
     }
+    pub fn identifier(mut self, identifier: Identifier) -> Self {
+        self.add_identifier(identifier);
+        self
+    }
     // pub fn gender(mut self, gender: Gender) -> Self {
     //     self.gender = Some(gender);
     //     self
@@ -109,12 +157,29 @@ impl Subject {
     pub fn add_evidence(&mut self, evidence: EvidenceReference) {
         self.evidence.push(evidence);
     }
+    pub fn add_identifier(&mut self, identifier: Identifier) {
+        self.identifiers.push(identifier);
+    }
+    pub fn identifiers(&self) -> &[Identifier] {
+        self.identifiers.as_slice()
+    }
+    /// Whether one of the identifiers matches `identifier`, see
+    /// [`Identifier::matches`].
+    pub fn has_identifier(&self, identifier: &Identifier) -> bool {
+        self.identifiers.iter().any(|own| own.matches(identifier))
+    }
     pub fn extracted_as_str(&self) -> &'static str {
         bool_as_str(self.extracted)
     }
     pub fn is_extracted(&self) -> bool {
         self.extracted
     }
+    pub fn conclusion(&self) -> &Conclusion {
+        &self.conclusion
+    }
+    pub fn conclusion_mut(&mut self) -> &mut Conclusion {
+        &mut self.conclusion
+    }
 }
 pub fn bool_as_str(b: bool) -> &'static str {
     if b {
@@ -123,17 +188,3 @@ pub fn bool_as_str(b: bool) -> &'static str {
         "false"
     }
 }
-impl SerializeXml for Subject {
-    fn tag(&self) -> &str {
-        "subject"
-    }
-
-    fn serialize_xml<W: io::Write>(&self, ser: &mut quick_xml::Writer<W>) -> Result<(), SerError> {
-        self.conclusion.serialize_xml(ser)?;
-
-        for evidence in &self.evidence {
-            evidence.serialize_xml(ser)?;
-        }
-        Ok(())
-    }
-}
@@ -0,0 +1,145 @@
+use std::{fmt, str::FromStr};
+
+use super::{parse::Parser, DateParseError};
+
+/// A length of time, e.g. `P10Y6M` or `PT3H`.
+///
+/// Only the components that were given are written back.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Duration {
+    years: Option<u32>,
+    months: Option<u32>,
+    days: Option<u32>,
+    hours: Option<u32>,
+    minutes: Option<u32>,
+    seconds: Option<u32>,
+}
+
+impl Duration {
+    pub fn years(&self) -> u32 {
+        self.years.unwrap_or(0)
+    }
+    pub fn months(&self) -> u32 {
+        self.months.unwrap_or(0)
+    }
+    pub fn days(&self) -> u32 {
+        self.days.unwrap_or(0)
+    }
+    pub fn hours(&self) -> u32 {
+        self.hours.unwrap_or(0)
+    }
+    pub fn minutes(&self) -> u32 {
+        self.minutes.unwrap_or(0)
+    }
+    pub fn seconds(&self) -> u32 {
+        self.seconds.unwrap_or(0)
+    }
+
+    pub(crate) fn parse_from(parser: &mut Parser<'_>) -> Result<Self, DateParseError> {
+        parser.expect(b'P', "expected 'P' before a duration")?;
+        let mut duration = Self::default();
+        let mut in_time = false;
+        // Components must come in order, this is the index of the next allowed one.
+        let mut next = 0;
+        while !parser.is_at_end() && parser.peek() != Some(b'/') {
+            if !in_time && parser.eat(b'T') {
+                in_time = true;
+                next = 3;
+                if parser.is_at_end() || parser.peek() == Some(b'/') {
+                    return Err(parser.error("expected a time component after 'T'"));
+                }
+                continue;
+            }
+            let value = parser.number("expected a number")?;
+            let index = match (in_time, parser.peek()) {
+                (false, Some(b'Y')) => 0,
+                (false, Some(b'M')) => 1,
+                (false, Some(b'D')) => 2,
+                (true, Some(b'H')) => 3,
+                (true, Some(b'M')) => 4,
+                (true, Some(b'S')) => 5,
+                _ => return Err(parser.error("expected a duration designator")),
+            };
+            if index < next {
+                return Err(parser.error("duration components are out of order"));
+            }
+            parser.eat(parser.peek().expect("designator"));
+            next = index + 1;
+            *duration.component_mut(index) = Some(value);
+        }
+        if duration == Self::default() {
+            return Err(parser.error("expected at least one duration component"));
+        }
+        Ok(duration)
+    }
+
+    fn component_mut(&mut self, index: usize) -> &mut Option<u32> {
+        match index {
+            0 => &mut self.years,
+            1 => &mut self.months,
+            2 => &mut self.days,
+            3 => &mut self.hours,
+            4 => &mut self.minutes,
+            _ => &mut self.seconds,
+        }
+    }
+}
+
+impl FromStr for Duration {
+    type Err = DateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let duration = Self::parse_from(&mut parser)?;
+        parser.end()?;
+        Ok(duration)
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("P")?;
+        for (value, designator) in [(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if let Some(value) = value {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+        if self.hours.is_some() || self.minutes.is_some() || self.seconds.is_some() {
+            f.write_str("T")?;
+        }
+        for (value, designator) in [(self.hours, 'H'), (self.minutes, 'M'), (self.seconds, 'S')] {
+            if let Some(value) = value {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_writes_components() {
+        let duration: Duration = "P1Y2M3DT4H5M6S".parse().unwrap();
+        assert_eq!(
+            (duration.years(), duration.months(), duration.days()),
+            (1, 2, 3)
+        );
+        assert_eq!(
+            (duration.hours(), duration.minutes(), duration.seconds()),
+            (4, 5, 6)
+        );
+        assert_eq!(duration.to_string(), "P1Y2M3DT4H5M6S");
+        assert_eq!("PT30M".parse::<Duration>().unwrap().to_string(), "PT30M");
+        assert_eq!("P0D".parse::<Duration>().unwrap().to_string(), "P0D");
+    }
+
+    #[test]
+    fn rejects_malformed_durations() {
+        for invalid in ["P", "PT", "P1H", "P1M1Y", "P1DT", "10Y", "P1Y2"] {
+            assert!(invalid.parse::<Duration>().is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! The GEDCOM X Date Format, used by the `formal` field of
+//! [`Date`](crate::conclusion::Date).
+//!
+//! ```
+//! use gedcomx_model::date::FormalDate;
+//!
+//! let range: FormalDate = "+1840/+1850".parse().unwrap();
+//! let birth: FormalDate = "A+1843-06".parse().unwrap();
+//! assert!(range.contains(&birth));
+//! assert_eq!(birth.to_string(), "A+1843-06");
+//! ```
+use std::{cmp::Ordering, error::Error as StdError, fmt, str::FromStr};
+
+mod duration;
+mod normalize;
+mod parse;
+mod range;
+mod recurring;
+mod simple;
+
+pub use duration::Duration;
+pub use normalize::{DateNormalizer, NormalizeError};
+pub use range::{DateRange, RangeEnd};
+pub use recurring::RecurringDate;
+pub use simple::{SimpleDate, Time, TimeZone};
+
+use parse::Parser;
+
+/// A formal date.
+///
+/// Dates are compared by the period they cover, e.g. `+1843` covers the whole
+/// year, approximate dates cover the same period as the exact date.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde_with::DeserializeFromStr,
+    serde_with::SerializeDisplay,
+)]
+pub enum FormalDate {
+    /// `+1843-06-23`
+    Simple(SimpleDate),
+    /// `A+1843`
+    Approximate(SimpleDate),
+    /// `+1840/+1850`
+    Range(DateRange),
+    /// `R3/+1900/P1Y`
+    Recurring(RecurringDate),
+}
+
+impl FormalDate {
+    pub fn is_approximate(&self) -> bool {
+        match self {
+            Self::Approximate(_) => true,
+            Self::Range(range) => range.is_approximate(),
+            Self::Simple(_) | Self::Recurring(_) => false,
+        }
+    }
+
+    /// The first second covered and the first second after, `None` where the
+    /// date is open.
+    fn span(&self) -> (Option<i64>, Option<i64>) {
+        match self {
+            Self::Simple(date) | Self::Approximate(date) => {
+                (Some(date.start_instant()), Some(date.end_instant()))
+            }
+            Self::Range(range) => range.span(),
+            Self::Recurring(recurring) => recurring.span(),
+        }
+    }
+
+    /// Orders dates by when they start, then by when they end.
+    ///
+    /// An open start comes first and an open end comes last.
+    pub fn chronological_cmp(&self, other: &FormalDate) -> Ordering {
+        let (start, end) = self.span();
+        let (other_start, other_end) = other.span();
+        start
+            .cmp(&other_start)
+            .then_with(|| match (end, other_end) {
+                (Some(end), Some(other_end)) => end.cmp(&other_end),
+                (end, other_end) => other_end.is_some().cmp(&end.is_some()),
+            })
+    }
+
+    /// Whether the whole period of `other` lies within this date, e.g. whether
+    /// `+1840/+1850` includes `+1843-06-23`.
+    pub fn contains(&self, other: &FormalDate) -> bool {
+        let (start, end) = self.span();
+        let (other_start, other_end) = other.span();
+        let starts_before = match (start, other_start) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(start), Some(other_start)) => start <= other_start,
+        };
+        let ends_after = match (end, other_end) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(end), Some(other_end)) => other_end <= end,
+        };
+        starts_before && ends_after
+    }
+}
+
+impl FromStr for FormalDate {
+    type Err = DateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let date = if parser.peek() == Some(b'R') {
+            Self::Recurring(RecurringDate::parse_from(&mut parser)?)
+        } else {
+            let approximate = parser.eat(b'A');
+            if s.contains('/') {
+                Self::Range(DateRange::parse_from(&mut parser)?.approximate(approximate))
+            } else if approximate {
+                Self::Approximate(SimpleDate::parse_from(&mut parser)?)
+            } else {
+                Self::Simple(SimpleDate::parse_from(&mut parser)?)
+            }
+        };
+        parser.end()?;
+        Ok(date)
+    }
+}
+
+impl fmt::Display for FormalDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Simple(date) => date.fmt(f),
+            Self::Approximate(date) => write!(f, "A{}", date),
+            Self::Range(range) => range.fmt(f),
+            Self::Recurring(recurring) => recurring.fmt(f),
+        }
+    }
+}
+
+impl From<SimpleDate> for FormalDate {
+    fn from(date: SimpleDate) -> Self {
+        Self::Simple(date)
+    }
+}
+
+impl From<DateRange> for FormalDate {
+    fn from(range: DateRange) -> Self {
+        Self::Range(range)
+    }
+}
+
+impl From<RecurringDate> for FormalDate {
+    fn from(recurring: RecurringDate) -> Self {
+        Self::Recurring(recurring)
+    }
+}
+
+impl deserx::XmlValue for FormalDate {
+    fn from_xml_value(value: &str) -> deserx::Result<Self> {
+        value
+            .parse()
+            .map_err(|err: DateParseError| deserx::Error::invalid_date(value, err.reason()))
+    }
+
+    fn to_xml_value(&self) -> std::borrow::Cow<'_, str> {
+        self.to_string().into()
+    }
+}
+
+/// A string that is not in the GEDCOM X Date Format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateParseError {
+    value: String,
+    position: usize,
+    reason: &'static str,
+}
+
+impl DateParseError {
+    pub(crate) fn new(value: &str, position: usize, reason: &'static str) -> Self {
+        Self {
+            value: value.into(),
+            position,
+            reason,
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+    /// The byte offset in the value where parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+    pub fn reason(&self) -> &str {
+        self.reason
+    }
+}
+
+impl fmt::Display for DateParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid formal date '{}': {} at position {}",
+            self.value, self.reason, self.position
+        )
+    }
+}
+
+impl StdError for DateParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> FormalDate {
+        s.parse().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    #[test]
+    fn canonical_strings_round_trip() {
+        for formal in [
+            "+1843",
+            "-0100-03",
+            "+1843-06-23",
+            "+1843-06-23T12",
+            "+1843-06-23T12:30:15Z",
+            "+1843-06-23T12:30-05:00",
+            "A+1843",
+            "+1840/+1850",
+            "A+1840/+1850",
+            "+1840/",
+            "/+1850",
+            "+1840-06/P10Y6M",
+            "R/+1900/P1Y",
+            "R3/+1900-01-01/+1900-06-30",
+        ] {
+            assert_eq!(date(formal).to_string(), formal);
+        }
+        assert_eq!(
+            date("+1843-06-23T12+01").to_string(),
+            "+1843-06-23T12+01:00"
+        );
+    }
+
+    #[test]
+    fn parses_into_variants() {
+        assert!(matches!(date("+1843"), FormalDate::Simple(d) if d.year() == 1843));
+        assert!(matches!(date("A-0044-03-15"), FormalDate::Approximate(d) if d.year() == -44));
+        let range = date("A+1840/");
+        assert!(range.is_approximate());
+        assert!(matches!(range, FormalDate::Range(r) if r.end().is_none()));
+        assert!(
+            matches!(date("R2/+1900/P1Y"), FormalDate::Recurring(r) if r.get_count() == Some(2))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        for invalid in [
+            "",
+            "1843",
+            "+184",
+            "+1843-13",
+            "+1843-02-29",
+            "+1843-06-23T25",
+            "+1843-06-23T24:01",
+            "+1843T12",
+            "/",
+            "/P1Y",
+            "A",
+            "+1843/+1850/+1860",
+            "R/+1900",
+            "R-1/+1900/P1Y",
+            "+1843 ",
+        ] {
+            assert!(invalid.parse::<FormalDate>().is_err(), "{:?}", invalid);
+        }
+        let err = "+1843-13".parse::<FormalDate>().unwrap_err();
+        assert_eq!(err.position(), 8);
+        assert_eq!(err.reason(), "month is out of range");
+        assert!("+1844-02-29".parse::<FormalDate>().is_ok());
+    }
+
+    #[test]
+    fn ranges_contain_dates() {
+        let decade = date("+1840/+1850");
+        assert!(decade.contains(&date("+1843-06-23")));
+        assert!(decade.contains(&date("+1850-12-31")));
+        assert!(decade.contains(&date("A+1845")));
+        assert!(!decade.contains(&date("+1851")));
+        assert!(!decade.contains(&date("+1839/+1841")));
+        assert!(date("+1840/").contains(&date("+2000")));
+        assert!(!date("+1840/").contains(&date("/+1850")));
+        assert!(date("/+1850").contains(&date("+1066")));
+        assert!(date("+1843").contains(&date("+1843-06-23T12:00:00Z")));
+        assert!(date("+1840/P10Y").contains(&date("+1849-12")));
+        assert!(!date("+1840/P10Y").contains(&date("+1850")));
+        assert!(date("R3/+1900/P1Y").contains(&date("+1902-06")));
+        assert!(!date("R3/+1900/P1Y").contains(&date("+1903")));
+        assert!(date("R/+1900/P1Y").contains(&date("+3000")));
+    }
+
+    #[test]
+    fn dates_sort_chronologically() {
+        let mut dates = [
+            date("+1850"),
+            date("+1843-06-23"),
+            date("/+1843"),
+            date("+1843"),
+            date("+1843/"),
+            date("-0001"),
+        ];
+        dates.sort_by(FormalDate::chronological_cmp);
+        let sorted: Vec<_> = dates.iter().map(FormalDate::to_string).collect();
+        assert_eq!(
+            sorted,
+            ["/+1843", "-0001", "+1843", "+1843/", "+1843-06-23", "+1850"]
+        );
+    }
+
+    #[test]
+    fn huge_recurrences_do_not_overflow() {
+        let forever = date("+1900/");
+        for huge in [
+            "R4294967295/-9999/+9999",
+            "R4294967295/+1900/P4294967295Y",
+            "R4294967295/+1900/P4294967295Y4294967295M",
+            "R4294967295/+1900/P4294967295DT4294967295S",
+        ] {
+            let huge = date(huge);
+            assert!(huge.contains(&date("+9999")));
+            assert!(!huge.contains(&forever));
+            assert_eq!(huge.chronological_cmp(&forever), Ordering::Less);
+        }
+    }
+}
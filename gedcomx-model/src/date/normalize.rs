@@ -0,0 +1,456 @@
+use std::{error::Error as StdError, fmt};
+
+use super::{DateRange, FormalDate, SimpleDate};
+
+/// Turns free-text dates, like the `original` of a
+/// [`Date`](crate::conclusion::Date), into formal dates.
+///
+/// Understands English and Scandinavian month names and qualifiers, e.g.
+/// `23 June 1843`, `abt 1790`, `bef. 3 MAR 1801`, `mellan 1840 och 1845` or
+/// `1843-06-23`. "Before" and "after" become open ranges that include the
+/// date itself.
+///
+/// Numeric dates separated by `/` or `-` can be read day or month first,
+/// e.g. `03/04/1850`. These are reported as ambiguous unless the order is
+/// given with [`day_first`](Self::day_first). Dates separated by `.` are
+/// always read day first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DateNormalizer {
+    day_first: Option<bool>,
+}
+
+/// Why a free-text date could not be normalized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NormalizeError {
+    /// The text is not a date this normalizer understands.
+    Unrecognized(String),
+    /// The text looks like a date that does not exist, e.g. `31 Feb 1850`.
+    InvalidDate(String),
+    /// The text can be read as more than one date.
+    Ambiguous {
+        original: String,
+        candidates: Vec<FormalDate>,
+    },
+}
+
+/// A qualifier in front of a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Qualifier {
+    Approximate,
+    Before,
+    After,
+    Between,
+}
+
+const QUALIFIERS: [(&str, Qualifier); 36] = [
+    ("abt", Qualifier::Approximate),
+    ("about", Qualifier::Approximate),
+    ("approx", Qualifier::Approximate),
+    ("approximately", Qualifier::Approximate),
+    ("around", Qualifier::Approximate),
+    ("c", Qualifier::Approximate),
+    ("ca", Qualifier::Approximate),
+    ("cal", Qualifier::Approximate),
+    ("calc", Qualifier::Approximate),
+    ("calculated", Qualifier::Approximate),
+    ("circa", Qualifier::Approximate),
+    ("cirka", Qualifier::Approximate),
+    ("est", Qualifier::Approximate),
+    ("estimated", Qualifier::Approximate),
+    ("omkr", Qualifier::Approximate),
+    ("omkring", Qualifier::Approximate),
+    ("omtrent", Qualifier::Approximate),
+    ("bef", Qualifier::Before),
+    ("before", Qualifier::Before),
+    ("før", Qualifier::Before),
+    ("före", Qualifier::Before),
+    ("inden", Qualifier::Before),
+    ("innan", Qualifier::Before),
+    ("aft", Qualifier::After),
+    ("after", Qualifier::After),
+    ("efter", Qualifier::After),
+    ("etter", Qualifier::After),
+    ("bet", Qualifier::Between),
+    ("btw", Qualifier::Between),
+    ("between", Qualifier::Between),
+    ("from", Qualifier::Between),
+    ("fra", Qualifier::Between),
+    ("från", Qualifier::Between),
+    ("mellan", Qualifier::Between),
+    ("mellem", Qualifier::Between),
+    ("mellom", Qualifier::Between),
+];
+
+/// Words between the start and the end of a range.
+const RANGE_SEPARATORS: [&str; 6] = ["and", "och", "og", "to", "till", "til"];
+
+/// Month names in English, Swedish, Danish and Norwegian; any prefix of at
+/// least three letters is accepted as an abbreviation.
+const MONTHS: [(&str, u8); 25] = [
+    ("january", 1),
+    ("januari", 1),
+    ("januar", 1),
+    ("february", 2),
+    ("februari", 2),
+    ("februar", 2),
+    ("march", 3),
+    ("mars", 3),
+    ("marts", 3),
+    ("april", 4),
+    ("may", 5),
+    ("maj", 5),
+    ("mai", 5),
+    ("june", 6),
+    ("juni", 6),
+    ("july", 7),
+    ("juli", 7),
+    ("august", 8),
+    ("augusti", 8),
+    ("september", 9),
+    ("october", 10),
+    ("oktober", 10),
+    ("november", 11),
+    ("december", 12),
+    ("desember", 12),
+];
+
+/// A year with an optional month and day.
+type Parts = (u32, Option<u8>, Option<u8>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    /// The value and the number of digits.
+    Number(u32, usize),
+    Separator(char),
+}
+
+impl DateNormalizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+// Builder lite
+impl DateNormalizer {
+    /// Reads ambiguous numeric dates day first (`true`) or month first.
+    pub fn day_first(mut self, yes: bool) -> Self {
+        self.day_first = Some(yes);
+        self
+    }
+}
+
+impl DateNormalizer {
+    pub fn normalize(&self, original: &str) -> Result<FormalDate, NormalizeError> {
+        let unrecognized = || NormalizeError::Unrecognized(original.into());
+        let mut tokens = tokenize(original).ok_or_else(unrecognized)?;
+        let qualifier = match tokens.first() {
+            Some(Token::Word(word)) => QUALIFIERS
+                .iter()
+                .find(|(name, _)| name == word)
+                .map(|(_, qualifier)| *qualifier),
+            _ => None,
+        };
+        if qualifier.is_some() {
+            tokens.remove(0);
+            if tokens.first() == Some(&Token::Separator('.')) {
+                tokens.remove(0);
+            }
+        }
+
+        let mut candidates = match (qualifier, split_range(&tokens, qualifier)) {
+            (None | Some(Qualifier::Between | Qualifier::Approximate), Some((start, end))) => {
+                let approximate = qualifier == Some(Qualifier::Approximate);
+                let starts = self.simple_dates(start, original)?;
+                let ends = self.simple_dates(end, original)?;
+                starts
+                    .iter()
+                    .flat_map(|start| {
+                        ends.iter().map(move |end| {
+                            DateRange::new(*start, *end).approximate(approximate).into()
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            }
+            (Some(Qualifier::Between), None) | (Some(_), Some(_)) => return Err(unrecognized()),
+            (qualifier, None) => self
+                .simple_dates(&tokens, original)?
+                .into_iter()
+                .map(|date| match qualifier {
+                    None => FormalDate::Simple(date),
+                    Some(Qualifier::Approximate) => FormalDate::Approximate(date),
+                    Some(Qualifier::Before) => DateRange::ending_at(date).into(),
+                    Some(Qualifier::After | Qualifier::Between) => {
+                        DateRange::starting_at(date).into()
+                    }
+                })
+                .collect(),
+        };
+        if candidates.len() == 1 {
+            Ok(candidates.remove(0))
+        } else {
+            Err(NormalizeError::Ambiguous {
+                original: original.into(),
+                candidates,
+            })
+        }
+    }
+
+    /// The dates `tokens` can be read as, more than one if ambiguous.
+    fn simple_dates(
+        &self,
+        tokens: &[Token],
+        original: &str,
+    ) -> Result<Vec<SimpleDate>, NormalizeError> {
+        let parts = self
+            .date_parts(tokens)
+            .ok_or_else(|| NormalizeError::Unrecognized(original.into()))?;
+        let mut dates = Vec::new();
+        for (year, month, day) in parts {
+            let date = SimpleDate::from_parts(year as i32, month, day)
+                .ok_or_else(|| NormalizeError::InvalidDate(original.into()))?;
+            if !dates.contains(&date) {
+                dates.push(date);
+            }
+        }
+        Ok(dates)
+    }
+
+    /// The year, month and day of `tokens`.
+    fn date_parts(&self, tokens: &[Token]) -> Option<Vec<Parts>> {
+        use Token::{Number, Separator};
+
+        let day = |value: u32, digits: usize| (digits <= 2).then_some(value as u8);
+        match tokens {
+            // 1843, 1843-06, 1843-06-23
+            [Number(year, 4)] => Some(vec![(*year, None, None)]),
+            [Number(year, 4), Separator('-'), Number(month, 2)] => {
+                Some(vec![(*year, Some(*month as u8), None)])
+            }
+            [Number(year, 4), Separator('-'), Number(month, 2), Separator('-'), Number(day, 2)] => {
+                Some(vec![(*year, Some(*month as u8), Some(*day as u8))])
+            }
+            // 23.6.1843, 03/04/1850
+            [Number(first, first_digits), Separator(sep), Number(second, second_digits), Separator(sep2), Number(year, 4)]
+                if sep == sep2 && matches!(sep, '.' | '/' | '-') =>
+            {
+                let first = day(*first, *first_digits)?;
+                let second = day(*second, *second_digits)?;
+                let day_first = (*year, Some(second), Some(first));
+                let month_first = (*year, Some(first), Some(second));
+                let day_first_only = *sep == '.' || first > 12 || self.day_first == Some(true);
+                let month_first_only = second > 12 || self.day_first == Some(false);
+                Some(match (day_first_only, month_first_only) {
+                    (true, _) => vec![day_first],
+                    (false, true) => vec![month_first],
+                    (false, false) => vec![day_first, month_first],
+                })
+            }
+            _ => self.worded_date_parts(tokens).map(|parts| vec![parts]),
+        }
+    }
+
+    /// `23 June 1843`, `June 23, 1843`, `jun. 1843` or `23. juni 1843`.
+    fn worded_date_parts(&self, tokens: &[Token]) -> Option<Parts> {
+        use Token::{Number, Word};
+
+        let tokens: Vec<_> = tokens
+            .iter()
+            .filter(|token| !matches!(token, Token::Separator('.' | ',')))
+            .collect();
+        let year = |value: &u32, digits: &usize| (3..=4).contains(digits).then_some(*value);
+        let day = |value: &u32, digits: &usize| (*digits <= 2).then_some(*value as u8);
+        match tokens.as_slice() {
+            [Number(value, digits)] => Some((year(value, digits)?, None, None)),
+            [Word(month), Number(value, digits)] => {
+                Some((year(value, digits)?, Some(month_number(month)?), None))
+            }
+            [Number(d, d_digits), Word(month), Number(value, digits)]
+            | [Word(month), Number(d, d_digits), Number(value, digits)] => Some((
+                year(value, digits)?,
+                Some(month_number(month)?),
+                Some(day(d, d_digits)?),
+            )),
+            _ => None,
+        }
+    }
+}
+
+fn tokenize(original: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = original.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() {
+            let mut digits = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+                digits.push(c);
+                chars.next();
+            }
+            tokens.push(Token::Number(digits.parse().ok()?, digits.len()));
+        } else if c.is_alphabetic() {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_alphabetic()) {
+                word.extend(c.to_lowercase());
+                chars.next();
+            }
+            tokens.push(Token::Word(word));
+        } else if matches!(c, '/' | '-' | '.' | ',' | '&') {
+            tokens.push(Token::Separator(c));
+            chars.next();
+        } else {
+            return None;
+        }
+    }
+    Some(tokens)
+}
+
+/// Splits `1840 and 1845` or `1840-1845` into the start and the end.
+fn split_range(tokens: &[Token], qualifier: Option<Qualifier>) -> Option<(&[Token], &[Token])> {
+    let index = tokens.iter().position(|token| match token {
+        Token::Word(word) => RANGE_SEPARATORS.contains(&word.as_str()),
+        Token::Separator('&') => qualifier == Some(Qualifier::Between),
+        _ => false,
+    });
+    if let Some(index) = index {
+        return Some((&tokens[..index], &tokens[index + 1..]));
+    }
+    match tokens {
+        [Token::Number(_, 4), Token::Separator('-'), Token::Number(_, 4)] => {
+            Some((&tokens[..1], &tokens[2..]))
+        }
+        _ => None,
+    }
+}
+
+fn month_number(word: &str) -> Option<u8> {
+    if word.chars().count() < 3 {
+        return None;
+    }
+    let mut matches = MONTHS
+        .iter()
+        .filter(|(name, _)| name.starts_with(word))
+        .map(|(_, month)| *month);
+    let month = matches.next()?;
+    matches.all(|other| other == month).then_some(month)
+}
+
+impl fmt::Display for NormalizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unrecognized(original) => write!(f, "unrecognized date '{}'", original),
+            Self::InvalidDate(original) => write!(f, "'{}' is not a valid date", original),
+            Self::Ambiguous {
+                original,
+                candidates,
+            } => {
+                write!(f, "ambiguous date '{}', could be ", original)?;
+                for (index, candidate) in candidates.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" or ")?;
+                    }
+                    write!(f, "{}", candidate)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl StdError for NormalizeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(original: &str) -> String {
+        DateNormalizer::new()
+            .normalize(original)
+            .unwrap_or_else(|err| panic!("{}", err))
+            .to_string()
+    }
+
+    #[test]
+    fn english_dates() {
+        assert_eq!(normalize("23 June 1843"), "+1843-06-23");
+        assert_eq!(normalize("June 23, 1843"), "+1843-06-23");
+        assert_eq!(normalize("Sept. 1843"), "+1843-09");
+        assert_eq!(normalize("1843-06-23"), "+1843-06-23");
+        assert_eq!(normalize("1843"), "+1843");
+        assert_eq!(normalize("abt 1790"), "A+1790");
+        assert_eq!(normalize("c. 1790"), "A+1790");
+        assert_eq!(normalize("bef. 3 MAR 1801"), "/+1801-03-03");
+        assert_eq!(normalize("aft 1850"), "+1850/");
+        assert_eq!(normalize("between 1840 and 1845"), "+1840/+1845");
+        assert_eq!(normalize("from Jan 1840 to Dec 1845"), "+1840-01/+1845-12");
+        assert_eq!(normalize("1840-1845"), "+1840/+1845");
+        assert_eq!(normalize("abt 1840 to 1845"), "A+1840/+1845");
+    }
+
+    #[test]
+    fn scandinavian_dates() {
+        assert_eq!(normalize("23. juni 1843"), "+1843-06-23");
+        assert_eq!(normalize("3 maj 1801"), "+1801-05-03");
+        assert_eq!(normalize("17 mai 1814"), "+1814-05-17");
+        assert_eq!(normalize("1 okt. 1850"), "+1850-10-01");
+        assert_eq!(normalize("24 desember 1900"), "+1900-12-24");
+        assert_eq!(normalize("23.6.1843"), "+1843-06-23");
+        assert_eq!(normalize("omkring 1790"), "A+1790");
+        assert_eq!(normalize("före 1801"), "/+1801");
+        assert_eq!(normalize("efter 1850"), "+1850/");
+        assert_eq!(normalize("mellan 1840 och 1845"), "+1840/+1845");
+        assert_eq!(normalize("mellem 1840 og 1845"), "+1840/+1845");
+        assert_eq!(normalize("fra 1840 til 1845"), "+1840/+1845");
+    }
+
+    #[test]
+    fn numeric_dates_are_ambiguous_unless_resolved() {
+        let err = DateNormalizer::new().normalize("03/04/1850").unwrap_err();
+        match err {
+            NormalizeError::Ambiguous { candidates, .. } => {
+                let candidates: Vec<_> = candidates.iter().map(|c| c.to_string()).collect();
+                assert_eq!(candidates, ["+1850-04-03", "+1850-03-04"]);
+            }
+            _ => panic!("expected ambiguity, got {}", err),
+        }
+        assert_eq!(normalize("13/04/1850"), "+1850-04-13");
+        assert_eq!(normalize("04/13/1850"), "+1850-04-13");
+        assert_eq!(normalize("04/04/1850"), "+1850-04-04");
+        let day_first = DateNormalizer::new().day_first(true);
+        assert_eq!(
+            day_first.normalize("03/04/1850").unwrap().to_string(),
+            "+1850-04-03"
+        );
+        let month_first = DateNormalizer::new().day_first(false);
+        assert_eq!(
+            month_first.normalize("03/04/1850").unwrap().to_string(),
+            "+1850-03-04"
+        );
+    }
+
+    #[test]
+    fn reports_unrecognized_and_invalid_dates() {
+        let normalizer = DateNormalizer::new();
+        for unrecognized in [
+            "",
+            "yesterday",
+            "abt",
+            "ju 1843",
+            "1843?",
+            "between 1840",
+            "43",
+        ] {
+            assert_eq!(
+                normalizer.normalize(unrecognized),
+                Err(NormalizeError::Unrecognized(unrecognized.into())),
+                "{}",
+                unrecognized
+            );
+        }
+        assert_eq!(
+            normalizer.normalize("31 Feb 1850"),
+            Err(NormalizeError::InvalidDate("31 Feb 1850".into()))
+        );
+    }
+}
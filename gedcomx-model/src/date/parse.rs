@@ -0,0 +1,78 @@
+use super::DateParseError;
+
+/// A cursor over a formal date string.
+pub(crate) struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    /// Consumes `b` if it is next.
+    pub fn eat(&mut self, b: u8) -> bool {
+        if self.peek() == Some(b) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn expect(&mut self, b: u8, reason: &'static str) -> Result<(), DateParseError> {
+        if self.eat(b) {
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    /// Reads exactly `count` digits.
+    pub fn digits(&mut self, count: usize, reason: &'static str) -> Result<u32, DateParseError> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + count)
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_digit()))
+            .ok_or_else(|| self.error(reason))?;
+        self.pos += count;
+        Ok(digits.parse().expect("ascii digits"))
+    }
+
+    /// Reads one or more digits.
+    pub fn number(&mut self, reason: &'static str) -> Result<u32, DateParseError> {
+        let len = self.input.as_bytes()[self.pos..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if len == 0 {
+            return Err(self.error(reason));
+        }
+        let number = self.input[self.pos..self.pos + len]
+            .parse()
+            .map_err(|_| self.error("number is too large"))?;
+        self.pos += len;
+        Ok(number)
+    }
+
+    pub fn end(&self) -> Result<(), DateParseError> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("unexpected character"))
+        }
+    }
+
+    pub fn error(&self, reason: &'static str) -> DateParseError {
+        DateParseError::new(self.input, self.pos, reason)
+    }
+}
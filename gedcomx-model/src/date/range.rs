@@ -0,0 +1,151 @@
+use std::{fmt, str::FromStr};
+
+use super::{parse::Parser, DateParseError, Duration, SimpleDate};
+
+/// The end of a [`DateRange`] or a [`RecurringDate`](super::RecurringDate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeEnd {
+    /// `+1850`
+    Date(SimpleDate),
+    /// `P10Y`, counted from the start.
+    Duration(Duration),
+}
+
+/// A period of time, e.g. `+1840/+1850`, `A+1840/`, `/+1850` or `+1840/P10Y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    approximate: bool,
+    start: Option<SimpleDate>,
+    end: Option<RangeEnd>,
+}
+
+impl DateRange {
+    /// A closed range between two dates.
+    pub fn new(start: SimpleDate, end: SimpleDate) -> Self {
+        Self {
+            approximate: false,
+            start: Some(start),
+            end: Some(RangeEnd::Date(end)),
+        }
+    }
+    /// A range from `start` that has not ended.
+    pub fn starting_at(start: SimpleDate) -> Self {
+        Self {
+            approximate: false,
+            start: Some(start),
+            end: None,
+        }
+    }
+    /// A range up to `end` with an unknown start.
+    pub fn ending_at(end: SimpleDate) -> Self {
+        Self {
+            approximate: false,
+            start: None,
+            end: Some(RangeEnd::Date(end)),
+        }
+    }
+    /// A range lasting `duration` from `start`.
+    pub fn lasting(start: SimpleDate, duration: Duration) -> Self {
+        Self {
+            approximate: false,
+            start: Some(start),
+            end: Some(RangeEnd::Duration(duration)),
+        }
+    }
+}
+
+// Builder lite
+impl DateRange {
+    pub fn approximate(mut self, yes: bool) -> Self {
+        self.approximate = yes;
+        self
+    }
+}
+
+impl DateRange {
+    pub fn is_approximate(&self) -> bool {
+        self.approximate
+    }
+    pub fn start(&self) -> Option<&SimpleDate> {
+        self.start.as_ref()
+    }
+    pub fn end(&self) -> Option<&RangeEnd> {
+        self.end.as_ref()
+    }
+
+    /// `+1840/+1850` without the leading `A`, if any.
+    pub(crate) fn parse_from(parser: &mut Parser<'_>) -> Result<Self, DateParseError> {
+        let start = match parser.peek() {
+            Some(b'/') => None,
+            _ => Some(SimpleDate::parse_from(parser)?),
+        };
+        parser.expect(b'/', "expected '/' in a date range")?;
+        let end = match parser.peek() {
+            None => None,
+            Some(b'P') => match start {
+                Some(_) => Some(RangeEnd::Duration(Duration::parse_from(parser)?)),
+                None => return Err(parser.error("a duration needs a start date")),
+            },
+            Some(_) => Some(RangeEnd::Date(SimpleDate::parse_from(parser)?)),
+        };
+        if start.is_none() && end.is_none() {
+            return Err(parser.error("a date range needs a start or an end"));
+        }
+        Ok(Self {
+            approximate: false,
+            start,
+            end,
+        })
+    }
+
+    /// The first second of the range and the first second after it, `None`
+    /// where the range is open.
+    pub(crate) fn span(&self) -> (Option<i64>, Option<i64>) {
+        let start = self.start.map(|start| start.start_instant());
+        let end = match (&self.start, &self.end) {
+            (_, Some(RangeEnd::Date(end))) => Some(end.end_instant()),
+            (Some(start), Some(RangeEnd::Duration(duration))) => {
+                Some(start.instant_after(duration, 1))
+            }
+            _ => None,
+        };
+        (start, end)
+    }
+}
+
+impl FromStr for DateRange {
+    type Err = DateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let approximate = parser.eat(b'A');
+        let range = Self::parse_from(&mut parser)?;
+        parser.end()?;
+        Ok(range.approximate(approximate))
+    }
+}
+
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.approximate {
+            f.write_str("A")?;
+        }
+        if let Some(start) = &self.start {
+            write!(f, "{}", start)?;
+        }
+        f.write_str("/")?;
+        if let Some(end) = &self.end {
+            write!(f, "{}", end)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RangeEnd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Date(date) => date.fmt(f),
+            Self::Duration(duration) => duration.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::{fmt, str::FromStr};
+
+use super::{parse::Parser, simple::saturate, DateParseError, Duration, RangeEnd, SimpleDate};
+
+/// A period of time that repeats, e.g. `R3/+1900/P1Y` for three years from 1900.
+///
+/// Without a count the date repeats forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RecurringDate {
+    count: Option<u32>,
+    start: SimpleDate,
+    end: RangeEnd,
+}
+
+impl RecurringDate {
+    pub fn new(start: SimpleDate, end: RangeEnd) -> Self {
+        Self {
+            count: None,
+            start,
+            end,
+        }
+    }
+}
+
+// Builder lite
+impl RecurringDate {
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+}
+
+impl RecurringDate {
+    pub fn get_count(&self) -> Option<u32> {
+        self.count
+    }
+    pub fn start(&self) -> &SimpleDate {
+        &self.start
+    }
+    pub fn end(&self) -> &RangeEnd {
+        &self.end
+    }
+
+    /// The start of the `n`th occurrence, counting from 0, saturating far
+    /// beyond the range of formal dates.
+    fn occurrence_instant(&self, n: u32) -> i64 {
+        match &self.end {
+            RangeEnd::Duration(duration) => self.start.instant_after(duration, n),
+            RangeEnd::Date(end) => {
+                let start = self.start.start_instant() as i128;
+                saturate(start + (end.end_instant() as i128 - start) * n as i128)
+            }
+        }
+    }
+
+    pub(crate) fn parse_from(parser: &mut Parser<'_>) -> Result<Self, DateParseError> {
+        parser.expect(b'R', "expected 'R' before a recurring date")?;
+        let count = match parser.peek() {
+            Some(b'/') => None,
+            _ => Some(parser.number("expected a repetition count")?),
+        };
+        parser.expect(b'/', "expected '/' after the repetition count")?;
+        let start = SimpleDate::parse_from(parser)?;
+        parser.expect(b'/', "expected '/' after the start of a recurring date")?;
+        let end = match parser.peek() {
+            Some(b'P') => RangeEnd::Duration(Duration::parse_from(parser)?),
+            _ => RangeEnd::Date(SimpleDate::parse_from(parser)?),
+        };
+        Ok(Self { count, start, end })
+    }
+
+    pub(crate) fn span(&self) -> (Option<i64>, Option<i64>) {
+        let start = self.start.start_instant();
+        (
+            Some(start),
+            self.count.map(|count| self.occurrence_instant(count)),
+        )
+    }
+}
+
+impl FromStr for RecurringDate {
+    type Err = DateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let recurring = Self::parse_from(&mut parser)?;
+        parser.end()?;
+        Ok(recurring)
+    }
+}
+
+impl fmt::Display for RecurringDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("R")?;
+        if let Some(count) = self.count {
+            write!(f, "{}", count)?;
+        }
+        write!(f, "/{}/{}", self.start, self.end)
+    }
+}
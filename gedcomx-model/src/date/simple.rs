@@ -0,0 +1,360 @@
+use std::{fmt, str::FromStr};
+
+use super::{parse::Parser, DateParseError, Duration};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A single point in time, as precise as it is known, e.g. `+1843-06-23`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SimpleDate {
+    year: i32,
+    month: Option<u8>,
+    day: Option<u8>,
+    time: Option<Time>,
+}
+
+/// The time of a [`SimpleDate`], e.g. `T12:30:00Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Time {
+    hours: u8,
+    minutes: Option<u8>,
+    seconds: Option<u8>,
+    zone: Option<TimeZone>,
+}
+
+/// The time zone of a [`Time`]; times without one are taken as UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeZone {
+    /// `Z`
+    Utc,
+    /// `+01:00`, in minutes.
+    Offset(i16),
+}
+
+impl SimpleDate {
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+    pub fn time(&self) -> Option<&Time> {
+        self.time.as_ref()
+    }
+
+    /// A date from its parts, `None` if they are out of range or a day is
+    /// given without a month.
+    pub(crate) fn from_parts(year: i32, month: Option<u8>, day: Option<u8>) -> Option<Self> {
+        if !(-9999..=9999).contains(&year) {
+            return None;
+        }
+        match (month, day) {
+            (None, Some(_)) => return None,
+            (Some(month), _) if !(1..=12).contains(&month) => return None,
+            (Some(month), Some(day)) if day == 0 || day > days_in_month(year as i64, month) => {
+                return None
+            }
+            _ => {}
+        }
+        Some(Self {
+            year,
+            month,
+            day,
+            time: None,
+        })
+    }
+
+    pub(crate) fn parse_from(parser: &mut Parser<'_>) -> Result<Self, DateParseError> {
+        let negative = match parser.peek() {
+            Some(b'+') => false,
+            Some(b'-') => true,
+            _ => return Err(parser.error("expected '+' or '-' before the year")),
+        };
+        parser.eat(if negative { b'-' } else { b'+' });
+        let year = parser.digits(4, "expected a four digit year")? as i32;
+        let mut date = Self {
+            year: if negative { -year } else { year },
+            month: None,
+            day: None,
+            time: None,
+        };
+        if !parser.eat(b'-') {
+            return Ok(date);
+        }
+        let month = parser.digits(2, "expected a two digit month")? as u8;
+        if !(1..=12).contains(&month) {
+            return Err(parser.error("month is out of range"));
+        }
+        date.month = Some(month);
+        if !parser.eat(b'-') {
+            return Ok(date);
+        }
+        let day = parser.digits(2, "expected a two digit day")? as u8;
+        if day == 0 || day > days_in_month(date.year as i64, month) {
+            return Err(parser.error("day is out of range"));
+        }
+        date.day = Some(day);
+        if parser.eat(b'T') {
+            date.time = Some(Time::parse_from(parser)?);
+        }
+        Ok(date)
+    }
+
+    /// The first second of the date, in seconds since `+1970-01-01T00:00:00Z`.
+    pub(crate) fn start_instant(&self) -> i64 {
+        let days = days_from_civil(
+            self.year as i64,
+            self.month.unwrap_or(1) as i64,
+            self.day.unwrap_or(1) as i64,
+        );
+        days * SECONDS_PER_DAY + self.time.map_or(0, |time| time.seconds_from_midnight())
+    }
+
+    /// The first second after the date, e.g. the start of 1844 for `+1843`.
+    pub(crate) fn end_instant(&self) -> i64 {
+        let start = self.start_instant();
+        match (self.month, self.day, self.time) {
+            (_, _, Some(time)) => {
+                let length = match (time.minutes, time.seconds) {
+                    (_, Some(_)) => 1,
+                    (Some(_), None) => 60,
+                    (None, None) => 3_600,
+                };
+                start + length
+            }
+            (_, Some(_), None) => start + SECONDS_PER_DAY,
+            (Some(month), None, None) => {
+                let (year, month) = add_months(self.year as i64, month as i64, 1);
+                days_from_civil(year, month, 1) * SECONDS_PER_DAY
+            }
+            (None, None, None) => days_from_civil(self.year as i64 + 1, 1, 1) * SECONDS_PER_DAY,
+        }
+    }
+
+    /// The start of the date moved `times` times by `duration`, saturating
+    /// far beyond the range of formal dates.
+    pub(crate) fn instant_after(&self, duration: &Duration, times: u32) -> i64 {
+        let times = times as i128;
+        let months = (duration.years() as i128 * 12 + duration.months() as i128) * times;
+        if months > MAX_MONTHS {
+            return i64::MAX;
+        }
+        let (year, month) = add_months(
+            self.year as i64,
+            self.month.unwrap_or(1) as i64,
+            months as i64,
+        );
+        let day = (self.day.unwrap_or(1) as i64).min(days_in_month(year, month as u8) as i64);
+        let seconds = (duration.days() as i128 * SECONDS_PER_DAY as i128
+            + duration.hours() as i128 * 3_600
+            + duration.minutes() as i128 * 60
+            + duration.seconds() as i128)
+            * times;
+        saturate(
+            (days_from_civil(year, month, day) * SECONDS_PER_DAY
+                + self.time.map_or(0, |time| time.seconds_from_midnight())) as i128
+                + seconds,
+        )
+    }
+}
+
+/// Months far beyond the years of formal dates, yet small enough for the
+/// seconds since 1970 to fit in an `i64`.
+const MAX_MONTHS: i128 = 12 * 100_000_000_000;
+
+/// `instant` clamped to the range of `i64`.
+pub(crate) fn saturate(instant: i128) -> i64 {
+    i64::try_from(instant).unwrap_or(if instant < 0 { i64::MIN } else { i64::MAX })
+}
+
+impl Time {
+    pub fn hours(&self) -> u8 {
+        self.hours
+    }
+    pub fn minutes(&self) -> Option<u8> {
+        self.minutes
+    }
+    pub fn seconds(&self) -> Option<u8> {
+        self.seconds
+    }
+    pub fn zone(&self) -> Option<TimeZone> {
+        self.zone
+    }
+
+    fn parse_from(parser: &mut Parser<'_>) -> Result<Self, DateParseError> {
+        let hours = parser.digits(2, "expected two digit hours")? as u8;
+        let mut time = Self {
+            hours,
+            minutes: None,
+            seconds: None,
+            zone: None,
+        };
+        if parser.eat(b':') {
+            time.minutes = Some(parser.digits(2, "expected two digit minutes")? as u8);
+            if parser.eat(b':') {
+                time.seconds = Some(parser.digits(2, "expected two digit seconds")? as u8);
+            }
+        }
+        let minutes = time.minutes.unwrap_or(0);
+        let seconds = time.seconds.unwrap_or(0);
+        if hours > 24 || (hours == 24 && (minutes, seconds) != (0, 0)) {
+            return Err(parser.error("hours are out of range"));
+        }
+        if minutes > 59 || seconds > 59 {
+            return Err(parser.error("minutes or seconds are out of range"));
+        }
+        time.zone = match parser.peek() {
+            Some(b'Z') => {
+                parser.eat(b'Z');
+                Some(TimeZone::Utc)
+            }
+            Some(sign @ (b'+' | b'-')) => {
+                parser.eat(sign);
+                let hours = parser.digits(2, "expected two digit time zone hours")? as i16;
+                let minutes = if parser.eat(b':') {
+                    parser.digits(2, "expected two digit time zone minutes")? as i16
+                } else {
+                    0
+                };
+                if hours > 14 || minutes > 59 {
+                    return Err(parser.error("time zone is out of range"));
+                }
+                let offset = hours * 60 + minutes;
+                Some(TimeZone::Offset(if sign == b'-' {
+                    -offset
+                } else {
+                    offset
+                }))
+            }
+            _ => None,
+        };
+        Ok(time)
+    }
+
+    fn seconds_from_midnight(&self) -> i64 {
+        let offset = match self.zone {
+            Some(TimeZone::Offset(minutes)) => minutes as i64 * 60,
+            Some(TimeZone::Utc) | None => 0,
+        };
+        self.hours as i64 * 3_600
+            + self.minutes.unwrap_or(0) as i64 * 60
+            + self.seconds.unwrap_or(0) as i64
+            - offset
+    }
+}
+
+impl FromStr for SimpleDate {
+    type Err = DateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let date = Self::parse_from(&mut parser)?;
+        parser.end()?;
+        Ok(date)
+    }
+}
+
+impl fmt::Display for SimpleDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.year < 0 { '-' } else { '+' };
+        write!(f, "{}{:04}", sign, self.year.unsigned_abs())?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        if let Some(time) = &self.time {
+            write!(f, "T{}", time)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}", self.hours)?;
+        if let Some(minutes) = self.minutes {
+            write!(f, ":{:02}", minutes)?;
+        }
+        if let Some(seconds) = self.seconds {
+            write!(f, ":{:02}", seconds)?;
+        }
+        match self.zone {
+            None => Ok(()),
+            Some(TimeZone::Utc) => f.write_str("Z"),
+            Some(TimeZone::Offset(offset)) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                write!(f, "{}{:02}:{:02}", sign, offset / 60, offset % 60)
+            }
+        }
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn add_months(year: i64, month: i64, months: i64) -> (i64, i64) {
+    let months = year * 12 + (month - 1) + months;
+    (months.div_euclid(12), months.rem_euclid(12) + 1)
+}
+
+/// Days since `+1970-01-01` in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_from_civil_matches_known_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(0, 3, 1), -719_468);
+    }
+
+    #[test]
+    fn precision_decides_the_end() {
+        let year: SimpleDate = "+1843".parse().unwrap();
+        let february: SimpleDate = "+1844-02".parse().unwrap();
+        let day: SimpleDate = "+1843-06-23".parse().unwrap();
+        assert_eq!(
+            year.end_instant(),
+            "+1844".parse::<SimpleDate>().unwrap().start_instant()
+        );
+        assert_eq!(
+            february.end_instant() - february.start_instant(),
+            29 * SECONDS_PER_DAY
+        );
+        assert_eq!(day.end_instant() - day.start_instant(), SECONDS_PER_DAY);
+    }
+
+    #[test]
+    fn time_zones_shift_the_instant() {
+        let utc: SimpleDate = "+2000-01-01T12:00:00Z".parse().unwrap();
+        let cet: SimpleDate = "+2000-01-01T13:00:00+01:00".parse().unwrap();
+        assert_eq!(utc.start_instant(), cet.start_instant());
+    }
+}
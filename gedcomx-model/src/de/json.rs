@@ -0,0 +1,298 @@
+use std::cell::OnceCell;
+use std::io::{self, BufRead};
+use std::iter;
+use std::marker::PhantomData;
+use std::mem;
+
+use serde::de::value::MapDeserializer;
+use serde::de::{DeserializeSeed, Deserializer, Error as _};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::gedcomx::{Attribution, Collection, GedcomX, Record};
+
+/// Reads the top-level items of a GEDCOM X JSON document one at a time.
+///
+/// The items of the `persons`, `relationships`, ... arrays are read from the
+/// input one at a time, so documents larger than memory can be read. The
+/// `attribution` is returned as a record like in
+/// [`XmlRecordReader`](crate::de::XmlRecordReader).
+///
+/// ```
+/// use gedcomx_model::de::JsonRecordReader;
+/// use gedcomx_model::gedcomx::Record;
+///
+/// let json = r#"{"persons": [{"id": "P-1"}, {"id": "P-2"}], "id": "doc"}"#;
+/// let mut records = JsonRecordReader::new(json.as_bytes())?;
+/// let mut persons = 0;
+/// for record in &mut records {
+///     if let Record::Person(_) = record? {
+///         persons += 1;
+///     }
+/// }
+/// assert_eq!(persons, 2);
+/// assert_eq!(records.header().get_id().unwrap().as_str(), "doc");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub struct JsonRecordReader<R> {
+    reader: R,
+    state: State,
+    /// The other fields of the root object, each checked when it is read.
+    fields: Map<String, Value>,
+    /// The header built from `fields`, emptied when a field is read.
+    header: OnceCell<GedcomX>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// In the root object, before the first field if `true`.
+    Fields(bool),
+    /// In the array of a collection, before the first item if `true`.
+    Items(Collection, bool),
+    Done,
+}
+
+impl<R: BufRead> JsonRecordReader<R> {
+    /// Reads up to and including the start of the root object.
+    pub fn new(mut reader: R) -> serde_json::Result<Self> {
+        expect(&mut reader, b'{')?;
+        Ok(Self {
+            reader,
+            state: State::Fields(true),
+            fields: Map::new(),
+            header: OnceCell::new(),
+        })
+    }
+
+    /// The document without its items, i.e. the other fields of the root
+    /// object.
+    ///
+    /// Unlike in XML these can be anywhere in the object, so the header is
+    /// only complete once all records are read.
+    pub fn header(&self) -> &GedcomX {
+        self.header.get_or_init(|| {
+            serde_json::from_value(Value::Object(self.fields.clone()))
+                .expect("fields checked when read")
+        })
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_record(&mut self) -> serde_json::Result<Option<Record>> {
+        loop {
+            match self.state {
+                State::Fields(first) => {
+                    if peek(&mut self.reader)? == b'}' {
+                        self.reader.consume(1);
+                        self.state = State::Done;
+                        // The fields are no longer needed once the header is
+                        // built from them.
+                        if self.header.get().is_none() {
+                            let fields = mem::take(&mut self.fields);
+                            self.header = OnceCell::from(serde_json::from_value::<GedcomX>(
+                                Value::Object(fields),
+                            )?);
+                        }
+                        return Ok(None);
+                    }
+                    if !first {
+                        expect(&mut self.reader, b',')?;
+                    }
+                    self.state = State::Fields(false);
+                    let key: String = self.deserialize(PhantomData)?;
+                    expect(&mut self.reader, b':')?;
+                    if let Some(collection) = Collection::ALL
+                        .into_iter()
+                        .find(|collection| collection.key() == key)
+                    {
+                        expect(&mut self.reader, b'[')?;
+                        self.state = State::Items(collection, true);
+                    } else if key == "attribution" {
+                        let attribution: Attribution = self.deserialize(PhantomData)?;
+                        return Ok(Some(Record::Attribution(attribution)));
+                    } else {
+                        let value: Value = self.deserialize(PhantomData)?;
+                        check_field(&key, &value)?;
+                        self.fields.insert(key, value);
+                        self.header.take();
+                    }
+                }
+                State::Items(collection, first) => {
+                    if peek(&mut self.reader)? == b']' {
+                        self.reader.consume(1);
+                        self.state = State::Fields(false);
+                        continue;
+                    }
+                    if !first {
+                        expect(&mut self.reader, b',')?;
+                    }
+                    self.state = State::Items(collection, false);
+                    return self.deserialize(RecordSeed(collection)).map(Some);
+                }
+                State::Done => return Ok(None),
+            }
+        }
+    }
+
+    /// Deserializes the next value, reading exactly up to its end.
+    fn deserialize<S, V>(&mut self, seed: S) -> serde_json::Result<V>
+    where
+        S: for<'de> DeserializeSeed<'de, Value = V>,
+    {
+        let value = read_value(&mut self.reader)?;
+        let mut deserializer = serde_json::Deserializer::from_slice(&value);
+        let value = seed.deserialize(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(value)
+    }
+}
+
+impl<R: BufRead> Iterator for JsonRecordReader<R> {
+    type Item = serde_json::Result<Record>;
+
+    /// Returns the next item, or `None` after the end of the root object or
+    /// the first error.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_record() {
+            Ok(record) => record.map(Ok),
+            Err(err) => {
+                self.state = State::Done;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Deserializes an item of a collection as a [`Record`].
+#[derive(Debug, Clone, Copy)]
+struct RecordSeed(Collection);
+
+impl<'de> DeserializeSeed<'de> for RecordSeed {
+    type Value = Record;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Record, D::Error> {
+        let record = match self.0 {
+            Collection::Persons => Record::Person(Deserialize::deserialize(deserializer)?),
+            Collection::Relationships => {
+                Record::Relationship(Deserialize::deserialize(deserializer)?)
+            }
+            Collection::SourceDescriptions => {
+                Record::SourceDescription(Deserialize::deserialize(deserializer)?)
+            }
+            Collection::Agents => Record::Agent(Deserialize::deserialize(deserializer)?),
+            Collection::Events => Record::Event(Deserialize::deserialize(deserializer)?),
+            Collection::Places => Record::Place(Deserialize::deserialize(deserializer)?),
+            Collection::Documents => Record::Document(Deserialize::deserialize(deserializer)?),
+            Collection::Groups => Record::Group(Deserialize::deserialize(deserializer)?),
+        };
+        Ok(record)
+    }
+}
+
+/// Checks `value` as the field `key` of a document on its own, so that the
+/// header can be built from the fields later without reading them all again
+/// for each field.
+fn check_field(key: &str, value: &Value) -> serde_json::Result<()> {
+    let field = MapDeserializer::new(iter::once((key, value)));
+    GedcomX::deserialize(field).map(drop)
+}
+
+fn skip_whitespace<R: BufRead>(reader: &mut R) -> serde_json::Result<()> {
+    loop {
+        let buf = reader.fill_buf().map_err(serde_json::Error::io)?;
+        let whitespace = buf
+            .iter()
+            .take_while(|byte| byte.is_ascii_whitespace())
+            .count();
+        let at_end = whitespace < buf.len() || buf.is_empty();
+        reader.consume(whitespace);
+        if at_end {
+            return Ok(());
+        }
+    }
+}
+
+/// Reads the bytes of the next value.
+///
+/// A number only ends at the byte after it, which `serde_json` would take
+/// from the input, so the end of the value is found here and the value is
+/// parsed from the bytes.
+fn read_value<R: BufRead>(reader: &mut R) -> serde_json::Result<Vec<u8>> {
+    skip_whitespace(reader)?;
+    let mut value = Vec::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    loop {
+        let buf = reader.fill_buf().map_err(serde_json::Error::io)?;
+        if buf.is_empty() {
+            // Left to the parser to report.
+            return Ok(value);
+        }
+        let mut used = 0;
+        let mut done = false;
+        for &byte in buf {
+            if in_string {
+                used += 1;
+                if escaped {
+                    escaped = false;
+                } else if byte == b'\\' {
+                    escaped = true;
+                } else if byte == b'"' {
+                    in_string = false;
+                    done = depth == 0;
+                }
+            } else {
+                match byte {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' if depth == 0 => done = true,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            used += 1;
+                            done = true;
+                        }
+                    }
+                    b',' | b':' if depth == 0 => done = true,
+                    byte if depth == 0 && byte.is_ascii_whitespace() => done = true,
+                    _ => {}
+                }
+                if !done {
+                    used += 1;
+                }
+            }
+            if done {
+                break;
+            }
+        }
+        value.extend_from_slice(&buf[..used]);
+        reader.consume(used);
+        if done {
+            return Ok(value);
+        }
+    }
+}
+
+/// The next byte that is not whitespace, without consuming it.
+fn peek<R: BufRead>(reader: &mut R) -> serde_json::Result<u8> {
+    skip_whitespace(reader)?;
+    match reader.fill_buf().map_err(serde_json::Error::io)?.first() {
+        Some(byte) => Ok(*byte),
+        None => Err(serde_json::Error::io(io::ErrorKind::UnexpectedEof.into())),
+    }
+}
+
+fn expect<R: BufRead>(reader: &mut R, expected: u8) -> serde_json::Result<()> {
+    let byte = peek(reader)?;
+    if byte != expected {
+        return Err(serde_json::Error::custom(format!(
+            "expected `{}`, found `{}`",
+            expected as char, byte as char
+        )));
+    }
+    reader.consume(1);
+    Ok(())
+}
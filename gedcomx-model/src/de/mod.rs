@@ -0,0 +1,5 @@
+mod json;
+mod xml;
+
+pub use crate::de::json::JsonRecordReader;
+pub use crate::de::xml::XmlRecordReader;
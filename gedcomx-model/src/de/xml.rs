@@ -0,0 +1,139 @@
+use std::io::BufRead;
+
+use deserx::de::{
+    deserialize_child, deserialize_empty_fields, enter_scope, is_in_parent_namespace,
+    read_element_xml,
+};
+use deserx::{Error, Result};
+use quick_xml::events::{BytesStart, Event};
+
+use crate::gedcomx::{GedcomX, Record};
+
+const ROOT: &str = "gedcomx";
+
+/// Reads the top-level items of a GEDCOM X XML document one at a time.
+///
+/// Only the current item is kept in memory, so documents larger than memory
+/// can be read, e.g. to index them:
+///
+/// ```
+/// use gedcomx_model::de::XmlRecordReader;
+/// use gedcomx_model::gedcomx::Record;
+///
+/// let xml = r##"<gedcomx><person id="P-1"/><person id="P-2"/></gedcomx>"##;
+/// let records = XmlRecordReader::new(quick_xml::Reader::from_str(xml))?;
+/// let mut persons = 0;
+/// for record in records {
+///     if let Record::Person(_) = record? {
+///         persons += 1;
+///     }
+/// }
+/// assert_eq!(persons, 2);
+/// # Ok::<(), deserx::Error>(())
+/// ```
+pub struct XmlRecordReader<R> {
+    reader: quick_xml::Reader<R>,
+    buf: Vec<u8>,
+    root: BytesStart<'static>,
+    header: GedcomX,
+    done: bool,
+}
+
+impl<R: BufRead> XmlRecordReader<R> {
+    /// Reads up to and including the start tag of the `gedcomx` root element.
+    pub fn new(mut reader: quick_xml::Reader<R>) -> Result<Self> {
+        let mut buf = Vec::new();
+        loop {
+            let (start, empty) = match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => (e, false),
+                Event::Empty(e) => (e, true),
+                Event::Eof => return Err(Error::unexpected_eof()),
+                _ => continue,
+            };
+            if start.local_name().as_ref() != ROOT.as_bytes() {
+                return Err(Error::unexpected_element(start.name()).at(reader.buffer_position()));
+            }
+            let header = deserialize_empty_fields(&start)
+                .map_err(|err| err.within(ROOT).at(reader.buffer_position()))?;
+            let root = start.into_owned();
+            buf.clear();
+            return Ok(Self {
+                reader,
+                buf,
+                root,
+                header,
+                done: empty,
+            });
+        }
+    }
+
+    /// The document without its items, i.e. the attributes of the root element.
+    pub fn header(&self) -> &GedcomX {
+        &self.header
+    }
+
+    pub fn into_inner(self) -> quick_xml::Reader<R> {
+        self.reader
+    }
+
+    fn read_record(&mut self) -> Result<Option<Record>> {
+        let _scope = enter_scope(&self.root)?;
+        loop {
+            self.buf.clear();
+            let (start, empty) = match self.reader.read_event_into(&mut self.buf)? {
+                Event::Start(e) => (e, false),
+                Event::Empty(e) => (e, true),
+                Event::End(_) => return Ok(None),
+                Event::Eof => return Err(Error::unexpected_eof()),
+                _ => continue,
+            };
+            return read_child(&mut self.reader, &start, empty).map(Some);
+        }
+    }
+}
+
+fn read_child<R: BufRead>(
+    reader: &mut quick_xml::Reader<R>,
+    start: &BytesStart<'_>,
+    empty: bool,
+) -> Result<Record> {
+    if !is_in_parent_namespace(start)? {
+        return Ok(Record::Extension(read_element_xml(reader, start, empty)?));
+    }
+    let record = match start.local_name().as_ref() {
+        b"attribution" => Record::Attribution(deserialize_child(reader, start, empty)?),
+        b"person" => Record::Person(deserialize_child(reader, start, empty)?),
+        b"relationship" => Record::Relationship(deserialize_child(reader, start, empty)?),
+        b"sourceDescription" => Record::SourceDescription(deserialize_child(reader, start, empty)?),
+        b"agent" => Record::Agent(deserialize_child(reader, start, empty)?),
+        b"event" => Record::Event(deserialize_child(reader, start, empty)?),
+        b"place" => Record::Place(deserialize_child(reader, start, empty)?),
+        b"document" => Record::Document(deserialize_child(reader, start, empty)?),
+        b"group" => Record::Group(deserialize_child(reader, start, empty)?),
+        _ => Record::Extension(read_element_xml(reader, start, empty)?),
+    };
+    Ok(record)
+}
+
+impl<R: BufRead> Iterator for XmlRecordReader<R> {
+    type Item = Result<Record>;
+
+    /// Returns the next item, or `None` after the end of the root element or
+    /// the first error.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.read_record() {
+            Ok(Some(record)) => Some(Ok(record)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err.within(ROOT)))
+            }
+        }
+    }
+}
@@ -1,16 +1,30 @@
-use std::{error::Error as StdError, fmt, result};
+use std::{error::Error as StdError, fmt, io, result};
+
+use crate::ser::SerError;
 
 pub type Result<T> = result::Result<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
     IriParseError(oxiri::IriParseError),
+    Io(io::Error),
+    Xml(deserx::Error),
+    XmlWrite(SerError),
+    Json(serde_json::Error),
+    /// The input is neither GEDCOM X XML nor JSON, or the media type is not
+    /// supported.
+    UnknownFormat,
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::IriParseError(_) => write!(f, "Failed parse Iri"),
+            Error::Io(err) => write!(f, "I/O error: {}", err),
+            Error::Xml(err) => write!(f, "Failed to read XML: {}", err),
+            Error::XmlWrite(err) => write!(f, "Failed to write XML: {}", err),
+            Error::Json(err) => write!(f, "Failed to read or write JSON: {}", err),
+            Error::UnknownFormat => write!(f, "Unknown format, expected XML or JSON"),
         }
     }
 }
@@ -19,6 +33,11 @@ impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
             Error::IriParseError(err) => Some(err),
+            Error::Io(err) => Some(err),
+            Error::Xml(err) => Some(err),
+            Error::XmlWrite(err) => Some(err),
+            Error::Json(err) => Some(err),
+            Error::UnknownFormat => None,
         }
     }
 }
@@ -28,3 +47,33 @@ impl From<oxiri::IriParseError> for Error {
         Self::IriParseError(err)
     }
 }
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<deserx::Error> for Error {
+    fn from(err: deserx::Error) -> Self {
+        Self::Xml(err)
+    }
+}
+
+impl From<SerError> for Error {
+    fn from(err: SerError) -> Self {
+        Self::XmlWrite(err)
+    }
+}
+
+impl From<quick_xml::Error> for Error {
+    fn from(err: quick_xml::Error) -> Self {
+        Self::XmlWrite(err.into())
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
@@ -0,0 +1,382 @@
+use std::io::{self, BufRead};
+
+use deserx::{DeserializeXml, SerializeXml};
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, Event};
+use serde::Serialize;
+
+use crate::de::{JsonRecordReader, XmlRecordReader};
+use crate::gedcomx::Record;
+use crate::{Error, GedcomX, Result};
+
+const NAMESPACE: &str = "http://gedcomx.org/v1/";
+
+/// A serialization format of GEDCOM X.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    Xml,
+    Json,
+}
+
+impl Format {
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::Xml => "application/x-gedcomx-v1+xml",
+            Self::Json => "application/x-gedcomx-v1+json",
+        }
+    }
+
+    /// The format of a media type, e.g. from a `Content-Type` header.
+    ///
+    /// Parameters like `charset` are ignored, and the generic XML and JSON
+    /// media types are accepted too.
+    pub fn from_media_type(media_type: &str) -> Option<Self> {
+        let essence = media_type.split(';').next().unwrap_or_default().trim();
+        let essence = essence.to_ascii_lowercase();
+        match essence.as_str() {
+            "application/x-gedcomx-v1+xml" | "application/xml" | "text/xml" => Some(Self::Xml),
+            "application/x-gedcomx-v1+json" | "application/json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Guesses the format from the start of the content, skipping a byte
+    /// order mark and whitespace.
+    pub fn sniff(content: &[u8]) -> Option<Self> {
+        let content = content.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(content);
+        match content.iter().find(|byte| !byte.is_ascii_whitespace()) {
+            Some(b'<') => Some(Self::Xml),
+            Some(b'{') => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// How [`write_with`] writes a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteOptions {
+    format: Format,
+    indent: Option<usize>,
+    declaration: bool,
+    namespace_prefix: Option<String>,
+}
+
+impl WriteOptions {
+    /// Compact output with an XML declaration and the GEDCOM X namespace as
+    /// the default namespace.
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            indent: None,
+            declaration: true,
+            namespace_prefix: None,
+        }
+    }
+}
+
+// Builder lite
+impl WriteOptions {
+    /// Puts each element or field on its own line, indented by `indent`
+    /// spaces per level.
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Whether to start XML with `<?xml ...?>`.
+    pub fn declaration(mut self, yes: bool) -> Self {
+        self.declaration = yes;
+        self
+    }
+
+    /// Writes the GEDCOM X elements in XML with `prefix`, e.g. `gx` for
+    /// `<gx:gedcomx xmlns:gx="http://gedcomx.org/v1/">`.
+    pub fn namespace_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.namespace_prefix = Some(prefix.into());
+        self
+    }
+}
+
+impl WriteOptions {
+    pub fn get_format(&self) -> Format {
+        self.format
+    }
+    pub fn get_indent(&self) -> Option<usize> {
+        self.indent
+    }
+    pub fn has_declaration(&self) -> bool {
+        self.declaration
+    }
+    pub fn get_namespace_prefix(&self) -> Option<&str> {
+        self.namespace_prefix.as_deref()
+    }
+}
+
+impl From<Format> for WriteOptions {
+    fn from(format: Format) -> Self {
+        Self::new(format)
+    }
+}
+
+/// Reads a document, detecting the format from the content.
+///
+/// ```
+/// let xml = r#"<gedcomx xmlns="http://gedcomx.org/v1/"><person id="P-1"/></gedcomx>"#;
+/// let json = r#"{"persons": [{"id": "P-1"}]}"#;
+/// assert_eq!(
+///     gedcomx_model::read(xml.as_bytes())?,
+///     gedcomx_model::read(json.as_bytes())?
+/// );
+/// # Ok::<(), gedcomx_model::Error>(())
+/// ```
+pub fn read<R: BufRead>(mut reader: R) -> Result<GedcomX> {
+    let format = sniff_reader(&mut reader)?;
+    read_as(reader, format)
+}
+
+/// Reads a document in `format`, e.g. from [`Format::from_media_type`].
+pub fn read_as<R: BufRead>(reader: R, format: Format) -> Result<GedcomX> {
+    match format {
+        Format::Xml => Ok(GedcomX::deserialize_xml(
+            &mut quick_xml::Reader::from_reader(reader),
+        )?),
+        Format::Json => Ok(serde_json::from_reader(reader)?),
+    }
+}
+
+/// Reads the top-level items of a document one at a time, detecting the
+/// format from the content, see [`RecordReader`].
+pub fn read_records<R: BufRead>(mut reader: R) -> Result<RecordReader<R>> {
+    let format = sniff_reader(&mut reader)?;
+    read_records_as(reader, format)
+}
+
+/// Reads the top-level items of a document in `format` one at a time.
+pub fn read_records_as<R: BufRead>(reader: R, format: Format) -> Result<RecordReader<R>> {
+    match format {
+        Format::Xml => Ok(RecordReader::Xml(XmlRecordReader::new(
+            quick_xml::Reader::from_reader(reader),
+        )?)),
+        Format::Json => Ok(RecordReader::Json(JsonRecordReader::new(reader)?)),
+    }
+}
+
+/// Writes `gedcomx` in `format`, compact and with an XML declaration.
+pub fn write<W: io::Write>(writer: W, gedcomx: &GedcomX, format: Format) -> Result<()> {
+    write_with(writer, gedcomx, &WriteOptions::new(format))
+}
+
+/// Writes `gedcomx` as described by `options`.
+///
+/// ```
+/// use gedcomx_model::{conclusion::Person, Format, GedcomX, WriteOptions};
+///
+/// let gedcomx = GedcomX::new().person(Person::new("P-1".parse().unwrap()));
+/// let options = WriteOptions::new(Format::Xml)
+///     .declaration(false)
+///     .namespace_prefix("gx");
+/// let mut xml = Vec::new();
+/// gedcomx_model::write_with(&mut xml, &gedcomx, &options)?;
+/// assert_eq!(
+///     String::from_utf8(xml).unwrap(),
+///     r#"<gx:gedcomx xmlns:gx="http://gedcomx.org/v1/"><gx:person id="P-1"/></gx:gedcomx>"#
+/// );
+/// # Ok::<(), gedcomx_model::Error>(())
+/// ```
+pub fn write_with<W: io::Write>(
+    writer: W,
+    gedcomx: &GedcomX,
+    options: &WriteOptions,
+) -> Result<()> {
+    match options.format {
+        Format::Xml => write_xml(writer, gedcomx, options),
+        Format::Json => write_json(writer, gedcomx, options),
+    }
+}
+
+fn write_xml<W: io::Write>(writer: W, gedcomx: &GedcomX, options: &WriteOptions) -> Result<()> {
+    let mut writer = match options.indent {
+        Some(indent) => quick_xml::Writer::new_with_indent(writer, b' ', indent),
+        None => quick_xml::Writer::new(writer),
+    };
+    if options.declaration {
+        writer.write_event(Event::Decl(BytesDecl::new(
+            "1.0",
+            Some("UTF-8"),
+            Some("yes"),
+        )))?;
+    }
+    match &options.namespace_prefix {
+        None => gedcomx.serialize_xml(&mut writer)?,
+        Some(prefix) => {
+            let mut buffer = quick_xml::Writer::new(Vec::new());
+            gedcomx.serialize_xml(&mut buffer)?;
+            add_namespace_prefix(&buffer.into_inner(), prefix, &mut writer)?;
+        }
+    }
+    Ok(())
+}
+
+/// Copies `xml` to `writer`, adding `prefix` to the unprefixed elements in
+/// the GEDCOM X namespace.
+///
+/// The content of `<text>` is copied as it is, since it is markup of its own,
+/// e.g. XHTML, and not GEDCOM X.
+fn add_namespace_prefix<W: io::Write>(
+    xml: &[u8],
+    prefix: &str,
+    writer: &mut quick_xml::Writer<W>,
+) -> Result<()> {
+    let mut reader = quick_xml::Reader::from_reader(xml);
+    let mut buf = Vec::new();
+    // Whether the default namespace of each open element is GEDCOM X.
+    let mut in_namespace = Vec::new();
+    // How many elements deep the content of a `<text>` is.
+    let mut in_text = 0usize;
+    loop {
+        let event = match reader.read_event_into(&mut buf)? {
+            Event::Start(start) if in_text > 0 => {
+                in_text += 1;
+                Event::Start(start)
+            }
+            Event::Empty(start) if in_text > 0 => Event::Empty(start),
+            Event::End(end) if in_text > 1 => {
+                in_text -= 1;
+                Event::End(end)
+            }
+            Event::Start(start) => {
+                let (start, gedcomx) = prefixed(&start, prefix, &in_namespace)?;
+                if gedcomx && start.local_name().as_ref() == b"text" {
+                    in_text = 1;
+                }
+                in_namespace.push(gedcomx);
+                Event::Start(start)
+            }
+            Event::Empty(start) => Event::Empty(prefixed(&start, prefix, &in_namespace)?.0),
+            Event::End(end) => {
+                in_text = 0;
+                let name = String::from_utf8_lossy(end.name().as_ref()).into_owned();
+                let name = match in_namespace.pop() {
+                    Some(true) if end.name().prefix().is_none() => format!("{}:{}", prefix, name),
+                    _ => name,
+                };
+                Event::End(BytesEnd::new(name))
+            }
+            Event::Eof => return Ok(()),
+            event => event,
+        };
+        writer.write_event(event)?;
+        buf.clear();
+    }
+}
+
+/// `start` with `prefix` if it is in the GEDCOM X namespace, and whether its
+/// default namespace is GEDCOM X.
+fn prefixed(
+    start: &BytesStart<'_>,
+    prefix: &str,
+    in_namespace: &[bool],
+) -> Result<(BytesStart<'static>, bool)> {
+    let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+    let mut gedcomx = in_namespace.last().copied().unwrap_or(false);
+    let mut attributes = Vec::new();
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(quick_xml::Error::from)?;
+        let value = attribute.unescape_value()?.into_owned();
+        if attribute.key.as_ref() == b"xmlns" {
+            gedcomx = value == NAMESPACE;
+            if gedcomx {
+                attributes.push((format!("xmlns:{}", prefix), value));
+                continue;
+            }
+        }
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        attributes.push((key, value));
+    }
+    let name = if gedcomx && start.name().prefix().is_none() {
+        format!("{}:{}", prefix, name)
+    } else {
+        name
+    };
+    let mut prefixed = BytesStart::new(name);
+    for (key, value) in &attributes {
+        prefixed.push_attribute((key.as_str(), value.as_str()));
+    }
+    Ok((prefixed, gedcomx))
+}
+
+fn write_json<W: io::Write>(writer: W, gedcomx: &GedcomX, options: &WriteOptions) -> Result<()> {
+    match options.indent {
+        Some(indent) => {
+            let indent = vec![b' '; indent];
+            let formatter = serde_json::ser::PrettyFormatter::with_indent(&indent);
+            let mut serializer = serde_json::Serializer::with_formatter(writer, formatter);
+            gedcomx.serialize(&mut serializer)?;
+        }
+        None => serde_json::to_writer(writer, gedcomx)?,
+    }
+    Ok(())
+}
+
+/// Skips a byte order mark and whitespace and guesses the format from the
+/// next byte.
+fn sniff_reader<R: BufRead>(reader: &mut R) -> Result<Format> {
+    let mut start = true;
+    loop {
+        let buf = reader.fill_buf()?;
+        if buf.is_empty() {
+            return Err(Error::UnknownFormat);
+        }
+        let mut skip = 0;
+        if start && buf.starts_with(b"\xEF\xBB\xBF") {
+            skip = 3;
+        }
+        start = false;
+        skip += buf[skip..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_whitespace())
+            .count();
+        if skip < buf.len() {
+            let format = Format::sniff(&buf[skip..]).ok_or(Error::UnknownFormat);
+            reader.consume(skip);
+            return format;
+        }
+        reader.consume(skip);
+    }
+}
+
+/// Reads the top-level items of a document in either format, see
+/// [`read_records`].
+pub enum RecordReader<R> {
+    Xml(XmlRecordReader<R>),
+    Json(JsonRecordReader<R>),
+}
+
+impl<R: BufRead> RecordReader<R> {
+    pub fn format(&self) -> Format {
+        match self {
+            Self::Xml(_) => Format::Xml,
+            Self::Json(_) => Format::Json,
+        }
+    }
+
+    /// The document without its items, in JSON only complete once all
+    /// records are read.
+    pub fn header(&self) -> &GedcomX {
+        match self {
+            Self::Xml(records) => records.header(),
+            Self::Json(records) => records.header(),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for RecordReader<R> {
+    type Item = Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Xml(records) => records.next().map(|record| Ok(record?)),
+            Self::Json(records) => records.next().map(|record| Ok(record?)),
+        }
+    }
+}
@@ -1,28 +1,41 @@
-use crate::common::{DateTime, IriRef, ResourceReference};
-use crate::ser::{SerError, SerializeXml, XmlSerializer};
+use crate::common::{impl_extensions, DateTime, Extensions, ResourceReference};
 use chrono::Utc;
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use deserx::{DeserializeXml, SerializeXml};
 use serde_with::TimestampMilliSeconds;
-use std::io;
 
 #[serde_with::serde_as]
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(remote = "Self")]
 pub struct Attribution {
     contributor: Option<ResourceReference>,
     #[serde_as(as = "TimestampMilliSeconds")]
+    #[xml(text_element)]
     modified: DateTime,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(Attribution);
+
 impl Attribution {
     pub fn new() -> Self {
         Self {
             contributor: None,
             modified: Utc::now(),
+            extensions: Extensions::default(),
         }
     }
 }
 
+impl Default for Attribution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Attribution {
     pub fn contributor<C: Into<ResourceReference>>(mut self, contributor: C) -> Self {
         self.set_contributor(contributor.into());
@@ -41,6 +54,13 @@ impl Attribution {
     pub fn set_modified(&mut self, modified: DateTime) {
         self.modified = modified;
     }
+
+    pub fn get_contributor(&self) -> Option<&ResourceReference> {
+        self.contributor.as_ref()
+    }
+    pub fn get_modified(&self) -> &DateTime {
+        &self.modified
+    }
 }
 pub fn verify_attribution_opt(
     a: Option<&Attribution>,
@@ -70,81 +90,3 @@ pub fn verify_attribution_opt(
     }
     Ok(())
 }
-impl SerializeXml for Attribution {
-    fn tag(&self) -> &str {
-        "attribution"
-    }
-    fn serialize_xml<W: io::Write>(&self, ser: &mut XmlSerializer<W>) -> Result<(), SerError> {
-        let elem = BytesStart::new(self.tag());
-        ser.write_event(Event::Start(elem))?;
-
-        let mut contributor = BytesStart::new("contributor");
-        contributor.push_attribute(("resource", self.contributor.as_ref().unwrap().resource()));
-        ser.write_event(Event::Empty(contributor))?;
-
-        ser.write_event(Event::Start(BytesStart::new("modified")))?;
-        ser.write_event(Event::Text(BytesText::new(&self.modified.to_rfc3339())))?;
-        ser.write_event(Event::End(BytesEnd::new("modified")))?;
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
-    }
-}
-
-impl DeserializeXml for Attribution {
-    fn deserialize_xml<R: std::io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-    ) -> Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
-        let mut attribution = Self::new();
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Empty(e) => {
-                    log::debug!("read Empty={:?}", e);
-                    match e.name().as_ref() {
-                        b"contributor" => {
-                            let attr = e.try_get_attribute("resource")?;
-                            if let Some(resource) = attr {
-                                attribution.set_contributor(ResourceReference::new(
-                                    IriRef::parse(resource.unescape_value()?.into())
-                                        .expect("parsing iri"),
-                                ));
-                            }
-                        }
-                        tag => todo!("handle {:?}", tag),
-                    }
-                }
-                Event::Start(e) => {
-                    log::debug!("read Start={:?}", e);
-                    match e.name().as_ref() {
-                        b"modified" => {
-                            log::trace!("found 'modified'");
-                            let mut date_buf = Vec::new();
-                            let event = deserializer.read_event_into(&mut date_buf)?;
-                            if let Event::Text(date_text) = event {
-                                log::debug!("date_text = {:?}", date_text);
-                                let modified = date_text
-                                    .unescape()?
-                                    .parse::<DateTime>()
-                                    .expect("attribution: failed parse date");
-                                attribution.set_modified(modified);
-                            }
-                        }
-                        tag => todo!("handle {:?}", tag),
-                    }
-                }
-                Event::End(e) => match e.name().as_ref() {
-                    b"attribution" => {
-                        log::trace!("found end of 'attribution' returning ...");
-                        break;
-                    }
-                    _tag => log::trace!("skipping '{:?}' ...", e),
-                },
-                e => {
-                    log::trace!("got: {:?} skipping ...", e);
-                }
-            }
-        }
-        log::debug!("attribution = {:?}", attribution);
-        Ok(attribution)
-    }
-}
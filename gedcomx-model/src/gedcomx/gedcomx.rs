@@ -1,38 +1,133 @@
+use std::slice::IterMut;
+
 use crate::agent::Agent;
-use crate::conclusion::{Document, Person, Relationship};
+use crate::common::{
+    impl_extensions, refers_to, Extensions, Identifier, IriRef, ResourceReference,
+};
+use crate::conclusion::{
+    Document, Event, Group, Person, PlaceDescription, PlaceReference, Relationship, Subject,
+};
 use crate::gedcomx::attribution::verify_attribution_opt;
 use crate::gedcomx::Attribution;
-use crate::ser::{SerError, SerializeXml};
 use crate::source::SourceDescription;
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, Event};
-use std::io;
+use deserx::{DeserializeXml, SerializeXml};
 
-#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename = "gedcomx", rename_all = "camelCase")]
+/// A GEDCOM X document, the collections of persons, relationships, sources
+/// and so on that make up a genealogical data set.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(rename = "gedcomx", rename_all = "camelCase", remote = "Self")]
+#[xml(
+    rename = "gedcomx",
+    namespace = "http://gedcomx.org/v1/",
+    order(
+        attribution,
+        persons,
+        relationships,
+        source_descriptions,
+        agents,
+        events,
+        places,
+        documents,
+        groups
+    )
+)]
 pub struct GedcomX {
+    #[xml(attribute)]
+    id: Option<IriRef>,
+    #[xml(attribute, rename = "xml:lang")]
+    lang: Option<String>,
+    /// The source description that describes this document.
+    #[xml(attribute)]
+    description: Option<IriRef>,
     attribution: Option<Attribution>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "person")]
     persons: Vec<Person>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "relationship")]
     relationships: Vec<Relationship>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "sourceDescription")]
     source_descriptions: Vec<SourceDescription>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "agent")]
     agents: Vec<Agent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "event")]
+    events: Vec<Event>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "place")]
+    places: Vec<PlaceDescription>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "document")]
     documents: Vec<Document>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "group")]
+    groups: Vec<Group>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(GedcomX);
+
 impl GedcomX {
     pub fn new() -> Self {
         Self {
+            id: None,
+            lang: None,
+            description: None,
             attribution: None,
             persons: Vec::new(),
             relationships: Vec::new(),
             source_descriptions: Vec::new(),
             agents: Vec::new(),
+            events: Vec::new(),
+            places: Vec::new(),
             documents: Vec::new(),
+            groups: Vec::new(),
+            extensions: Extensions::default(),
         }
     }
 }
 
+impl Default for GedcomX {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GedcomX {
+    pub fn id(mut self, id: IriRef) -> Self {
+        self.set_id(Some(id));
+        self
+    }
+
+    pub fn set_id(&mut self, id: Option<IriRef>) {
+        self.id = id;
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.set_lang(Some(lang.into()));
+        self
+    }
+
+    pub fn set_lang(&mut self, lang: Option<String>) {
+        self.lang = lang;
+    }
+
+    pub fn description(mut self, description: IriRef) -> Self {
+        self.set_description(Some(description));
+        self
+    }
+
+    pub fn set_description(&mut self, description: Option<IriRef>) {
+        self.description = description;
+    }
+
     pub fn person(mut self, p: Person) -> Self {
         self.add_person(p);
         self
@@ -78,6 +173,24 @@ impl GedcomX {
         self.agents.push(p);
     }
 
+    pub fn event(mut self, event: Event) -> Self {
+        self.add_event(event);
+        self
+    }
+
+    pub fn add_event(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    pub fn place(mut self, place: PlaceDescription) -> Self {
+        self.add_place(place);
+        self
+    }
+
+    pub fn add_place(&mut self, place: PlaceDescription) {
+        self.places.push(place);
+    }
+
     pub fn document(mut self, p: Document) -> Self {
         self.add_document(p);
         self
@@ -86,133 +199,204 @@ impl GedcomX {
     pub fn add_document(&mut self, p: Document) {
         self.documents.push(p);
     }
+
+    pub fn group(mut self, group: Group) -> Self {
+        self.add_group(group);
+        self
+    }
+
+    pub fn add_group(&mut self, group: Group) {
+        self.groups.push(group);
+    }
 }
 
 impl GedcomX {
+    pub fn get_id(&self) -> Option<&IriRef> {
+        self.id.as_ref()
+    }
+    pub fn get_lang(&self) -> Option<&str> {
+        self.lang.as_deref()
+    }
+    pub fn get_description(&self) -> Option<&IriRef> {
+        self.description.as_ref()
+    }
+    pub fn get_attribution(&self) -> Option<&Attribution> {
+        self.attribution.as_ref()
+    }
+
     pub fn persons(&self) -> &[Person] {
         self.persons.as_slice()
     }
-}
+    pub fn persons_mut(&mut self) -> IterMut<'_, Person> {
+        self.persons.iter_mut()
+    }
 
-impl SerializeXml for GedcomX {
-    fn tag(&self) -> &str {
-        "gedcomx"
+    pub fn relationships(&self) -> &[Relationship] {
+        self.relationships.as_slice()
+    }
+    pub fn relationships_mut(&mut self) -> IterMut<'_, Relationship> {
+        self.relationships.iter_mut()
     }
 
-    fn serialize_xml<W: io::Write>(&self, ser: &mut quick_xml::Writer<W>) -> Result<(), SerError> {
-        let mut root = BytesStart::new(self.tag());
-        root.push_attribute(("xmlns", "http://gedcomx.org/v1/"));
-        ser.write_event(Event::Start(root))?;
+    pub fn source_descriptions(&self) -> &[SourceDescription] {
+        self.source_descriptions.as_slice()
+    }
+    pub fn source_descriptions_mut(&mut self) -> IterMut<'_, SourceDescription> {
+        self.source_descriptions.iter_mut()
+    }
 
-        if let Some(attribution) = &self.attribution {
-            attribution.serialize_xml(ser)?;
-        }
-        for person in &self.persons {
-            person.serialize_xml(ser)?;
-        }
-        for relationship in &self.relationships {
-            relationship.serialize_xml(ser)?;
-        }
-        for source_description in &self.source_descriptions {
-            source_description.serialize_xml(ser)?;
-        }
-        for agent in &self.agents {
-            agent.serialize_xml(ser)?;
-        }
-        for document in &self.documents {
-            document.serialize_xml(ser)?;
-        }
+    pub fn agents(&self) -> &[Agent] {
+        self.agents.as_slice()
+    }
+    pub fn agents_mut(&mut self) -> IterMut<'_, Agent> {
+        self.agents.iter_mut()
+    }
 
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
+    pub fn events(&self) -> &[Event] {
+        self.events.as_slice()
+    }
+    pub fn events_mut(&mut self) -> IterMut<'_, Event> {
+        self.events.iter_mut()
+    }
+
+    pub fn places(&self) -> &[PlaceDescription] {
+        self.places.as_slice()
+    }
+    pub fn places_mut(&mut self) -> IterMut<'_, PlaceDescription> {
+        self.places.iter_mut()
+    }
+
+    pub fn documents(&self) -> &[Document] {
+        self.documents.as_slice()
+    }
+    pub fn documents_mut(&mut self) -> IterMut<'_, Document> {
+        self.documents.iter_mut()
+    }
+
+    pub fn groups(&self) -> &[Group] {
+        self.groups.as_slice()
+    }
+    pub fn groups_mut(&mut self) -> IterMut<'_, Group> {
+        self.groups.iter_mut()
+    }
+
+    /// The source description that describes this document, if it is in
+    /// this document.
+    pub fn resolve_description(&self) -> Option<&SourceDescription> {
+        let description = self.description.as_ref()?;
+        self.source_descriptions
+            .iter()
+            .find(|source| refers_to(description, source.get_id()))
+    }
+
+    /// The person `person` refers to, if it is in this document.
+    pub fn resolve_person(&self, person: &ResourceReference) -> Option<&Person> {
+        self.persons
+            .iter()
+            .find(|candidate| candidate.is_referenced_by(person))
+    }
+
+    /// The relationship `relationship` refers to, if it is in this document.
+    pub fn resolve_relationship(&self, relationship: &ResourceReference) -> Option<&Relationship> {
+        self.relationships
+            .iter()
+            .find(|candidate| candidate.is_referenced_by(relationship))
+    }
+
+    /// The place description `place` refers to, if it is in this document.
+    pub fn resolve_place(&self, place: &PlaceReference) -> Option<&PlaceDescription> {
+        self.places
+            .iter()
+            .find(|description| description.is_referenced_by(place))
+    }
+
+    /// All persons, relationships, events, places and groups in this document.
+    pub fn subjects(&self) -> impl Iterator<Item = SubjectRef<'_>> {
+        let persons = self.persons.iter().map(SubjectRef::Person);
+        let relationships = self.relationships.iter().map(SubjectRef::Relationship);
+        let events = self.events.iter().map(SubjectRef::Event);
+        let places = self.places.iter().map(SubjectRef::Place);
+        let groups = self.groups.iter().map(SubjectRef::Group);
+        persons
+            .chain(relationships)
+            .chain(events)
+            .chain(places)
+            .chain(groups)
+    }
+
+    /// The first subject with an identifier that matches `identifier`, see
+    /// [`Identifier::matches`].
+    pub fn find_by_identifier(&self, identifier: &Identifier) -> Option<SubjectRef<'_>> {
+        self.subjects()
+            .find(|subject| subject.subject().has_identifier(identifier))
     }
 }
 
-impl DeserializeXml for GedcomX {
-    fn deserialize_xml<R: io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-    ) -> Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Start(e) => {
-                    log::trace!("read start={:?}", e);
-                    match e.name().as_ref() {
-                        b"gedcomx" => break,
-                        e => {
-                            return Err(quick_xml::Error::UnexpectedToken(format!(
-                                "got tag: '{}', expected: 'gedcomx'",
-                                String::from_utf8_lossy(e)
-                            )))
-                        }
-                    }
-                }
-                e => log::trace!("skipping {:?}", e),
-            };
-        }
-        log::trace!("buf = {:?}", String::from_utf8_lossy(&buf));
-        let mut gedcomx = Self::new();
-
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Start(e) => {
-                    log::trace!("read start={:?}", e);
-                    match e.name().as_ref() {
-                        b"agent" => {
-                            let agent = Agent::deserialize_xml_with_start(deserializer, &e)?;
-                            gedcomx.add_agent(agent);
-                        }
-                        b"attribution" => {
-                            let attribution = Attribution::deserialize_xml(deserializer)?;
-                            gedcomx.set_attribution(attribution);
-                        }
-                        b"document" => {
-                            let document = Document::deserialize_xml_with_start(deserializer, &e)?;
-                            gedcomx.add_document(document);
-                        }
-                        b"person" => {
-                            let person = Person::deserialize_xml_with_start(deserializer, &e)?;
-                            gedcomx.add_person(person);
-                        }
-                        b"relationship" => {
-                            let relationship = Relationship::deserialize_xml_with_start(deserializer, &e)?;
-                            gedcomx.add_relationship(relationship);
-                        }
-                        b"sourceDescription" => {
-                            let source_description = SourceDescription::deserialize_xml_with_start(deserializer, &e)?;
-                            gedcomx.add_source_description(source_description);
-                        }
-                        e => {
-                            return Err(quick_xml::Error::UnexpectedToken(format!(
-                                "got tag: '{}', expected one of: ['attribution', 'person', 'relationship', 'sourceDescription', 'agent', 'document']'",
-                                String::from_utf8_lossy(e)
-                            )))
-                        }
-                    }
+/// Adds items to the collection of their type, e.g.
+/// `gedcomx.extend(persons)` or `persons.into_iter().collect::<GedcomX>()`.
+macro_rules! impl_collection {
+    ($($item:ty => $field:ident,)*) => {
+        $(
+            impl Extend<$item> for GedcomX {
+                fn extend<I: IntoIterator<Item = $item>>(&mut self, iter: I) {
+                    self.$field.extend(iter);
                 }
-                Event::End(e) => {
-                    log::trace!("read End={:?}", e);
-                    match e.name().as_ref() {
-                        b"gedcomx" => {
-                            log::debug!("found '</gedcomx>' returning ..");
-                            break;
-                        }
-                        _tag => log::trace!("skipping '{:?}' ...", e),
-                    }
+            }
+
+            impl FromIterator<$item> for GedcomX {
+                fn from_iter<I: IntoIterator<Item = $item>>(iter: I) -> Self {
+                    let mut gedcomx = Self::new();
+                    gedcomx.extend(iter);
+                    gedcomx
                 }
-                e => log::trace!("skipping {:?}", e),
-            };
+            }
+        )*
+    };
+}
+
+impl_collection! {
+    Person => persons,
+    Relationship => relationships,
+    SourceDescription => source_descriptions,
+    Agent => agents,
+    Event => events,
+    PlaceDescription => places,
+    Document => documents,
+    Group => groups,
+}
+
+/// A subject in a [`GedcomX`] document.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubjectRef<'a> {
+    Person(&'a Person),
+    Relationship(&'a Relationship),
+    Event(&'a Event),
+    Place(&'a PlaceDescription),
+    Group(&'a Group),
+}
+
+impl<'a> SubjectRef<'a> {
+    pub fn subject(&self) -> &'a Subject {
+        match self {
+            Self::Person(person) => person.subject(),
+            Self::Relationship(relationship) => relationship.subject(),
+            Self::Event(event) => event.subject(),
+            Self::Place(place) => place.subject(),
+            Self::Group(group) => group.subject(),
         }
-        log::trace!("buf = {:?}", String::from_utf8_lossy(&buf));
-        log::trace!("{:#?}", gedcomx);
-        Ok(gedcomx)
     }
 }
 
 pub fn verify_gedcomx(a: &GedcomX, b: &GedcomX) -> Result<(), String> {
     verify_attribution_opt(a.attribution.as_ref(), b.attribution.as_ref())?;
+    assert_eq!(a.id, b.id);
+    assert_eq!(a.lang, b.lang);
+    assert_eq!(a.description, b.description);
     assert_eq!(a.agents, b.agents);
     assert_eq!(a.documents, b.documents);
+    assert_eq!(a.events, b.events);
+    assert_eq!(a.places, b.places);
+    assert_eq!(a.groups, b.groups);
     for (a_person, b_person) in a.persons.iter().zip(b.persons.iter()) {
         assert_eq!(a_person, b_person);
     }
@@ -228,8 +412,6 @@ pub fn verify_gedcomx(a: &GedcomX, b: &GedcomX) -> Result<(), String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    
-    
 
     // fn gedcomx_record() -> GedcomX {
     //     GedcomX::new().person(Person::new())
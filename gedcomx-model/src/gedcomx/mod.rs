@@ -1,5 +1,9 @@
 pub mod attribution;
+#[allow(clippy::module_inception)]
 mod gedcomx;
+mod record;
 
 pub use attribution::Attribution;
-pub use gedcomx::{verify_gedcomx, GedcomX};
+pub use gedcomx::{verify_gedcomx, GedcomX, SubjectRef};
+pub(crate) use record::Collection;
+pub use record::Record;
@@ -0,0 +1,135 @@
+use crate::agent::Agent;
+use crate::conclusion::{Document, Event, Group, Person, PlaceDescription, Relationship};
+use crate::gedcomx::{Attribution, GedcomX};
+use crate::source::SourceDescription;
+
+/// A top-level item of a [`GedcomX`] document, e.g. read one at a time with
+/// [`XmlRecordReader`](crate::de::XmlRecordReader).
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Record {
+    Attribution(Attribution),
+    Person(Person),
+    Relationship(Relationship),
+    SourceDescription(SourceDescription),
+    Agent(Agent),
+    Event(Event),
+    Place(PlaceDescription),
+    Document(Document),
+    Group(Group),
+    /// An element that is not part of GEDCOM X, as an XML fragment.
+    Extension(String),
+}
+
+/// The collections of a [`GedcomX`](crate::GedcomX) document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Collection {
+    Persons,
+    Relationships,
+    SourceDescriptions,
+    Agents,
+    Events,
+    Places,
+    Documents,
+    Groups,
+}
+
+impl Collection {
+    pub(crate) const ALL: [Collection; 8] = [
+        Self::Persons,
+        Self::Relationships,
+        Self::SourceDescriptions,
+        Self::Agents,
+        Self::Events,
+        Self::Places,
+        Self::Documents,
+        Self::Groups,
+    ];
+
+    /// The name of an item in XML.
+    pub(crate) fn tag(self) -> &'static str {
+        match self {
+            Self::Persons => "person",
+            Self::Relationships => "relationship",
+            Self::SourceDescriptions => "sourceDescription",
+            Self::Agents => "agent",
+            Self::Events => "event",
+            Self::Places => "place",
+            Self::Documents => "document",
+            Self::Groups => "group",
+        }
+    }
+
+    /// The name of the array in JSON.
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            Self::Persons => "persons",
+            Self::Relationships => "relationships",
+            Self::SourceDescriptions => "sourceDescriptions",
+            Self::Agents => "agents",
+            Self::Events => "events",
+            Self::Places => "places",
+            Self::Documents => "documents",
+            Self::Groups => "groups",
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($($item:ty => $variant:ident,)*) => {
+        $(
+            impl From<$item> for Record {
+                fn from(item: $item) -> Self {
+                    Self::$variant(item)
+                }
+            }
+        )*
+    };
+}
+
+impl_from! {
+    Attribution => Attribution,
+    Person => Person,
+    Relationship => Relationship,
+    SourceDescription => SourceDescription,
+    Agent => Agent,
+    Event => Event,
+    PlaceDescription => Place,
+    Document => Document,
+    Group => Group,
+}
+
+impl GedcomX {
+    /// Adds `record` to the collection of its type, an extension element is
+    /// added to [`GedcomX::extensions`].
+    pub fn add_record(&mut self, record: Record) {
+        match record {
+            Record::Attribution(attribution) => self.set_attribution(attribution),
+            Record::Person(person) => self.add_person(person),
+            Record::Relationship(relationship) => self.add_relationship(relationship),
+            Record::SourceDescription(description) => self.add_source_description(description),
+            Record::Agent(agent) => self.add_agent(agent),
+            Record::Event(event) => self.add_event(event),
+            Record::Place(place) => self.add_place(place),
+            Record::Document(document) => self.add_document(document),
+            Record::Group(group) => self.add_group(group),
+            Record::Extension(xml) => self.extensions_mut().xml_mut().add_element(xml),
+        }
+    }
+}
+
+impl Extend<Record> for GedcomX {
+    fn extend<I: IntoIterator<Item = Record>>(&mut self, iter: I) {
+        for record in iter {
+            self.add_record(record);
+        }
+    }
+}
+
+impl FromIterator<Record> for GedcomX {
+    fn from_iter<I: IntoIterator<Item = Record>>(iter: I) -> Self {
+        let mut gedcomx = Self::new();
+        gedcomx.extend(iter);
+        gedcomx
+    }
+}
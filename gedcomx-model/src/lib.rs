@@ -1,11 +1,19 @@
 pub mod agent;
 pub mod common;
 pub mod conclusion;
+pub mod date;
+pub mod de;
 pub mod error;
+mod format;
 pub mod gedcomx;
+pub mod name;
 pub mod ser;
 pub mod source;
 pub mod types;
 
 pub use crate::error::{Error, Result};
+pub use crate::format::{
+    read, read_as, read_records, read_records_as, write, write_with, Format, RecordReader,
+    WriteOptions,
+};
 pub use crate::gedcomx::GedcomX;
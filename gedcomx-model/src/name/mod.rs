@@ -0,0 +1,57 @@
+//! Splitting the full text of a [`NameForm`](crate::conclusion::NameForm)
+//! into [`NamePart`]s.
+//!
+//! ```
+//! use gedcomx_model::conclusion::NameForm;
+//! use gedcomx_model::types::NamePartType;
+//!
+//! let mut form = NameForm::new().full_text("Ludwig van Beethoven".into());
+//! let confidence = form.parse_parts();
+//! assert_eq!(form.get_part(&NamePartType::Surname), Some("van Beethoven"));
+//! assert!(confidence > 0.5);
+//! ```
+use crate::conclusion::NamePart;
+
+mod standard;
+
+pub use standard::StandardNameParser;
+
+/// Splits the full text of a name into parts.
+///
+/// Implement this to use your own rules with
+/// [`NameForm::parse_parts_with`](crate::conclusion::NameForm::parse_parts_with).
+pub trait NameParser {
+    /// Splits `full_text`, written in the language `lang` if known.
+    fn parse(&self, full_text: &str, lang: Option<&str>) -> ParsedName;
+}
+
+/// The parts of a name and how sure the parser is of the split.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedName {
+    parts: Vec<NamePart>,
+    confidence: f32,
+}
+
+impl ParsedName {
+    /// `confidence` is clamped to `0.0..=1.0`.
+    pub fn new(parts: Vec<NamePart>, confidence: f32) -> Self {
+        Self {
+            parts,
+            confidence: confidence.clamp(0.0, 1.0),
+        }
+    }
+
+    pub fn parts(&self) -> &[NamePart] {
+        self.parts.as_slice()
+    }
+
+    pub fn into_parts(self) -> Vec<NamePart> {
+        self.parts
+    }
+
+    /// From 0 (a guess) to 1 (certain), splits below about 0.5 should be
+    /// reviewed.
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+}
@@ -0,0 +1,372 @@
+use crate::{
+    conclusion::NamePart,
+    types::{NamePartQualifierType, NamePartType},
+};
+
+use super::{NameParser, ParsedName};
+
+/// Splits names by the conventions of their language.
+///
+/// - Western order, e.g. `John Fitzgerald Kennedy`, with prefixes (`Dr.`,
+///   `Sir`) and suffixes (`Jr.`, `III`). `Kennedy, John` is read surname
+///   first.
+/// - Particles belong to the surname, e.g. `Ludwig van Beethoven` or
+///   `Maria de la Cruz`.
+/// - Scandinavian patronymics, e.g. `Anders Svensson` or `Anna Persdotter
+///   Berg`, are qualified as [`Patronymic`](NamePartQualifierType::Patronymic).
+///   Without a language only the unambiguous `-sson` and `-dotter` endings are
+///   recognized.
+/// - Chinese, Japanese, Korean, Vietnamese and Hungarian names are read family
+///   name first, e.g. `Mao Zedong`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StandardNameParser {
+    default_lang: Option<String>,
+}
+
+const PREFIXES: [&str; 20] = [
+    "mr", "mrs", "ms", "miss", "mx", "dr", "prof", "rev", "sir", "dame", "lord", "lady", "capt",
+    "col", "gen", "hr", "herr", "fru", "frk", "fröken",
+];
+
+const SUFFIXES: [&str; 10] = [
+    "jr", "jnr", "sr", "snr", "ii", "iii", "iv", "esq", "phd", "md",
+];
+
+const PARTICLES: [&str; 21] = [
+    "van", "von", "der", "den", "de", "del", "della", "di", "da", "du", "des", "la", "le", "ten",
+    "ter", "af", "av", "zu", "bin", "ibn", "al",
+];
+
+const PATRONYMIC_ENDINGS: [&str; 8] = [
+    "sson", "son", "søn", "sen", "dotter", "datter", "dóttir", "dottir",
+];
+
+/// Endings that mark a patronymic even when the language is not known.
+const UNAMBIGUOUS_PATRONYMIC_ENDINGS: [&str; 4] = ["sson", "dotter", "datter", "dóttir"];
+
+const SCANDINAVIAN_LANGS: [&str; 7] = ["sv", "da", "no", "nb", "nn", "is", "fo"];
+
+const FAMILY_FIRST_LANGS: [&str; 5] = ["zh", "ja", "ko", "vi", "hu"];
+
+impl StandardNameParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+// Builder lite
+impl StandardNameParser {
+    /// The language of names that don't have one, e.g. `sv` for a Swedish
+    /// parish register.
+    pub fn default_lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.default_lang = Some(lang.into());
+        self
+    }
+}
+
+impl NameParser for StandardNameParser {
+    fn parse(&self, full_text: &str, lang: Option<&str>) -> ParsedName {
+        let lang = lang
+            .filter(|lang| !lang.is_empty())
+            .or(self.default_lang.as_deref())
+            .map(|lang| {
+                lang.split(['-', '_'])
+                    .next()
+                    .unwrap_or_default()
+                    .to_lowercase()
+            });
+        let lang = lang.as_deref();
+        let tokens: Vec<_> = full_text.split_whitespace().collect();
+        if tokens.is_empty() {
+            return ParsedName::new(Vec::new(), 0.0);
+        }
+        if lang.is_some_and(|lang| FAMILY_FIRST_LANGS.contains(&lang)) {
+            return family_first(&tokens, lang == Some("hu"));
+        }
+        let scandinavian = lang.map(|lang| SCANDINAVIAN_LANGS.contains(&lang));
+        if let Some(comma) = tokens.iter().position(|token| token.ends_with(',')) {
+            if !tokens[comma + 1..]
+                .iter()
+                .all(|token| is_one_of(&SUFFIXES, token))
+            {
+                return surname_first(&tokens, comma, scandinavian);
+            }
+        }
+        western(&tokens, scandinavian)
+    }
+}
+
+/// `Anders Svensson`, `Dr. Ludwig van Beethoven` or `John Smith, Jr.`
+fn western(tokens: &[&str], scandinavian: Option<bool>) -> ParsedName {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while start + 1 < tokens.len() && is_one_of(&PREFIXES, tokens[start]) {
+        parts.push(part(NamePartType::Prefix, &tokens[start..=start]));
+        start += 1;
+    }
+    let mut end = tokens.len();
+    while end > start + 1 && is_one_of(&SUFFIXES, tokens[end - 1]) {
+        end -= 1;
+    }
+    let names = &tokens[start..end];
+    let confidence = if let [name] = names {
+        parts.push(part(NamePartType::Given, &[name]));
+        0.4
+    } else {
+        let last = names.len() - 1;
+        let particle = (1..last).find(|&index| is_one_of(&PARTICLES, names[index]));
+        let patronymic =
+            |index: usize| particle.is_none() && is_patronymic(names[index], scandinavian);
+        let (surname_start, confidence) = if let Some(particle) = particle {
+            (particle, 0.8)
+        } else if last >= 2 && patronymic(last - 1) {
+            (last - 1, 0.8)
+        } else if last == 1 || patronymic(last) {
+            (last, 0.9)
+        } else {
+            // A middle name can be part of a double surname.
+            (last, 0.7)
+        };
+        parts.extend(
+            names[..surname_start]
+                .iter()
+                .map(|name| part(NamePartType::Given, &[name])),
+        );
+        if particle.is_some() {
+            parts.push(part(NamePartType::Surname, &names[surname_start..]));
+        } else {
+            for (index, name) in names.iter().enumerate().skip(surname_start) {
+                let surname = part(NamePartType::Surname, &[name]);
+                parts.push(if patronymic(index) {
+                    surname.qualifier(NamePartQualifierType::Patronymic)
+                } else {
+                    surname
+                });
+            }
+        }
+        confidence
+    };
+    parts.extend(
+        tokens[end..]
+            .iter()
+            .map(|suffix| part(NamePartType::Suffix, &[suffix])),
+    );
+    ParsedName::new(parts, confidence)
+}
+
+/// `Kennedy, John Fitzgerald`, the surname ends at the token with the comma.
+fn surname_first(tokens: &[&str], comma: usize, scandinavian: Option<bool>) -> ParsedName {
+    let surname = &tokens[..=comma];
+    let mut parts = vec![
+        if surname.len() == 1 && is_patronymic(surname[0], scandinavian) {
+            part(NamePartType::Surname, surname).qualifier(NamePartQualifierType::Patronymic)
+        } else {
+            part(NamePartType::Surname, surname)
+        },
+    ];
+    let mut end = tokens.len();
+    while end > comma + 1 && is_one_of(&SUFFIXES, tokens[end - 1]) {
+        end -= 1;
+    }
+    parts.extend(
+        tokens[comma + 1..end]
+            .iter()
+            .map(|name| part(NamePartType::Given, &[name])),
+    );
+    parts.extend(
+        tokens[end..]
+            .iter()
+            .map(|suffix| part(NamePartType::Suffix, &[suffix])),
+    );
+    ParsedName::new(parts, 0.9)
+}
+
+/// `Mao Zedong` or `毛泽东`.
+fn family_first(tokens: &[&str], hungarian: bool) -> ParsedName {
+    if let [name] = tokens {
+        let chars: Vec<_> = name.chars().collect();
+        // Names in Chinese or Korean script are written without spaces, most
+        // family names are a single character.
+        if !hungarian && (2..=4).contains(&chars.len()) && chars.iter().all(|c| !c.is_ascii()) {
+            let parts = vec![
+                NamePart::new(NamePartType::Surname, chars[..1].iter().collect()),
+                NamePart::new(NamePartType::Given, chars[1..].iter().collect()),
+            ];
+            return ParsedName::new(parts, 0.6);
+        }
+        return ParsedName::new(vec![part(NamePartType::Given, tokens)], 0.3);
+    }
+    let mut parts = vec![part(NamePartType::Surname, &tokens[..1])];
+    parts.extend(
+        tokens[1..]
+            .iter()
+            .map(|name| part(NamePartType::Given, &[name])),
+    );
+    ParsedName::new(parts, 0.9)
+}
+
+fn part(r#type: NamePartType, tokens: &[&str]) -> NamePart {
+    let value = tokens.join(" ");
+    NamePart::new(r#type, value.trim_end_matches(',').into())
+}
+
+/// Compares `token` without case and trailing punctuation.
+fn is_one_of(words: &[&str], token: &str) -> bool {
+    let word = token.trim_end_matches(['.', ',']).to_lowercase();
+    words.contains(&word.as_str())
+}
+
+/// `scandinavian` is `None` when the language is not known.
+fn is_patronymic(token: &str, scandinavian: Option<bool>) -> bool {
+    let word = token.trim_end_matches(',').to_lowercase();
+    let endings: &[&str] = match scandinavian {
+        Some(true) => &PATRONYMIC_ENDINGS,
+        Some(false) => &[],
+        None => &UNAMBIGUOUS_PATRONYMIC_ENDINGS,
+    };
+    endings
+        .iter()
+        .any(|ending| word.len() > ending.len() + 2 && word.ends_with(ending))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_parts(full_text: &str, lang: Option<&str>, expected: &[(NamePartType, &str)]) {
+        let parsed = StandardNameParser::new().parse(full_text, lang);
+        let parts: Vec<_> = parsed
+            .parts()
+            .iter()
+            .map(|part| (part.get_type().unwrap().clone(), part.get_value()))
+            .collect();
+        assert_eq!(parts, expected, "{}", full_text);
+    }
+
+    fn confidence(full_text: &str) -> f32 {
+        StandardNameParser::new()
+            .parse(full_text, None)
+            .confidence()
+    }
+
+    use NamePartType::{Given, Prefix, Suffix, Surname};
+
+    #[test]
+    fn western_order() {
+        assert_parts(
+            "John Fitzgerald Kennedy",
+            Some("en"),
+            &[(Given, "John"), (Given, "Fitzgerald"), (Surname, "Kennedy")],
+        );
+        assert_parts(
+            "Dr. Martin Luther King Jr.",
+            None,
+            &[
+                (Prefix, "Dr."),
+                (Given, "Martin"),
+                (Given, "Luther"),
+                (Surname, "King"),
+                (Suffix, "Jr."),
+            ],
+        );
+        assert_parts(
+            "Henry Ford III",
+            None,
+            &[(Given, "Henry"), (Surname, "Ford"), (Suffix, "III")],
+        );
+        assert_parts(
+            "Kennedy, John Fitzgerald",
+            None,
+            &[(Surname, "Kennedy"), (Given, "John"), (Given, "Fitzgerald")],
+        );
+        assert_parts(
+            "John Smith, Jr.",
+            None,
+            &[(Given, "John"), (Surname, "Smith"), (Suffix, "Jr.")],
+        );
+    }
+
+    #[test]
+    fn particles_belong_to_the_surname() {
+        assert_parts(
+            "Ludwig van Beethoven",
+            None,
+            &[(Given, "Ludwig"), (Surname, "van Beethoven")],
+        );
+        assert_parts(
+            "Maria de la Cruz",
+            Some("es"),
+            &[(Given, "Maria"), (Surname, "de la Cruz")],
+        );
+        assert_parts(
+            "Jan van der Berg",
+            None,
+            &[(Given, "Jan"), (Surname, "van der Berg")],
+        );
+    }
+
+    #[test]
+    fn scandinavian_patronymics() {
+        let parsed = StandardNameParser::new().parse("Anders Svensson", Some("sv"));
+        let surname = &parsed.parts()[1];
+        assert_eq!(surname.get_value(), "Svensson");
+        assert!(surname.has_qualifier(&NamePartQualifierType::Patronymic));
+
+        let parsed = StandardNameParser::new().parse("Anna Persdotter Berg", None);
+        assert_parts(
+            "Anna Persdotter Berg",
+            None,
+            &[(Given, "Anna"), (Surname, "Persdotter"), (Surname, "Berg")],
+        );
+        assert!(parsed.parts()[1].has_qualifier(&NamePartQualifierType::Patronymic));
+        assert!(!parsed.parts()[2].has_qualifier(&NamePartQualifierType::Patronymic));
+
+        // "-sen" is only a patronymic in a Scandinavian name.
+        let parsed = StandardNameParser::new().parse("Ole Hansen", Some("no"));
+        assert!(parsed.parts()[1].has_qualifier(&NamePartQualifierType::Patronymic));
+        let parsed = StandardNameParser::new().parse("Ole Hansen", None);
+        assert!(parsed.parts()[1].qualifiers().is_empty());
+        let parsed = StandardNameParser::new()
+            .default_lang("da")
+            .parse("Ole Hansen", None);
+        assert!(parsed.parts()[1].has_qualifier(&NamePartQualifierType::Patronymic));
+        let parsed = StandardNameParser::new().parse("Jack Robinson", Some("en"));
+        assert!(parsed.parts()[1].qualifiers().is_empty());
+    }
+
+    #[test]
+    fn family_name_first() {
+        assert_parts(
+            "Mao Zedong",
+            Some("zh"),
+            &[(Surname, "Mao"), (Given, "Zedong")],
+        );
+        assert_parts(
+            "Nguyen Van An",
+            Some("vi"),
+            &[(Surname, "Nguyen"), (Given, "Van"), (Given, "An")],
+        );
+        assert_parts(
+            "Bartók Béla",
+            Some("hu-HU"),
+            &[(Surname, "Bartók"), (Given, "Béla")],
+        );
+        assert_parts(
+            "毛泽东",
+            Some("zh-Hans"),
+            &[(Surname, "毛"), (Given, "泽东")],
+        );
+        let parsed = StandardNameParser::new().parse("毛泽东", Some("zh"));
+        assert!(parsed.confidence() < 0.7);
+    }
+
+    #[test]
+    fn uncertain_splits_have_low_confidence() {
+        let single = confidence("Madonna");
+        let middle = confidence("John Fitzgerald Kennedy");
+        let simple = confidence("John Kennedy");
+        assert!(single < 0.5);
+        assert!(single < middle && middle < simple);
+        assert_eq!(confidence("  "), 0.0);
+    }
+}
@@ -0,0 +1,203 @@
+use std::io;
+
+use serde::Serialize;
+
+use crate::agent::Agent;
+use crate::conclusion::{Document, Event, Group, Person, PlaceDescription, Relationship};
+use crate::gedcomx::{Attribution, Collection, GedcomX, Record};
+use crate::ser::write_item_methods;
+use crate::source::SourceDescription;
+
+/// Writes a GEDCOM X JSON document one top-level item at a time.
+///
+/// In JSON the items of a collection must be in one array, so only one array
+/// is written at a time: items of the collection of the first item are
+/// written directly, items of other collections are kept in memory until
+/// [`end_collection`](Self::end_collection) or [`finish`](Self::finish).
+/// Writing the items collection by collection and calling `end_collection`
+/// in between keeps the memory use bounded.
+///
+/// ```
+/// use gedcomx_model::conclusion::{Person, Relationship};
+/// use gedcomx_model::ser::JsonRecordWriter;
+/// use gedcomx_model::GedcomX;
+///
+/// let mut records = JsonRecordWriter::new(Vec::new(), &GedcomX::new())?;
+/// let father = Person::new("P-1".parse().unwrap());
+/// let son = Person::new("P-2".parse().unwrap());
+/// records.write_person(&father)?;
+/// records.write_person(&son)?;
+/// records.end_collection()?;
+/// records.write_relationship(&Relationship::parent_child(&father, &son))?;
+/// let json = String::from_utf8(records.finish()?).unwrap();
+/// assert!(json.starts_with(r#"{"persons":[{"id":"P-1"},{"id":"P-2"}],"relationships":["#));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub struct JsonRecordWriter<W: io::Write> {
+    writer: W,
+    has_fields: bool,
+    has_attribution: bool,
+    /// The attribution given while an array was being written.
+    attribution: Option<Vec<u8>>,
+    /// The collection whose array is being written, and if it has items.
+    open: Option<(Collection, bool)>,
+    ended: Vec<Collection>,
+    /// The items of other collections, separated by commas.
+    pending: Vec<(Collection, Vec<u8>)>,
+}
+
+impl<W: io::Write> JsonRecordWriter<W> {
+    /// Writes the start of the root object, with the fields and extensions
+    /// of `header` except its items.
+    pub fn new(writer: W, header: &GedcomX) -> serde_json::Result<Self> {
+        let mut records = Self {
+            writer,
+            has_fields: false,
+            has_attribution: false,
+            attribution: None,
+            open: None,
+            ended: Vec::new(),
+            pending: Vec::new(),
+        };
+        records.write_all(b"{")?;
+        if let serde_json::Value::Object(fields) = serde_json::to_value(header)? {
+            let collections = Collection::ALL.map(Collection::key);
+            for (key, value) in fields {
+                if collections.contains(&key.as_str()) {
+                    continue;
+                }
+                records.has_attribution |= key == "attribution";
+                records.write_key(&key)?;
+                serde_json::to_writer(&mut records.writer, &value)?;
+            }
+        }
+        Ok(records)
+    }
+
+    /// Writes the attribution of the document, which can only be given once,
+    /// also counting the attribution of the header.
+    pub fn write_attribution(&mut self, attribution: &Attribution) -> serde_json::Result<()> {
+        if self.has_attribution {
+            return Err(invalid_input("the attribution is already written"));
+        }
+        self.has_attribution = true;
+        if self.open.is_some() {
+            self.attribution = Some(serde_json::to_vec(attribution)?);
+            return Ok(());
+        }
+        self.write_key("attribution")?;
+        serde_json::to_writer(&mut self.writer, attribution)
+    }
+
+    /// Fails, elements that are not part of GEDCOM X can only be written as
+    /// XML.
+    pub fn write_extension(&mut self, _xml: &str) -> serde_json::Result<()> {
+        Err(invalid_input("XML extensions can not be written as JSON"))
+    }
+
+    write_item_methods!(serde_json::Error);
+
+    fn write_item<T: Serialize>(
+        &mut self,
+        collection: Collection,
+        item: &T,
+    ) -> serde_json::Result<()> {
+        if self.ended.contains(&collection) {
+            return Err(invalid_input(format!(
+                "the {} are already written",
+                collection.key()
+            )));
+        }
+        if self.open.is_none() {
+            self.open_collection(collection)?;
+        }
+        match &mut self.open {
+            Some((open, has_items)) if *open == collection => {
+                if *has_items {
+                    self.writer.write_all(b",").map_err(serde_json::Error::io)?;
+                }
+                *has_items = true;
+                serde_json::to_writer(&mut self.writer, item)
+            }
+            _ => {
+                let buffer = self.pending_mut(collection);
+                if !buffer.is_empty() {
+                    buffer.push(b',');
+                }
+                serde_json::to_writer(buffer, item)
+            }
+        }
+    }
+
+    fn open_collection(&mut self, collection: Collection) -> serde_json::Result<()> {
+        self.write_key(collection.key())?;
+        self.write_all(b"[")?;
+        let pending = self.take_pending(collection);
+        self.write_all(&pending)?;
+        self.open = Some((collection, !pending.is_empty()));
+        Ok(())
+    }
+
+    /// Ends the array being written, no more items of its collection can be
+    /// written. The next item opens the array of its collection, with the
+    /// items of that collection that were kept in memory.
+    pub fn end_collection(&mut self) -> serde_json::Result<()> {
+        if let Some((collection, _)) = self.open.take() {
+            self.write_all(b"]")?;
+            self.ended.push(collection);
+        }
+        if let Some(attribution) = self.attribution.take() {
+            self.write_key("attribution")?;
+            self.write_all(&attribution)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the items kept in memory and the end of the root object.
+    pub fn finish(mut self) -> serde_json::Result<W> {
+        self.end_collection()?;
+        for (collection, items) in std::mem::take(&mut self.pending) {
+            self.write_key(collection.key())?;
+            self.write_all(b"[")?;
+            self.write_all(&items)?;
+            self.write_all(b"]")?;
+        }
+        self.write_all(b"}")?;
+        Ok(self.writer)
+    }
+
+    fn pending_mut(&mut self, collection: Collection) -> &mut Vec<u8> {
+        let index = match self.pending.iter().position(|(c, _)| *c == collection) {
+            Some(index) => index,
+            None => {
+                self.pending.push((collection, Vec::new()));
+                self.pending.len() - 1
+            }
+        };
+        &mut self.pending[index].1
+    }
+
+    fn take_pending(&mut self, collection: Collection) -> Vec<u8> {
+        match self.pending.iter().position(|(c, _)| *c == collection) {
+            Some(index) => self.pending.remove(index).1,
+            None => Vec::new(),
+        }
+    }
+
+    fn write_key(&mut self, key: &str) -> serde_json::Result<()> {
+        if self.has_fields {
+            self.write_all(b",")?;
+        }
+        self.has_fields = true;
+        serde_json::to_writer(&mut self.writer, key)?;
+        self.write_all(b":")
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> serde_json::Result<()> {
+        self.writer.write_all(bytes).map_err(serde_json::Error::io)
+    }
+}
+
+fn invalid_input<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> serde_json::Error {
+    serde_json::Error::io(io::Error::new(io::ErrorKind::InvalidInput, error))
+}
@@ -1,8 +1,62 @@
-mod error;
+mod json_writer;
 mod serialize_xml;
 pub mod xml;
+mod xml_writer;
 
-pub use crate::ser::error::SerError;
-pub use crate::ser::serialize_xml::{serialize_to_xml, SerializeXml};
+pub use crate::ser::json_writer::JsonRecordWriter;
+pub use crate::ser::serialize_xml::serialize_to_xml;
+pub use crate::ser::xml_writer::XmlRecordWriter;
+pub use deserx::ser::{SerError, SerializeXml, XmlSerializer};
 
-pub use quick_xml::Writer as XmlSerializer;
+/// The `write_person`, `write_relationship`, ... methods of a record writer,
+/// which must have a `write_item(Collection, &T)` method.
+macro_rules! write_item_methods {
+    ($error:ty) => {
+        pub fn write_person(&mut self, person: &Person) -> Result<(), $error> {
+            self.write_item(Collection::Persons, person)
+        }
+        pub fn write_relationship(&mut self, relationship: &Relationship) -> Result<(), $error> {
+            self.write_item(Collection::Relationships, relationship)
+        }
+        pub fn write_source_description(
+            &mut self,
+            description: &SourceDescription,
+        ) -> Result<(), $error> {
+            self.write_item(Collection::SourceDescriptions, description)
+        }
+        pub fn write_agent(&mut self, agent: &Agent) -> Result<(), $error> {
+            self.write_item(Collection::Agents, agent)
+        }
+        pub fn write_event(&mut self, event: &Event) -> Result<(), $error> {
+            self.write_item(Collection::Events, event)
+        }
+        pub fn write_place(&mut self, place: &PlaceDescription) -> Result<(), $error> {
+            self.write_item(Collection::Places, place)
+        }
+        pub fn write_document(&mut self, document: &Document) -> Result<(), $error> {
+            self.write_item(Collection::Documents, document)
+        }
+        pub fn write_group(&mut self, group: &Group) -> Result<(), $error> {
+            self.write_item(Collection::Groups, group)
+        }
+
+        /// Writes `record` with the method for its type.
+        pub fn write_record(&mut self, record: &Record) -> Result<(), $error> {
+            match record {
+                Record::Attribution(attribution) => self.write_attribution(attribution),
+                Record::Person(person) => self.write_person(person),
+                Record::Relationship(relationship) => self.write_relationship(relationship),
+                Record::SourceDescription(description) => {
+                    self.write_source_description(description)
+                }
+                Record::Agent(agent) => self.write_agent(agent),
+                Record::Event(event) => self.write_event(event),
+                Record::Place(place) => self.write_place(place),
+                Record::Document(document) => self.write_document(document),
+                Record::Group(group) => self.write_group(group),
+                Record::Extension(xml) => self.write_extension(xml),
+            }
+        }
+    };
+}
+use write_item_methods;
@@ -32,8 +32,7 @@ pub fn write_elem_w_text_if_not_empty<W: io::Write>(
     tag: &str,
     text: &str,
 ) -> Result<()> {
-    if text.len() > 0 {
-        println!("writing ...");
+    if !text.is_empty() {
         write_elem_w_text(ser, tag, text)?;
     }
     Ok(())
@@ -0,0 +1,133 @@
+use std::io;
+
+use deserx::ser::SerializeXmlFields;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event as XmlEvent};
+
+use crate::agent::Agent;
+use crate::conclusion::{Document, Event, Group, Person, PlaceDescription, Relationship};
+use crate::gedcomx::{Attribution, Collection, GedcomX, Record};
+use crate::ser::{write_item_methods, SerError, SerializeXml, XmlSerializer};
+use crate::source::SourceDescription;
+
+const ROOT: &str = "gedcomx";
+
+/// Writes a GEDCOM X XML document one top-level item at a time.
+///
+/// Items are written in the order they are given, nothing is kept in memory.
+///
+/// ```
+/// use gedcomx_model::conclusion::Person;
+/// use gedcomx_model::ser::XmlRecordWriter;
+/// use gedcomx_model::GedcomX;
+///
+/// let writer = quick_xml::Writer::new(Vec::new());
+/// let mut records = XmlRecordWriter::new(writer, &GedcomX::new())?;
+/// for id in ["P-1", "P-2"] {
+///     records.write_person(&Person::new(id.parse().unwrap()))?;
+/// }
+/// let xml = records.finish()?.into_inner();
+/// assert!(String::from_utf8(xml).unwrap().ends_with(
+///     r#"<person id="P-1"/><person id="P-2"/></gedcomx>"#
+/// ));
+/// # Ok::<(), gedcomx_model::ser::SerError>(())
+/// ```
+pub struct XmlRecordWriter<W: io::Write> {
+    writer: XmlSerializer<W>,
+    has_attribution: bool,
+    /// Whether items or extension elements are written, after which the
+    /// attribution can no longer be written.
+    has_items: bool,
+}
+
+impl<W: io::Write> XmlRecordWriter<W> {
+    /// Writes the XML declaration and the start tag of the root element, with
+    /// the attributes, attribution and extension elements of `header`.
+    ///
+    /// The items of `header` are not written.
+    pub fn new(writer: XmlSerializer<W>, header: &GedcomX) -> Result<Self, SerError> {
+        Self::start(writer, header, true)
+    }
+
+    /// Like [`new`](Self::new), without the XML declaration, e.g. when it is
+    /// already written.
+    pub fn new_without_declaration(
+        writer: XmlSerializer<W>,
+        header: &GedcomX,
+    ) -> Result<Self, SerError> {
+        Self::start(writer, header, false)
+    }
+
+    fn start(
+        mut writer: XmlSerializer<W>,
+        header: &GedcomX,
+        declaration: bool,
+    ) -> Result<Self, SerError> {
+        if declaration {
+            writer.write_event(XmlEvent::Decl(BytesDecl::new(
+                "1.0",
+                Some("UTF-8"),
+                Some("yes"),
+            )))?;
+        }
+        let mut start = BytesStart::new(ROOT);
+        header.serialize_attributes(&mut start);
+        writer.write_event(XmlEvent::Start(start))?;
+        let mut records = Self {
+            writer,
+            has_attribution: false,
+            has_items: false,
+        };
+        if let Some(attribution) = header.get_attribution() {
+            records.write_attribution(attribution)?;
+        }
+        let extensions = header.extensions().xml();
+        extensions.serialize_elements(&mut records.writer)?;
+        records.has_items = !extensions.elements().is_empty();
+        Ok(records)
+    }
+
+    /// Writes the attribution of the document, which can only be given once,
+    /// also counting the attribution of the header, and before any items.
+    pub fn write_attribution(&mut self, attribution: &Attribution) -> Result<(), SerError> {
+        if self.has_attribution {
+            return Err(invalid_input("the attribution is already written"));
+        }
+        if self.has_items {
+            return Err(invalid_input(
+                "the attribution must be written before the items",
+            ));
+        }
+        self.has_attribution = true;
+        attribution.serialize_xml_with_tag("attribution", &mut self.writer)
+    }
+
+    /// Writes an element that is not part of GEDCOM X, given as an XML fragment.
+    pub fn write_extension(&mut self, xml: &str) -> Result<(), SerError> {
+        self.has_items = true;
+        self.writer
+            .write_event(XmlEvent::Text(BytesText::from_escaped(xml)))?;
+        Ok(())
+    }
+
+    write_item_methods!(SerError);
+
+    fn write_item<T: SerializeXml>(
+        &mut self,
+        collection: Collection,
+        item: &T,
+    ) -> Result<(), SerError> {
+        self.has_items = true;
+        item.serialize_xml_with_tag(collection.tag(), &mut self.writer)
+    }
+
+    /// Writes the end tag of the root element.
+    pub fn finish(mut self) -> Result<XmlSerializer<W>, SerError> {
+        self.writer
+            .write_event(XmlEvent::End(BytesEnd::new(ROOT)))?;
+        Ok(self.writer)
+    }
+}
+
+fn invalid_input(error: &str) -> SerError {
+    SerError::IoError(io::Error::new(io::ErrorKind::InvalidInput, error))
+}
@@ -0,0 +1,65 @@
+use deserx::{DeserializeXml, SerializeXml};
+
+use crate::common::{impl_extensions, Extensions};
+
+use crate::conclusion::{Date, PlaceReference};
+
+/// The place and time covered by a source, e.g. a parish register for
+/// Ecclesall-Bierlow from 1837 to 1850.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    DeserializeXml,
+    SerializeXml,
+)]
+#[serde(remote = "Self")]
+#[xml(order(spatial, temporal))]
+pub struct Coverage {
+    spatial: Option<PlaceReference>,
+    temporal: Option<Date>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
+}
+
+impl_extensions!(Coverage);
+
+impl Coverage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+// Builder lite
+impl Coverage {
+    pub fn spatial(mut self, spatial: PlaceReference) -> Self {
+        self.set_spatial(Some(spatial));
+        self
+    }
+
+    pub fn temporal(mut self, temporal: Date) -> Self {
+        self.set_temporal(Some(temporal));
+        self
+    }
+}
+
+impl Coverage {
+    pub fn set_spatial(&mut self, spatial: Option<PlaceReference>) {
+        self.spatial = spatial;
+    }
+    pub fn set_temporal(&mut self, temporal: Option<Date>) {
+        self.temporal = temporal;
+    }
+
+    pub fn get_spatial(&self) -> Option<&PlaceReference> {
+        self.spatial.as_ref()
+    }
+    pub fn get_temporal(&self) -> Option<&Date> {
+        self.temporal.as_ref()
+    }
+}
@@ -1,7 +1,11 @@
+mod coverage;
+mod rectangle_region;
 mod source_citation;
 mod source_description;
 mod source_reference;
 
+pub use coverage::Coverage;
+pub use rectangle_region::{RectangleRegion, RegionParseError};
 pub use source_citation::SourceCitation;
 pub use source_description::SourceDescription;
 pub use source_reference::SourceReference;
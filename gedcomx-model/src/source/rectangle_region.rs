@@ -0,0 +1,113 @@
+use std::{error::Error as StdError, fmt, str::FromStr};
+
+/// A rectangular region of a digital image, the value of a
+/// [`RectangleRegion`](crate::types::SourceReferenceQualifierType::RectangleRegion)
+/// qualifier.
+///
+/// Written as `x1,y1,x2,y2`, the upper left and the lower right corner. If no
+/// value is greater than 1 the corners are fractions of the width and height
+/// of the image, otherwise they are pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RectangleRegion {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+}
+
+impl RectangleRegion {
+    pub fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    pub fn x1(&self) -> f64 {
+        self.x1
+    }
+    pub fn y1(&self) -> f64 {
+        self.y1
+    }
+    pub fn x2(&self) -> f64 {
+        self.x2
+    }
+    pub fn y2(&self) -> f64 {
+        self.y2
+    }
+
+    /// Whether the corners are fractions of the image size rather than pixels.
+    pub fn is_relative(&self) -> bool {
+        [self.x1, self.y1, self.x2, self.y2]
+            .iter()
+            .all(|value| *value <= 1.0)
+    }
+}
+
+impl FromStr for RectangleRegion {
+    type Err = RegionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || RegionParseError(s.into());
+        let values = s
+            .split(',')
+            .map(|value| value.trim().parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| error())?;
+        match values.as_slice() {
+            [x1, y1, x2, y2]
+                if values
+                    .iter()
+                    .all(|value| value.is_finite() && *value >= 0.0) =>
+            {
+                Ok(Self::new(*x1, *y1, *x2, *y2))
+            }
+            _ => Err(error()),
+        }
+    }
+}
+
+impl fmt::Display for RectangleRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{},{}", self.x1, self.y1, self.x2, self.y2)
+    }
+}
+
+/// A value that is not four non-negative numbers separated by commas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionParseError(String);
+
+impl fmt::Display for RegionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid rectangle region '{}'", self.0)
+    }
+}
+
+impl StdError for RegionParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_writes_corners() {
+        let region: RectangleRegion = "0.1, 0.25,0.5,0.75".parse().unwrap();
+        assert_eq!(region, RectangleRegion::new(0.1, 0.25, 0.5, 0.75));
+        assert!(region.is_relative());
+        assert_eq!(region.to_string(), "0.1,0.25,0.5,0.75");
+        assert!(!"10,20,640,480"
+            .parse::<RectangleRegion>()
+            .unwrap()
+            .is_relative());
+    }
+
+    #[test]
+    fn rejects_malformed_regions() {
+        for invalid in [
+            "",
+            "0.1,0.2,0.3",
+            "0.1,0.2,0.3,0.4,0.5",
+            "a,b,c,d",
+            "-1,0,1,1",
+        ] {
+            assert!(invalid.parse::<RectangleRegion>().is_err(), "{}", invalid);
+        }
+    }
+}
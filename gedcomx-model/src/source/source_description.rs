@@ -1,16 +1,18 @@
 use chrono::Utc;
-use deserx::DeserializeXml;
-use quick_xml::events::{BytesEnd, BytesStart, Event};
+use deserx::{DeserializeXml, SerializeXml};
 use serde::{Deserialize, Deserializer, Serializer};
 
-use super::{SourceCitation, SourceReference};
+use super::{Coverage, SourceCitation, SourceReference};
 use crate::{
-    common::{DateTime, IriRef, ResourceReference, TextValue, Uri},
-    ser::{xml, SerError, SerializeXml},
+    common::{
+        identifier_map, impl_extensions, DateTime, Extensions, Identifier, IriRef, Note,
+        ResourceReference, TextValue, Uri,
+    },
+    conclusion::DocumentReference,
+    gedcomx::Attribution,
     types::ResourceType,
 };
 
-
 pub fn ser_opt_date<S: Serializer>(v: &Option<DateTime>, s: S) -> Result<S::Ok, S::Error> {
     match v {
         // Some(d) => s.serialize_i64(DateTime::from_utc(d.and_hms(0, 0, 0), Utc).timestamp()),
@@ -47,44 +49,169 @@ impl<'de> serde::de::Visitor<'de> for OptionalDateTimeVisitor {
         D: Deserializer<'de>,
     {
         use chrono::TimeZone;
-        Ok(Some(Utc.timestamp_millis(i64::deserialize(deserializer)?)))
+        use serde::de::Error;
+        let millis = i64::deserialize(deserializer)?;
+        match Utc.timestamp_millis_opt(millis).single() {
+            Some(datetime) => Ok(Some(datetime)),
+            None => Err(D::Error::custom(format!(
+                "timestamp out of range: {}",
+                millis
+            ))),
+        }
     }
 }
 
-// #[serdxce_as]
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+/// A description of a source, e.g. a scanned image, a parish register or a
+/// published book.
+#[serde_with::skip_serializing_none]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+#[xml(order(
+    citations,
+    mediator,
+    publisher,
+    authors,
+    sources,
+    analysis,
+    component_of,
+    names,
+    titles,
+    notes,
+    attribution,
+    rights,
+    coverage,
+    descriptions,
+    identifiers,
+    created,
+    modified,
+    published,
+    repository,
+    descriptor_ref
+))]
 pub struct SourceDescription {
-    resource_type: Option<Uri>,
+    #[xml(attribute)]
+    resource_type: Option<ResourceType>,
+    #[xml(rename = "citation")]
     citations: Vec<SourceCitation>,
+    /// The hint about the media (MIME) type of the source, e.g. `image/jpeg`.
+    #[xml(attribute)]
+    media_type: Option<String>,
+    /// The URI of the source, e.g. of a scanned image.
+    #[xml(attribute)]
+    about: Option<Uri>,
+    mediator: Option<ResourceReference>,
+    publisher: Option<ResourceReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "author")]
+    authors: Vec<ResourceReference>,
+    /// The sources this source is derived from.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "source")]
+    sources: Vec<SourceReference>,
+    analysis: Option<ResourceReference>,
+    /// The source this source is a part of, e.g. the book of a page.
+    component_of: Option<SourceReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "name")]
     names: Vec<TextValue>,
+    #[xml(rename = "title")]
     titles: Vec<TextValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "note")]
+    notes: Vec<Note>,
+    attribution: Option<Attribution>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rights: Vec<ResourceReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    coverage: Vec<Coverage>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "description")]
+    descriptions: Vec<TextValue>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        with = "identifier_map"
+    )]
+    #[xml(rename = "identifier")]
+    identifiers: Vec<Identifier>,
     #[serde(
+        default,
         serialize_with = "ser_opt_date",
         deserialize_with = "deserialize_optional_datetime"
     )]
     // #[serde_as(as = "TimestampMilliSeconds<i64>")]
+    #[xml(text_element)]
     created: Option<DateTime>,
+    #[serde(
+        default,
+        serialize_with = "ser_opt_date",
+        deserialize_with = "deserialize_optional_datetime"
+    )]
+    #[xml(text_element)]
+    modified: Option<DateTime>,
+    #[serde(
+        default,
+        serialize_with = "ser_opt_date",
+        deserialize_with = "deserialize_optional_datetime"
+    )]
+    #[xml(text_element)]
+    published: Option<DateTime>,
     repository: Option<ResourceReference>,
+    /// A reference to a description of the source in another format.
+    #[serde(rename = "descriptor")]
+    #[xml(rename = "descriptor")]
+    descriptor_ref: Option<ResourceReference>,
+    #[xml(attribute)]
+    sort_key: Option<String>,
+    #[xml(attribute, rename = "xml:lang")]
+    lang: Option<String>,
     // #[serde(default)]
+    #[xml(attribute)]
     id: IriRef,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(SourceDescription);
+
 impl SourceDescription {
     pub fn new(id: IriRef) -> Self {
         Self {
             id,
+            resource_type: None,
+            citations: Vec::new(),
+            media_type: None,
+            about: None,
+            mediator: None,
+            publisher: None,
+            authors: Vec::new(),
+            sources: Vec::new(),
+            analysis: None,
+            component_of: None,
             names: Vec::new(),
             titles: Vec::new(),
-            citations: Vec::new(),
-            repository: None,
+            notes: Vec::new(),
+            attribution: None,
+            rights: Vec::new(),
+            coverage: Vec::new(),
+            descriptions: Vec::new(),
+            identifiers: Vec::new(),
             created: None,
-            resource_type: None,
+            modified: None,
+            published: None,
+            repository: None,
+            descriptor_ref: None,
+            sort_key: None,
+            lang: None,
+            extensions: Extensions::default(),
         }
     }
 }
 
+// Builder lite
 impl SourceDescription {
     // pub fn id<S: Into<String>>(mut self, id: S) -> Self {
     //     self.set_id(id.into());
@@ -101,8 +228,18 @@ impl SourceDescription {
         self
     }
 
+    pub fn modified<S: Into<DateTime>>(mut self, modified: S) -> Self {
+        self.set_modified(Some(modified.into()));
+        self
+    }
+
+    pub fn published<S: Into<DateTime>>(mut self, published: S) -> Self {
+        self.set_published(Some(published.into()));
+        self
+    }
+
     pub fn resource_type(mut self, resource_type: ResourceType) -> Self {
-        self.set_resource_type(resource_type.into());
+        self.set_resource_type(resource_type);
         self
     }
 
@@ -111,6 +248,46 @@ impl SourceDescription {
         self
     }
 
+    pub fn media_type<S: Into<String>>(mut self, media_type: S) -> Self {
+        self.set_media_type(Some(media_type.into()));
+        self
+    }
+
+    pub fn about(mut self, about: Uri) -> Self {
+        self.set_about(Some(about));
+        self
+    }
+
+    pub fn mediator<R: Into<ResourceReference>>(mut self, mediator: R) -> Self {
+        self.set_mediator(Some(mediator.into()));
+        self
+    }
+
+    pub fn publisher<R: Into<ResourceReference>>(mut self, publisher: R) -> Self {
+        self.set_publisher(Some(publisher.into()));
+        self
+    }
+
+    pub fn author<R: Into<ResourceReference>>(mut self, author: R) -> Self {
+        self.add_author(author.into());
+        self
+    }
+
+    pub fn source<S: Into<SourceReference>>(mut self, source: S) -> Self {
+        self.add_source(source.into());
+        self
+    }
+
+    pub fn analysis<S: Into<DocumentReference>>(mut self, analysis: S) -> Self {
+        self.set_analysis(Some(analysis.into().into()));
+        self
+    }
+
+    pub fn component_of<S: Into<SourceReference>>(mut self, component_of: S) -> Self {
+        self.set_component_of(Some(component_of.into()));
+        self
+    }
+
     pub fn name<T: Into<TextValue>>(mut self, name: T) -> Self {
         self.add_name(name.into());
         self
@@ -120,6 +297,51 @@ impl SourceDescription {
         self.add_title(title.into());
         self
     }
+
+    pub fn note<N: Into<Note>>(mut self, note: N) -> Self {
+        self.add_note(note.into());
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn rights(mut self, rights: IriRef) -> Self {
+        self.add_rights(ResourceReference::new(rights));
+        self
+    }
+
+    pub fn coverage(mut self, coverage: Coverage) -> Self {
+        self.add_coverage(coverage);
+        self
+    }
+
+    pub fn description<T: Into<TextValue>>(mut self, description: T) -> Self {
+        self.add_description(description.into());
+        self
+    }
+
+    pub fn identifier(mut self, identifier: Identifier) -> Self {
+        self.add_identifier(identifier);
+        self
+    }
+
+    pub fn descriptor_ref<R: Into<ResourceReference>>(mut self, descriptor_ref: R) -> Self {
+        self.set_descriptor_ref(Some(descriptor_ref.into()));
+        self
+    }
+
+    pub fn sort_key<S: Into<String>>(mut self, sort_key: S) -> Self {
+        self.set_sort_key(Some(sort_key.into()));
+        self
+    }
+
+    pub fn lang<S: Into<String>>(mut self, lang: S) -> Self {
+        self.set_lang(Some(lang.into()));
+        self
+    }
 }
 impl SourceDescription {
     // pub fn set_id(&mut self, id: String) {
@@ -131,11 +353,44 @@ impl SourceDescription {
     pub fn set_created(&mut self, created: DateTime) {
         self.created = Some(created);
     }
-    pub fn set_resource_type(&mut self, resource_type: Uri) {
+    pub fn set_modified(&mut self, modified: Option<DateTime>) {
+        self.modified = modified;
+    }
+    pub fn set_published(&mut self, published: Option<DateTime>) {
+        self.published = published;
+    }
+    pub fn set_resource_type(&mut self, resource_type: ResourceType) {
         self.resource_type = Some(resource_type);
     }
-    pub fn get_id(&self) -> &str {
-        self.id.as_str()
+    pub fn set_media_type(&mut self, media_type: Option<String>) {
+        self.media_type = media_type;
+    }
+    pub fn set_about(&mut self, about: Option<Uri>) {
+        self.about = about;
+    }
+    pub fn set_mediator(&mut self, mediator: Option<ResourceReference>) {
+        self.mediator = mediator;
+    }
+    pub fn set_publisher(&mut self, publisher: Option<ResourceReference>) {
+        self.publisher = publisher;
+    }
+    pub fn set_analysis(&mut self, analysis: Option<ResourceReference>) {
+        self.analysis = analysis;
+    }
+    pub fn set_component_of(&mut self, component_of: Option<SourceReference>) {
+        self.component_of = component_of;
+    }
+    pub fn set_attribution(&mut self, attribution: Option<Attribution>) {
+        self.attribution = attribution;
+    }
+    pub fn set_descriptor_ref(&mut self, descriptor_ref: Option<ResourceReference>) {
+        self.descriptor_ref = descriptor_ref;
+    }
+    pub fn set_sort_key(&mut self, sort_key: Option<String>) {
+        self.sort_key = sort_key;
+    }
+    pub fn set_lang(&mut self, lang: Option<String>) {
+        self.lang = lang;
     }
     pub fn add_title(&mut self, title: TextValue) {
         self.titles.push(title);
@@ -146,6 +401,106 @@ impl SourceDescription {
     pub fn add_name(&mut self, name: TextValue) {
         self.names.push(name);
     }
+    pub fn add_author(&mut self, author: ResourceReference) {
+        self.authors.push(author);
+    }
+    pub fn add_source(&mut self, source: SourceReference) {
+        self.sources.push(source);
+    }
+    pub fn add_note(&mut self, note: Note) {
+        self.notes.push(note);
+    }
+    pub fn add_rights(&mut self, rights: ResourceReference) {
+        self.rights.push(rights);
+    }
+    pub fn add_coverage(&mut self, coverage: Coverage) {
+        self.coverage.push(coverage);
+    }
+    pub fn add_description(&mut self, description: TextValue) {
+        self.descriptions.push(description);
+    }
+    pub fn add_identifier(&mut self, identifier: Identifier) {
+        self.identifiers.push(identifier);
+    }
+
+    pub fn get_id(&self) -> &str {
+        self.id.as_str()
+    }
+    pub fn get_resource_type(&self) -> Option<&ResourceType> {
+        self.resource_type.as_ref()
+    }
+    pub fn citations(&self) -> &[SourceCitation] {
+        self.citations.as_slice()
+    }
+    pub fn get_media_type(&self) -> Option<&str> {
+        self.media_type.as_deref()
+    }
+    pub fn get_about(&self) -> Option<&Uri> {
+        self.about.as_ref()
+    }
+    pub fn get_mediator(&self) -> Option<&ResourceReference> {
+        self.mediator.as_ref()
+    }
+    pub fn get_publisher(&self) -> Option<&ResourceReference> {
+        self.publisher.as_ref()
+    }
+    pub fn authors(&self) -> &[ResourceReference] {
+        self.authors.as_slice()
+    }
+    pub fn sources(&self) -> &[SourceReference] {
+        self.sources.as_slice()
+    }
+    pub fn get_analysis(&self) -> Option<&ResourceReference> {
+        self.analysis.as_ref()
+    }
+    pub fn get_component_of(&self) -> Option<&SourceReference> {
+        self.component_of.as_ref()
+    }
+    pub fn names(&self) -> &[TextValue] {
+        self.names.as_slice()
+    }
+    pub fn titles(&self) -> &[TextValue] {
+        self.titles.as_slice()
+    }
+    pub fn notes(&self) -> &[Note] {
+        self.notes.as_slice()
+    }
+    pub fn get_attribution(&self) -> Option<&Attribution> {
+        self.attribution.as_ref()
+    }
+    pub fn get_rights(&self) -> &[ResourceReference] {
+        self.rights.as_slice()
+    }
+    pub fn get_coverage(&self) -> &[Coverage] {
+        self.coverage.as_slice()
+    }
+    pub fn descriptions(&self) -> &[TextValue] {
+        self.descriptions.as_slice()
+    }
+    pub fn identifiers(&self) -> &[Identifier] {
+        self.identifiers.as_slice()
+    }
+    pub fn get_created(&self) -> Option<&DateTime> {
+        self.created.as_ref()
+    }
+    pub fn get_modified(&self) -> Option<&DateTime> {
+        self.modified.as_ref()
+    }
+    pub fn get_published(&self) -> Option<&DateTime> {
+        self.published.as_ref()
+    }
+    pub fn get_repository(&self) -> Option<&ResourceReference> {
+        self.repository.as_ref()
+    }
+    pub fn get_descriptor_ref(&self) -> Option<&ResourceReference> {
+        self.descriptor_ref.as_ref()
+    }
+    pub fn get_sort_key(&self) -> Option<&str> {
+        self.sort_key.as_deref()
+    }
+    pub fn get_lang(&self) -> Option<&str> {
+        self.lang.as_deref()
+    }
 }
 
 impl From<&SourceDescription> for ResourceReference {
@@ -156,156 +511,6 @@ impl From<&SourceDescription> for ResourceReference {
 
 impl From<&SourceDescription> for SourceReference {
     fn from(source: &SourceDescription) -> Self {
-        Self::new(source.id.clone(), source.id.clone().into_inner())
-    }
-}
-impl SerializeXml for SourceDescription {
-    fn tag(&self) -> &str {
-        "sourceDescription"
-    }
-
-    fn serialize_xml<W: std::io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> Result<(), SerError> {
-        let mut elem = BytesStart::new(self.tag());
-        if let Some(resource_type) = &self.resource_type {
-            elem.push_attribute(("resourceType", resource_type.as_str()));
-        }
-        elem.push_attribute(("id", self.id.as_str()));
-        ser.write_event(Event::Start(elem))?;
-
-        for citation in &self.citations {
-            citation.serialize_xml(ser)?;
-        }
-        for title in &self.titles {
-            xml::write_elem_w_text(ser, "title", title.value())?;
-        }
-        if let Some(created) = &self.created {
-            xml::write_elem_w_text(ser, "created", &created.to_rfc3339())?;
-        }
-        if let Some(repository) = &self.repository {
-            xml::write_elem_w_attribute(ser, "repository", ("resource", &repository.resource()))?;
-        }
-        ser.write_event(Event::End(BytesEnd::new(self.tag())))?;
-        Ok(())
-    }
-}
-
-impl DeserializeXml for SourceDescription {
-    fn deserialize_xml_with_start<'de, R: std::io::BufRead>(
-        deserializer: &mut quick_xml::Reader<R>,
-        start: &quick_xml::events::BytesStart<'de>,
-    ) -> Result<Self, quick_xml::Error> {
-        let mut buf = Vec::new();
-        let attr = start.try_get_attribute("id")?;
-        let id: String = if let Some(id) = attr {
-            id.unescape_value()?.into()
-            // source_description.set_contributor(ResourceReference::with_resource(
-            //     resource.unescape_value()?.into(),
-            // ));
-        } else {
-            todo!("handle no 'id'")
-        };
-        let mut source_description = Self::new(IriRef::parse(id).expect("parsing iri"));
-        let attr = start.try_get_attribute("resourceType")?;
-        let resource_type = if let Some(resource_type) = attr {
-            Uri::new(resource_type.unescape_value()?.into())
-        } else {
-            todo!()
-        };
-        source_description.set_resource_type(resource_type);
-        loop {
-            match deserializer.read_event_into(&mut buf)? {
-                Event::Empty(e) => {
-                    log::debug!("read Empty={:?}", e);
-                    match e.name().as_ref() {
-                        b"analysis" => {
-                            let attr = e.try_get_attribute("resource")?;
-                            if let Some(_value) = attr {
-                                // source_description.set_analysis(DocumentReference::new(
-                                //     value.unescape_value()?.into(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"repository" => {
-                            let attr = e.try_get_attribute("resource")?;
-                            if let Some(value) = attr {
-                                source_description.set_repository(ResourceReference::new(
-                                    IriRef::parse(value.unescape_value()?.into())
-                                        .expect("parsing iri"),
-                                ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"gender" => {
-                            let attr = e.try_get_attribute("type")?;
-                            if let Some(_value) = attr {
-                                // source_description.set_gender(Gender::from_qname_uri(
-                                //     value.unescape_value()?.as_ref(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        b"source" => {
-                            let attr = e.try_get_attribute("description")?;
-                            if let Some(_source) = attr {
-                                // source_description.add_source(SourceReference::new(
-                                //     Uri::new(source.unescape_value()?.to_string()),
-                                //     String::new(),
-                                // ));
-                            } else {
-                                todo!("handle error")
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::Start(e) => {
-                    log::debug!("read Start={:?}", e);
-                    match e.name().as_ref() {
-                        b"citation" => {
-                            log::trace!("found 'fact'");
-                            let citation =
-                                SourceCitation::deserialize_xml_with_start(deserializer, &e)?;
-                            source_description.add_citation(citation);
-                        }
-                        b"created" => {
-                            log::trace!("found 'created'");
-                            if let Event::Text(e_created) =
-                                deserializer.read_event_into(&mut buf)?
-                            {
-                                source_description.set_created(
-                                    e_created.unescape()?.as_ref().parse().expect("datetime"),
-                                );
-                            }
-                        }
-                        b"title" => {
-                            log::trace!("found 'title'");
-                            if let Event::Text(e_title) = deserializer.read_event_into(&mut buf)? {
-                                source_description.add_title(e_title.unescape()?.into());
-                            }
-                        }
-                        _tag => todo!("handle {:?}", e),
-                    }
-                }
-                Event::End(e) => match e.name().as_ref() {
-                    b"sourceDescription" => {
-                        log::trace!("found end of 'source_description' returning ...");
-                        break;
-                    }
-                    _tag => log::trace!("skipping '{:?}' ...", e),
-                },
-                e => {
-                    log::trace!("got: {:?} skipping ...", e);
-                }
-            }
-        }
-        log::debug!("source_description = {:?}", source_description);
-        Ok(source_description)
+        Self::new(source.id.clone())
     }
 }
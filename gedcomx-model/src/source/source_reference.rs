@@ -1,40 +1,117 @@
+use deserx::{DeserializeXml, SerializeXml};
+
 use crate::{
-    common::IriRef,
-    ser::{xml, SerializeXml},
+    common::{impl_extensions, Extensions, IriRef, Qualifier},
+    gedcomx::Attribution,
+    types::SourceReferenceQualifierType,
 };
 
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+use super::RectangleRegion;
+
+/// A reference to a source description, e.g. the source of a conclusion.
+///
+/// Qualifiers point at a part of the source, e.g. a region of a scanned page,
+/// see [`SourceReferenceQualifierType`].
+#[serde_with::skip_serializing_none]
+#[derive(
+    Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, DeserializeXml, SerializeXml,
+)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+#[xml(rename = "source", order(attribution, qualifiers))]
 pub struct SourceReference {
+    #[xml(attribute)]
     description: IriRef,
-    #[serde(default, skip_serializing)]
-    description_id: String,
+    /// The id of the source description, in case `description` can't be
+    /// resolved.
+    #[xml(attribute)]
+    description_id: Option<String>,
+    attribution: Option<Attribution>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[xml(rename = "qualifier")]
+    qualifiers: Vec<Qualifier>,
+    #[serde(flatten, skip_serializing)]
+    #[xml(extensions)]
+    extensions: Extensions,
 }
 
+impl_extensions!(SourceReference);
+
 impl SourceReference {
-    pub fn new(description: IriRef, description_id: String) -> Self {
+    pub fn new(description: IriRef) -> Self {
         Self {
             description,
-            description_id,
+            description_id: None,
+            attribution: None,
+            qualifiers: Vec::new(),
+            extensions: Extensions::default(),
         }
     }
 }
 
-impl SerializeXml for SourceReference {
-    fn tag(&self) -> &str {
-        "source"
+// Builder lite
+impl SourceReference {
+    pub fn description_id<S: Into<String>>(mut self, description_id: S) -> Self {
+        self.set_description_id(Some(description_id.into()));
+        self
+    }
+
+    pub fn attribution(mut self, attribution: Attribution) -> Self {
+        self.set_attribution(Some(attribution));
+        self
+    }
+
+    pub fn qualifier<Q: Into<Qualifier>>(mut self, qualifier: Q) -> Self {
+        self.add_qualifier(qualifier.into());
+        self
     }
 
-    fn serialize_xml<W: std::io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> Result<(), crate::ser::SerError> {
-        xml::write_elem_w_attribute(ser, self.tag(), ("description", self.description.as_str()))?;
-        Ok(())
+    pub fn rectangle_region(mut self, region: RectangleRegion) -> Self {
+        self.add_qualifier(
+            Qualifier::from(SourceReferenceQualifierType::RectangleRegion)
+                .value(region.to_string()),
+        );
+        self
     }
 }
 
-impl PartialEq for SourceReference {
-    fn eq(&self, other: &Self) -> bool {
-        self.description == other.description
+impl SourceReference {
+    pub fn set_description_id(&mut self, description_id: Option<String>) {
+        self.description_id = description_id;
+    }
+    pub fn set_attribution(&mut self, attribution: Option<Attribution>) {
+        self.attribution = attribution;
+    }
+    pub fn add_qualifier(&mut self, qualifier: Qualifier) {
+        self.qualifiers.push(qualifier);
+    }
+
+    pub fn description(&self) -> &IriRef {
+        &self.description
+    }
+    pub fn get_description_id(&self) -> Option<&str> {
+        self.description_id.as_deref()
+    }
+    pub fn get_attribution(&self) -> Option<&Attribution> {
+        self.attribution.as_ref()
+    }
+    pub fn qualifiers(&self) -> &[Qualifier] {
+        self.qualifiers.as_slice()
+    }
+
+    /// The value of the first qualifier named `name`.
+    pub fn get_qualifier(&self, name: &SourceReferenceQualifierType) -> Option<&str> {
+        self.qualifiers
+            .iter()
+            .find(|qualifier| qualifier.is(name))
+            .and_then(Qualifier::get_value)
+    }
+
+    /// The rectangle regions of the source, values that are not a rectangle are
+    /// skipped.
+    pub fn rectangle_regions(&self) -> impl Iterator<Item = RectangleRegion> + '_ {
+        self.qualifiers
+            .iter()
+            .filter(|qualifier| qualifier.is(&SourceReferenceQualifierType::RectangleRegion))
+            .filter_map(|qualifier| qualifier.get_value()?.parse().ok())
     }
 }
@@ -0,0 +1,16 @@
+use crate::common::vocabulary;
+
+vocabulary! {
+    /// Enumeration of the levels of confidence in a conclusion.
+    pub enum ConfidenceLevel {
+        /// The contributor has a high degree of confidence that the assertion
+        /// is true.
+        High = "http://gedcomx.org/High",
+        /// The contributor has a medium degree of confidence that the assertion
+        /// is true.
+        Medium = "http://gedcomx.org/Medium",
+        /// The contributor has a low degree of confidence that the assertion is
+        /// true.
+        Low = "http://gedcomx.org/Low",
+    }
+}
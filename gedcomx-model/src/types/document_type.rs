@@ -0,0 +1,16 @@
+use crate::common::vocabulary;
+
+vocabulary! {
+    /// Enumeration of the kinds of documents.
+    pub enum DocumentType {
+        /// The document is an abstract of a record or document.
+        Abstract = "http://gedcomx.org/Abstract",
+        /// The document is a transcription of a record or document.
+        Transcription = "http://gedcomx.org/Transcription",
+        /// The document is a translation of a record or document.
+        Translation = "http://gedcomx.org/Translation",
+        /// The document is an analysis done by a researcher, often used to
+        /// justify a conclusion.
+        Analysis = "http://gedcomx.org/Analysis",
+    }
+}
@@ -0,0 +1,18 @@
+use crate::common::vocabulary;
+
+vocabulary! {
+    /// Enumeration of standard event roles.
+    pub enum EventRoleType {
+        /// The principal person of the event, e.g. the person born in a birth.
+        Principal = "http://gedcomx.org/Principal",
+
+        /// A participant in the event.
+        Participant = "http://gedcomx.org/Participant",
+
+        /// A person officiating the event.
+        Official = "http://gedcomx.org/Official",
+
+        /// A witness of the event.
+        Witness = "http://gedcomx.org/Witness",
+    }
+}
@@ -0,0 +1,108 @@
+use crate::common::vocabulary;
+
+vocabulary! {
+    /// Enumeration of standard event types.
+    pub enum EventType {
+        /// An adoption event.
+        Adoption = "http://gedcomx.org/Adoption",
+
+        /// An adult christening event.
+        AdultChristening = "http://gedcomx.org/AdultChristening",
+
+        /// An annulment event of a marriage.
+        Annulment = "http://gedcomx.org/Annulment",
+
+        /// A baptism event.
+        Baptism = "http://gedcomx.org/Baptism",
+
+        /// A bar mitzvah event.
+        BarMitzvah = "http://gedcomx.org/BarMitzvah",
+
+        /// A bat mitzvah event.
+        BatMitzvah = "http://gedcomx.org/BatMitzvah",
+
+        /// A birth event.
+        Birth = "http://gedcomx.org/Birth",
+
+        /// An official blessing event, e.g. by a clergy member.
+        Blessing = "http://gedcomx.org/Blessing",
+
+        /// A burial event.
+        Burial = "http://gedcomx.org/Burial",
+
+        /// A census event.
+        Census = "http://gedcomx.org/Census",
+
+        /// A christening event at birth, see `AdultChristening` for adults.
+        Christening = "http://gedcomx.org/Christening",
+
+        /// A circumcision event.
+        Circumcision = "http://gedcomx.org/Circumcision",
+
+        /// A confirmation event (or other rite of initiation) in a church or religion.
+        Confirmation = "http://gedcomx.org/Confirmation",
+
+        /// A cremation event after death.
+        Cremation = "http://gedcomx.org/Cremation",
+
+        /// A death event.
+        Death = "http://gedcomx.org/Death",
+
+        /// A divorce event.
+        Divorce = "http://gedcomx.org/Divorce",
+
+        /// A divorce filing event.
+        DivorceFiling = "http://gedcomx.org/DivorceFiling",
+
+        /// An education or educational achievement event, e.g. a graduation.
+        Education = "http://gedcomx.org/Education",
+
+        /// An engagement to be married event.
+        Engagement = "http://gedcomx.org/Engagement",
+
+        /// An emigration event.
+        Emigration = "http://gedcomx.org/Emigration",
+
+        /// An excommunication event from a church.
+        Excommunication = "http://gedcomx.org/Excommunication",
+
+        /// A first communion event.
+        FirstCommunion = "http://gedcomx.org/FirstCommunion",
+
+        /// A funeral event.
+        Funeral = "http://gedcomx.org/Funeral",
+
+        /// An immigration event.
+        Immigration = "http://gedcomx.org/Immigration",
+
+        /// A land transaction event.
+        LandTransaction = "http://gedcomx.org/LandTransaction",
+
+        /// A marriage event.
+        Marriage = "http://gedcomx.org/Marriage",
+
+        /// A military award event.
+        MilitaryAward = "http://gedcomx.org/MilitaryAward",
+
+        /// A military discharge event.
+        MilitaryDischarge = "http://gedcomx.org/MilitaryDischarge",
+
+        /// A mission event.
+        Mission = "http://gedcomx.org/Mission",
+
+        /// An event of a move (i.e. change of residence) from a location.
+        MoveFrom = "http://gedcomx.org/MoveFrom",
+
+        /// An event of a move (i.e. change of residence) to a location.
+        MoveTo = "http://gedcomx.org/MoveTo",
+
+        /// A naturalization event (i.e. acquisition of citizenship and nationality).
+        Naturalization = "http://gedcomx.org/Naturalization",
+
+        /// An ordination event.
+        Ordination = "http://gedcomx.org/Ordination",
+
+        /// A retirement event.
+        Retirement = "http://gedcomx.org/Retirement",
+    }
+}
@@ -0,0 +1,20 @@
+use crate::common::vocabulary;
+
+vocabulary! {
+    /// Enumeration of standard qualifiers of a fact.
+    pub enum FactQualifierType {
+        /// The age of a person at the event described by the fact.
+        Age = "http://gedcomx.org/Age",
+        /// The cause of the fact, such as the cause of death.
+        Cause = "http://gedcomx.org/Cause",
+        /// The religion associated with a religious event such as a baptism or
+        /// excommunication.
+        Religion = "http://gedcomx.org/Religion",
+        /// The name of the transport associated with an event that indicates a
+        /// move, e.g. the name of a ship.
+        Transport = "http://gedcomx.org/Transport",
+        /// An indicator that the event occurred non-consensually, e.g. under
+        /// enslavement. Has no value.
+        NonConsensual = "http://gedcomx.org/NonConsensual",
+    }
+}
@@ -1,83 +1,212 @@
-use core::fmt;
+use crate::common::vocabulary;
 
-use crate::ser::SerializeXml;
-use quick_xml::events::{BytesStart, Event};
+vocabulary! {
+    /// Enumeration of standard fact types.
+    pub enum FactType {
+        // Person facts
+        /// A fact of a person's adoption.
+        Adoption = "http://gedcomx.org/Adoption",
+        /// A fact of a person's christening or baptism as an adult.
+        AdultChristening = "http://gedcomx.org/AdultChristening",
+        /// A fact of a person's amnesty.
+        Amnesty = "http://gedcomx.org/Amnesty",
+        /// A fact of a person's ancestral hall.
+        AncestralHall = "http://gedcomx.org/AncestralHall",
+        /// A fact of a person's ancestral poem.
+        AncestralPoem = "http://gedcomx.org/AncestralPoem",
+        /// A fact of a person's apprenticeship.
+        Apprenticeship = "http://gedcomx.org/Apprenticeship",
+        /// A fact of a person's arrest.
+        Arrest = "http://gedcomx.org/Arrest",
+        /// A fact of a person's award (medal, honor).
+        Award = "http://gedcomx.org/Award",
+        /// A fact of a person's baptism.
+        Baptism = "http://gedcomx.org/Baptism",
+        /// A fact of a person's bar mitzvah.
+        BarMitzvah = "http://gedcomx.org/BarMitzvah",
+        /// A fact of a person's bat mitzvah.
+        BatMitzvah = "http://gedcomx.org/BatMitzvah",
+        /// A fact of a person's birth.
+        Birth = "http://gedcomx.org/Birth",
+        /// A fact of a person's birth notice, such as posted in a newspaper.
+        BirthNotice = "http://gedcomx.org/BirthNotice",
+        /// A fact of a person's blessing, e.g. by a clergy member.
+        Blessing = "http://gedcomx.org/Blessing",
+        /// A fact of a person's branch within an extended clan.
+        Branch = "http://gedcomx.org/Branch",
+        /// A fact of the burial of a person's body after death.
+        Burial = "http://gedcomx.org/Burial",
+        /// A fact of a person's caste.
+        Caste = "http://gedcomx.org/Caste",
+        /// A fact of a person's participation in a census.
+        Census = "http://gedcomx.org/Census",
+        /// A fact of a person's christening *at birth*.
+        Christening = "http://gedcomx.org/Christening",
+        /// A fact of a person's circumcision.
+        Circumcision = "http://gedcomx.org/Circumcision",
+        /// A fact of a person's clan.
+        Clan = "http://gedcomx.org/Clan",
+        /// A fact of a person's confirmation (or other rite of initiation) in a church or religion.
+        Confirmation = "http://gedcomx.org/Confirmation",
+        /// A fact of a person's appearance in a court proceeding.
+        Court = "http://gedcomx.org/Court",
+        /// A fact of the cremation of person's body after death.
+        Cremation = "http://gedcomx.org/Cremation",
+        /// A fact of the death of a person.
+        Death = "http://gedcomx.org/Death",
+        /// A fact of an education or an educational achievement of a person.
+        Education = "http://gedcomx.org/Education",
+        /// A fact of a person's enrollment in an educational program or institution.
+        EducationEnrollment = "http://gedcomx.org/EducationEnrollment",
+        /// A fact of the emigration of a person.
+        Emigration = "http://gedcomx.org/Emigration",
+        /// A fact of a person's enslavement.
+        Enslavement = "http://gedcomx.org/Enslavement",
+        /// A fact of a person's ethnicity or race.
+        Ethnicity = "http://gedcomx.org/Ethnicity",
+        /// A fact of a person's excommunication from a church.
+        Excommunication = "http://gedcomx.org/Excommunication",
+        /// A fact of a person's first communion in a church.
+        FirstCommunion = "http://gedcomx.org/FirstCommunion",
+        /// A fact of a person's funeral.
+        Funeral = "http://gedcomx.org/Funeral",
+        /// A fact of a person's gender change.
+        GenderChange = "http://gedcomx.org/GenderChange",
+        /// A fact of a person's generation number within a family or clan.
+        GenerationNumber = "http://gedcomx.org/GenerationNumber",
+        /// A fact of a person's graduation from a scholastic institution.
+        Graduation = "http://gedcomx.org/Graduation",
+        /// A fact of a person's heimat, the place a person is legally a citizen of.
+        Heimat = "http://gedcomx.org/Heimat",
+        /// A fact of a person's immigration.
+        Immigration = "http://gedcomx.org/Immigration",
+        /// A fact of a person's imprisonment.
+        Imprisonment = "http://gedcomx.org/Imprisonment",
+        /// A fact of a legal inquest, typically after a death.
+        Inquest = "http://gedcomx.org/Inquest",
+        /// A fact of a land transaction enacted by a person.
+        LandTransaction = "http://gedcomx.org/LandTransaction",
+        /// A fact of a language spoken by a person.
+        Language = "http://gedcomx.org/Language",
+        /// A fact of a record of a person's living for a specific period.
+        Living = "http://gedcomx.org/Living",
+        /// A fact of a person's marital status.
+        MaritalStatus = "http://gedcomx.org/MaritalStatus",
+        /// A fact of a person's medical record, such as for an illness or hospital stay.
+        Medical = "http://gedcomx.org/Medical",
+        /// A fact of a person's military award.
+        MilitaryAward = "http://gedcomx.org/MilitaryAward",
+        /// A fact of a person's military discharge.
+        MilitaryDischarge = "http://gedcomx.org/MilitaryDischarge",
+        /// A fact of a person's registration for a military draft.
+        MilitaryDraftRegistration = "http://gedcomx.org/MilitaryDraftRegistration",
+        /// A fact of a person's military induction.
+        MilitaryInduction = "http://gedcomx.org/MilitaryInduction",
+        /// A fact of a person's military service.
+        MilitaryService = "http://gedcomx.org/MilitaryService",
+        /// A fact of a person's church mission.
+        Mission = "http://gedcomx.org/Mission",
+        /// A fact of a person's move (i.e. change of residence) from a location.
+        MoveFrom = "http://gedcomx.org/MoveFrom",
+        /// A fact of a person's move (i.e. change of residence) to a new location.
+        MoveTo = "http://gedcomx.org/MoveTo",
+        /// A fact that a person was born as part of a multiple birth, e.g. twins.
+        MultipleBirth = "http://gedcomx.org/MultipleBirth",
+        /// A fact of a person's national id, e.g. a social security number.
+        NationalId = "http://gedcomx.org/NationalId",
+        /// A fact of a person's nationality.
+        Nationality = "http://gedcomx.org/Nationality",
+        /// A fact of a person's naturalization, i.e. acquisition of citizenship.
+        Naturalization = "http://gedcomx.org/Naturalization",
+        /// A fact of the number of children of a person or couple.
+        NumberOfChildren = "http://gedcomx.org/NumberOfChildren",
+        /// A fact of a person's number of marriages.
+        NumberOfMarriages = "http://gedcomx.org/NumberOfMarriages",
+        /// A fact of a person's obituary.
+        Obituary = "http://gedcomx.org/Obituary",
+        /// A fact of a person's official, e.g. governmental, position.
+        OfficialPosition = "http://gedcomx.org/OfficialPosition",
+        /// A fact of a person's occupation or employment.
+        Occupation = "http://gedcomx.org/Occupation",
+        /// A fact of a person's ordination.
+        Ordination = "http://gedcomx.org/Ordination",
+        /// A fact of a person's legal pardon.
+        Pardon = "http://gedcomx.org/Pardon",
+        /// A fact of a person's physical description.
+        PhysicalDescription = "http://gedcomx.org/PhysicalDescription",
+        /// A fact of a receipt of probate of a person's property.
+        Probate = "http://gedcomx.org/Probate",
+        /// A fact of a person's property or possessions.
+        Property = "http://gedcomx.org/Property",
+        /// A fact of a person's race.
+        Race = "http://gedcomx.org/Race",
+        /// A fact of a person's religion or religious affiliation.
+        Religion = "http://gedcomx.org/Religion",
+        /// A fact of a person's residence.
+        Residence = "http://gedcomx.org/Residence",
+        /// A fact of a person's retirement.
+        Retirement = "http://gedcomx.org/Retirement",
+        /// A fact of a person's stillbirth.
+        Stillbirth = "http://gedcomx.org/Stillbirth",
+        /// A fact of a person's tax assessment.
+        TaxAssessment = "http://gedcomx.org/TaxAssessment",
+        /// A fact of a person's tribe.
+        Tribe = "http://gedcomx.org/Tribe",
+        /// A fact of a person's will.
+        Will = "http://gedcomx.org/Will",
+        /// A fact of a person's visit to a place different from the person's residence.
+        Visit = "http://gedcomx.org/Visit",
+        /// A fact of a person's yahrzeit date.
+        Yahrzeit = "http://gedcomx.org/Yahrzeit",
 
-/// Enumeration of standard fact types.
-#[derive(Debug, Clone, Copy, PartialEq)] //, serde::Deserialize)] //, serde::Serialize)]
-pub enum FactType {
-    /// A fact of a person's birth.
-    Birth,
-    /// A fact of a person's occupation or employment.
-    Occupation,
-}
-
-impl FactType {
-    pub fn as_qname_uri(&self) -> &str {
-        match self {
-            Self::Birth => "http://gedcomx.org/Birth",
-            Self::Occupation => "http://gedcomx.org/Occupation",
-            // Self::Intersex => "http://gedcomx.org/Intersex",
-            // Self::Unknown => "http://gedcomx.org/Unknown",
-        }
-    }
-    pub fn from_qname_uri(qname_uri: &str) -> Self {
-        match qname_uri {
-            "http://gedcomx.org/Birth" => Self::Birth,
-            "http://gedcomx.org/Occupation" => Self::Occupation,
-            // Self::Intersex => "http://gedcomx.org/Intersex",
-            // Self::Unknown => "http://gedcomx.org/Unknown",
-            _ => todo!("handle qname_uri='{}'", qname_uri),
-        }
-    }
-}
-
-impl serde::Serialize for FactType {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        serializer.serialize_str(self.as_qname_uri())
-    }
-}
-
-impl<'de> serde::Deserialize<'de> for FactType {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        deserializer.deserialize_str(FactTypeVisitor)
-    }
-}
-
-struct FactTypeVisitor;
-
-impl<'de> serde::de::Visitor<'de> for FactTypeVisitor {
-    type Value = FactType;
-
-    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("a uri")
-    }
-
-    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
-    where
-        E: serde::de::Error,
-    {
-        Ok(FactType::from_qname_uri(value))
-    }
-}
-impl SerializeXml for FactType {
-    fn tag(&self) -> &str {
-        "gender"
-    }
+        // Couple facts
+        /// The fact of an annulment of a marriage.
+        Annulment = "http://gedcomx.org/Annulment",
+        /// The fact of a marriage by common law.
+        CommonLawMarriage = "http://gedcomx.org/CommonLawMarriage",
+        /// The fact of a civil union of a couple.
+        CivilUnion = "http://gedcomx.org/CivilUnion",
+        /// The fact of a divorce of a couple.
+        Divorce = "http://gedcomx.org/Divorce",
+        /// The fact of a filing for divorce.
+        DivorceFiling = "http://gedcomx.org/DivorceFiling",
+        /// The fact of a domestic partnership of a couple.
+        DomesticPartnership = "http://gedcomx.org/DomesticPartnership",
+        /// The fact of an engagement to be married.
+        Engagement = "http://gedcomx.org/Engagement",
+        /// The fact of a marriage.
+        Marriage = "http://gedcomx.org/Marriage",
+        /// The fact of a marriage banns.
+        MarriageBanns = "http://gedcomx.org/MarriageBanns",
+        /// The fact of a marriage contract.
+        MarriageContract = "http://gedcomx.org/MarriageContract",
+        /// The fact of a marriage license.
+        MarriageLicense = "http://gedcomx.org/MarriageLicense",
+        /// The fact of a marriage notice.
+        MarriageNotice = "http://gedcomx.org/MarriageNotice",
+        /// The fact of a separation of a couple.
+        Separation = "http://gedcomx.org/Separation",
 
-    fn serialize_xml<W: std::io::Write>(
-        &self,
-        ser: &mut quick_xml::Writer<W>,
-    ) -> Result<(), crate::ser::SerError> {
-        let mut elem = BytesStart::new(self.tag());
-        elem.push_attribute(("type", self.as_qname_uri()));
-        ser.write_event(Event::Empty(elem))?;
-        Ok(())
+        // Parent-child facts
+        /// A fact about an adoptive relationship between a parent and a child.
+        AdoptiveParent = "http://gedcomx.org/AdoptiveParent",
+        /// A fact about the biological relationship between a parent and a child.
+        BiologicalParent = "http://gedcomx.org/BiologicalParent",
+        /// A fact about the child order between a parent and a child.
+        ChildOrder = "http://gedcomx.org/ChildOrder",
+        /// A fact about an entering heir relationship between a parent and a child.
+        EnteringHeir = "http://gedcomx.org/EnteringHeir",
+        /// A fact about an exiting heir relationship between a parent and a child.
+        ExitingHeir = "http://gedcomx.org/ExitingHeir",
+        /// A fact about a foster relationship between a foster parent and a child.
+        FosterParent = "http://gedcomx.org/FosterParent",
+        /// A fact about a legal guardianship between a parent and a child.
+        GuardianParent = "http://gedcomx.org/GuardianParent",
+        /// A fact about the step relationship between a parent and a child.
+        StepParent = "http://gedcomx.org/StepParent",
+        /// A fact about a sociological relationship between a parent and a child.
+        SociologicalParent = "http://gedcomx.org/SociologicalParent",
+        /// A fact about a pregnancy surrogate relationship between a parent and a child.
+        SurrogateParent = "http://gedcomx.org/SurrogateParent",
     }
 }
@@ -0,0 +1,19 @@
+use crate::common::vocabulary;
+
+vocabulary! {
+    /// Enumeration of standard identifier types.
+    pub enum IdentifierType {
+        /// The primary identifier for the resource. The value of the identifier
+        /// must resolve to the instance of the resource.
+        Primary = "http://gedcomx.org/Primary",
+        /// An identifier for the resource in an external authority or other
+        /// expert system.
+        Authority = "http://gedcomx.org/Authority",
+        /// An identifier that has been relegated, deprecated, or otherwise
+        /// downgraded, e.g. after two resources were merged.
+        Deprecated = "http://gedcomx.org/Deprecated",
+        /// An identifier that is guaranteed to resolve to the resource for as
+        /// long as the resource exists.
+        Persistent = "http://gedcomx.org/Persistent",
+    }
+}
@@ -1,13 +1,29 @@
+mod confidence_level;
+mod document_type;
+mod event_role_type;
+mod event_type;
+mod fact_qualifier_type;
 mod fact_type;
 mod gender;
+mod identifier_type;
 mod name;
 mod name_type;
 mod relationship;
 mod resource;
+mod source_reference_qualifier_type;
+mod text_type;
 
+pub use confidence_level::ConfidenceLevel;
+pub use document_type::DocumentType;
+pub use event_role_type::EventRoleType;
+pub use event_type::EventType;
+pub use fact_qualifier_type::FactQualifierType;
 pub use fact_type::FactType;
-pub use gender::Gender;
-pub use name::NamePartType;
+pub use gender::GenderType;
+pub use identifier_type::IdentifierType;
+pub use name::{NamePartQualifierType, NamePartType};
 pub use name_type::NameType;
 pub use relationship::RelationshipType;
 pub use resource::ResourceType;
+pub use source_reference_qualifier_type::SourceReferenceQualifierType;
+pub use text_type::TextType;
@@ -1,5 +1,65 @@
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub enum NamePartType {
-    Given,
-    Surname,
+use crate::common::vocabulary;
+
+vocabulary! {
+    /// Enumeration of standard name part types.
+    pub enum NamePartType {
+        /// A name prefix, e.g. "Mr." or "Sir".
+        Prefix = "http://gedcomx.org/Prefix",
+        /// A name suffix, e.g. "Jr." or "III".
+        Suffix = "http://gedcomx.org/Suffix",
+        /// A given name.
+        Given = "http://gedcomx.org/Given",
+        /// A surname.
+        Surname = "http://gedcomx.org/Surname",
+    }
+}
+
+vocabulary! {
+    /// Enumeration of standard qualifiers of a name part.
+    pub enum NamePartQualifierType {
+        /// A designation for honorifics (e.g. Dr., Rev., His Majesty, Haji),
+        /// ranks (e.g. Colonel, General, Knight, Esquire), positions (e.g. Count,
+        /// Chief, Father, King) or other titles (e.g., PhD, MD).
+        Title = "http://gedcomx.org/Title",
+        /// A designation for the name of most prominent in importance among the
+        /// names of that type (e.g., the primary given name).
+        Primary = "http://gedcomx.org/Primary",
+        /// A designation for a name that is not primary in its importance among
+        /// the names of that type.
+        Secondary = "http://gedcomx.org/Secondary",
+        /// A designation useful for cultures that designate a middle name that is
+        /// distinct from a given name and a surname.
+        Middle = "http://gedcomx.org/Middle",
+        /// A designation for one's familiar name.
+        Familiar = "http://gedcomx.org/Familiar",
+        /// A designation for a name given for religious purposes.
+        Religious = "http://gedcomx.org/Religious",
+        /// A name that associates a person with a group, such as a clan, tribe,
+        /// or patriarchal hierarchy.
+        Family = "http://gedcomx.org/Family",
+        /// A designation given by women to their original surname after they
+        /// adopt a new surname upon marriage.
+        Maiden = "http://gedcomx.org/Maiden",
+        /// A name derived from a father or paternal ancestor.
+        Patronymic = "http://gedcomx.org/Patronymic",
+        /// A name derived from a mother or maternal ancestor.
+        Matronymic = "http://gedcomx.org/Matronymic",
+        /// A name derived from associated geography.
+        Geographic = "http://gedcomx.org/Geographic",
+        /// A name derived from one's occupation.
+        Occupational = "http://gedcomx.org/Occupational",
+        /// A name derived from a characteristic.
+        Characteristic = "http://gedcomx.org/Characteristic",
+        /// A name mandated by law for populations from Congo Free State / Belgian
+        /// Congo / Congo / Democratic Republic of Congo (formerly Zaire).
+        Postnom = "http://gedcomx.org/Postnom",
+        /// A grammatical designation for articles (a, the, dem, las, el, etc.),
+        /// prepositions (of, from, aus, zu, op, etc.), initials, annotations
+        /// (e.g. twin, wife of, infant, unknown) or other parts of a name that
+        /// are usually ignored when sorting names.
+        Particle = "http://gedcomx.org/Particle",
+        /// The "root" of a name part as distinguished from prefixes or suffixes,
+        /// e.g. "Wilk" in the Polish surname "Wilkówna".
+        RootName = "http://gedcomx.org/RootName",
+    }
 }
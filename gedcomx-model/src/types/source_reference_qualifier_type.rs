@@ -0,0 +1,18 @@
+use crate::common::vocabulary;
+
+vocabulary! {
+    /// Enumeration of standard qualifiers of a source reference.
+    pub enum SourceReferenceQualifierType {
+        /// A region of text in a digital document, in the form `start,end` of
+        /// character positions.
+        CharacterRegion = "http://gedcomx.org/CharacterRegion",
+        /// A rectangular region of a digital image, see
+        /// [`RectangleRegion`](crate::source::RectangleRegion).
+        RectangleRegion = "http://gedcomx.org/RectangleRegion",
+        /// A region of time of a digital audio or video recording, in the form
+        /// `start,end` of milliseconds.
+        TimeRegion = "http://gedcomx.org/TimeRegion",
+        /// A page number.
+        Page = "http://gedcomx.org/Page",
+    }
+}
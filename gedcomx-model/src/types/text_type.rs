@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+
+/// How the text of a [`Document`](crate::conclusion::Document) is to be read.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum TextType {
+    /// Plain text, the default.
+    #[default]
+    Plain,
+    /// XHTML markup, written as it is in XML.
+    Xhtml,
+}
+
+impl TextType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Plain => "plain",
+            Self::Xhtml => "xhtml",
+        }
+    }
+}
+
+impl deserx::XmlValue for TextType {
+    fn from_xml_value(value: &str) -> deserx::Result<Self> {
+        match value {
+            "plain" => Ok(Self::Plain),
+            "xhtml" => Ok(Self::Xhtml),
+            _ => Err(deserx::Error::invalid_value(
+                value,
+                "expected 'plain' or 'xhtml'",
+            )),
+        }
+    }
+
+    fn to_xml_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
+}
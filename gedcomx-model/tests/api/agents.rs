@@ -0,0 +1,93 @@
+use std::error::Error;
+
+use gedcomx_model::agent::{Address, Agent, OnlineAccount};
+use gedcomx_model::common::{Identifier, Uri};
+use gedcomx_model::conclusion::Person;
+use gedcomx_model::types::IdentifierType;
+
+use crate::common::{assert_xml_round_trip, iri};
+
+fn repository() -> Result<Agent, Box<dyn Error>> {
+    let archivist = Person::new(iri("#P-9"));
+    Ok(Agent::new(iri("#A-1"))
+        .identifier(
+            Identifier::new(Uri::new("https://example.org/repositories/42".into()))
+                .r#type(IdentifierType::Primary),
+        )
+        .name("Riksarkivet")
+        .homepage(iri("https://riksarkivet.se"))
+        .openid(iri("https://openid.example.org/riksarkivet"))
+        .account(OnlineAccount::new(
+            iri("https://www.familysearch.org"),
+            "riksarkivet",
+        ))
+        .try_email("forskarservice@riksarkivet.se")?
+        .try_phone("+46-10-476-70-00")?
+        .address(
+            Address::new()
+                .street("Box 12541")
+                .street("Fyrverkarbacken 13")
+                .postal_code("102 29")
+                .city("Stockholm")
+                .country("Sweden"),
+        )
+        .person(&archivist))
+}
+
+const REPOSITORY_XML: &str = r##"<agent id="#A-1"><identifier type="http://gedcomx.org/Primary">https://example.org/repositories/42</identifier><email resource="mailto:forskarservice@riksarkivet.se"/><name>Riksarkivet</name><homepage resource="https://riksarkivet.se"/><openid resource="https://openid.example.org/riksarkivet"/><account><serviceHomepage resource="https://www.familysearch.org"/><accountName>riksarkivet</accountName></account><phone resource="tel:+46-10-476-70-00"/><address><city>Stockholm</city><country>Sweden</country><postalCode>102 29</postalCode><street>Box 12541</street><street2>Fyrverkarbacken 13</street2></address><person resource="#P-9"/></agent>"##;
+
+#[test]
+fn agent_round_trips_as_xml() -> Result<(), Box<dyn Error>> {
+    assert_xml_round_trip(&repository()?, REPOSITORY_XML)
+}
+
+#[test]
+fn agent_round_trips_as_json() -> Result<(), Box<dyn Error>> {
+    let agent = repository()?;
+
+    let json = serde_json::to_value(&agent)?;
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "identifiers": {"http://gedcomx.org/Primary": ["https://example.org/repositories/42"]},
+            "names": [{"value": "Riksarkivet"}],
+            "homepage": {"resource": "https://riksarkivet.se"},
+            "openid": {"resource": "https://openid.example.org/riksarkivet"},
+            "accounts": [{
+                "serviceHomepage": {"resource": "https://www.familysearch.org"},
+                "accountName": "riksarkivet",
+            }],
+            "emails": [{"resource": "mailto:forskarservice@riksarkivet.se"}],
+            "phones": [{"resource": "tel:+46-10-476-70-00"}],
+            "addresses": [{
+                "city": "Stockholm",
+                "country": "Sweden",
+                "postalCode": "102 29",
+                "street": "Box 12541",
+                "street2": "Fyrverkarbacken 13",
+            }],
+            "person": {"resource": "#P-9"},
+            "id": "#A-1",
+        })
+    );
+    assert_eq!(serde_json::from_value::<Agent>(json)?, agent);
+    Ok(())
+}
+
+#[test]
+fn address_street_lines() {
+    let address = Address::new().street("c/o Andersson").street("Storgatan 1");
+    assert_eq!(
+        address.streets().collect::<Vec<_>>(),
+        ["c/o Andersson", "Storgatan 1"]
+    );
+    assert_eq!(address.get_city(), None);
+
+    let mut full = (1..=7).fold(Address::new(), |address, n| {
+        address.street(format!("Line {}", n))
+    });
+    assert_eq!(full.streets().count(), 6);
+    assert_eq!(full.streets().last(), Some("Line 6"));
+    assert!(!full.add_street("Line 8".into()));
+    assert_eq!(full.streets().count(), 6);
+}
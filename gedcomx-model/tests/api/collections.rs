@@ -0,0 +1,92 @@
+use std::error::Error;
+
+use deserx::{DeserializeXml, SerializeXml};
+use gedcomx_model::common::ResourceReference;
+use gedcomx_model::conclusion::{Person, Relationship};
+use gedcomx_model::source::SourceDescription;
+use gedcomx_model::GedcomX;
+
+use crate::common::{emma_bocock_example, iri};
+
+fn family() -> GedcomX {
+    let persons = ["#P-1", "#P-2"].map(|id| Person::new(iri(id)).name("Bocock"));
+    let mut gedcomx: GedcomX = persons.iter().cloned().collect();
+    gedcomx.extend([Relationship::parent_child(&persons[1], &persons[0])]);
+    gedcomx.extend([SourceDescription::new(iri("#S-1")).title("Family tree of the Bococks")]);
+    gedcomx.id(iri("#GX-1")).lang("en").description(iri("#S-1"))
+}
+
+#[test]
+fn metadata_round_trips_as_xml() -> Result<(), Box<dyn Error>> {
+    let gedcomx = family();
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    gedcomx.serialize_xml(&mut writer)?;
+    let xml = String::from_utf8(writer.into_inner())?;
+    assert!(xml.starts_with(
+        r##"<gedcomx xmlns="http://gedcomx.org/v1/" id="#GX-1" xml:lang="en" description="#S-1">"##
+    ));
+    assert_eq!(
+        GedcomX::deserialize_xml(&mut quick_xml::Reader::from_str(&xml))?,
+        gedcomx
+    );
+    Ok(())
+}
+
+#[test]
+fn metadata_round_trips_as_json() -> Result<(), Box<dyn Error>> {
+    let gedcomx = family();
+
+    let json = serde_json::to_string(&gedcomx)?;
+    assert!(json.starts_with(r##"{"id":"#GX-1","lang":"en","description":"#S-1","persons":["##));
+    assert!(!json.contains("attribution"));
+    assert_eq!(serde_json::from_str::<GedcomX>(&json)?, gedcomx);
+
+    assert_eq!(serde_json::from_str::<GedcomX>("{}")?, GedcomX::new());
+    assert_eq!(serde_json::to_string(&GedcomX::new())?, "{}");
+    Ok(())
+}
+
+#[test]
+fn collections_are_built_and_inspected() {
+    let gedcomx = family();
+    assert_eq!(gedcomx.get_id(), Some(&iri("#GX-1")));
+    assert_eq!(gedcomx.get_lang(), Some("en"));
+    assert_eq!(gedcomx.persons().len(), 2);
+    assert_eq!(gedcomx.relationships().len(), 1);
+    assert_eq!(
+        gedcomx.resolve_description().map(SourceDescription::get_id),
+        Some("#S-1")
+    );
+    assert!(gedcomx.agents().is_empty());
+    assert!(gedcomx.groups().is_empty());
+
+    let emma = emma_bocock_example();
+    assert_eq!(emma.source_descriptions().len(), 1);
+    assert_eq!(emma.agents().len(), 2);
+    assert_eq!(emma.documents().len(), 1);
+    assert!(emma.get_attribution().is_some());
+    assert_eq!(emma.resolve_description(), None);
+
+    let source = SourceDescription::new(iri("S-1"));
+    let local = GedcomX::new()
+        .source_description(source.clone())
+        .description(iri("#S-1"));
+    assert_eq!(local.resolve_description(), Some(&source));
+    let foreign = local.description(iri("http://elsewhere.example/x#S-1"));
+    assert_eq!(foreign.resolve_description(), None);
+}
+
+#[test]
+fn collections_can_be_changed_in_place() {
+    let mut gedcomx = family();
+    for person in gedcomx.persons_mut() {
+        person.set_extracted(true);
+    }
+    assert!(gedcomx.persons().iter().all(Person::is_extracted));
+
+    for relationship in gedcomx.relationships_mut() {
+        relationship.set_person2(ResourceReference::new(iri("#P-3")));
+    }
+    assert_eq!(gedcomx.relationships()[0].person2().resource(), "#P-3");
+}
@@ -1,14 +1,71 @@
+use std::error::Error;
+use std::fmt::Debug;
+
+use deserx::{DeserializeXml, SerializeXml};
 use gedcomx_model::agent::Agent;
 use gedcomx_model::common::{DateTime, IriRef};
 use gedcomx_model::conclusion::{Date, Document, Fact, Person, PlaceReference, Relationship};
-use gedcomx_model::gedcomx::{Attribution};
+use gedcomx_model::gedcomx::Attribution;
 use gedcomx_model::source::{SourceCitation, SourceDescription};
-use gedcomx_model::types::{FactType, Gender, RelationshipType, ResourceType};
+use gedcomx_model::types::{FactType, GenderType, ResourceType};
 use gedcomx_model::GedcomX;
 
 pub fn iri(s: &str) -> IriRef {
     IriRef::parse(s.into()).expect("parse iri")
 }
+
+/// Asserts that `item` is written as `expected` and read back from it,
+/// indented by 4 spaces if `expected` spans several lines.
+pub fn assert_xml_round_trip<T>(item: &T, expected: &str) -> Result<(), Box<dyn Error>>
+where
+    T: SerializeXml + DeserializeXml + PartialEq + Debug,
+{
+    let indented = expected.contains('\n');
+    let mut writer = if indented {
+        quick_xml::Writer::new_with_indent(Vec::new(), b' ', 4)
+    } else {
+        quick_xml::Writer::new(Vec::new())
+    };
+    item.serialize_xml(&mut writer)?;
+    assert_eq!(String::from_utf8(writer.into_inner())?, expected);
+
+    let mut reader = quick_xml::Reader::from_str(expected);
+    reader.trim_text(indented);
+    assert_eq!(&T::deserialize_xml(&mut reader)?, item);
+    Ok(())
+}
+
+/// Asserts that `item` is written as `expected` and read back from it,
+/// pretty-printed if `expected` spans several lines.
+pub fn assert_json_round_trip<T>(item: &T, expected: &str) -> Result<(), Box<dyn Error>>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + Debug,
+{
+    let json = if expected.contains('\n') {
+        serde_json::to_string_pretty(item)?
+    } else {
+        serde_json::to_string(item)?
+    };
+    assert_eq!(json, expected);
+    assert_eq!(&serde_json::from_str::<T>(expected)?, item);
+    Ok(())
+}
+
+/// Writes `gedcomx` in both formats and asserts that it is read back the
+/// same, returning the XML and the JSON for further checks.
+pub fn round_trip_document(gedcomx: &GedcomX) -> Result<(String, String), Box<dyn Error>> {
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    gedcomx.serialize_xml(&mut writer)?;
+    let xml = String::from_utf8(writer.into_inner())?;
+    assert_eq!(
+        &GedcomX::deserialize_xml(&mut quick_xml::Reader::from_str(&xml))?,
+        gedcomx
+    );
+
+    let json = serde_json::to_string(gedcomx)?;
+    assert_eq!(&serde_json::from_str::<GedcomX>(&json)?, gedcomx);
+    Ok((xml, json))
+}
 pub fn emma_bocock_example() -> GedcomX {
     let contributor = Agent::new(iri("#A-1"))
         .name("Jane Doe")
@@ -36,7 +93,7 @@ pub fn emma_bocock_example() -> GedcomX {
         .extracted(true)
         .source(&source_description)
         .name("Emma Bocock")
-        .gender(Gender::Female)
+        .gender(GenderType::Female)
         .fact(birth);
     let father = Person::new(iri("#P-2"))
         .extracted(true)
@@ -47,13 +104,9 @@ pub fn emma_bocock_example() -> GedcomX {
         .extracted(true)
         .source(&source_description)
         .name("Sarah Bocock formerly Brough");
-    let father_relationship = Relationship::new(RelationshipType::ParentChild)
-        .person1(&father)
-        .person2(&emma);
+    let father_relationship = Relationship::parent_child(&father, &emma);
 
-    let mother_relationship = Relationship::new(RelationshipType::ParentChild)
-        .person1(&mother)
-        .person2(&emma);
+    let mother_relationship = Relationship::parent_child(&mother, &emma);
 
     let analysis = Document::new(iri("#D-1")).text("...Jane Doe's analysis document...");
     let emma_conclusion = Person::new(iri("#C-1")).evidence(&emma).analysis(&analysis);
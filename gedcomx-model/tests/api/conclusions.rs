@@ -13,6 +13,352 @@ mod persons {
             "Emma Bocock"
         );
         assert!(person.is_extracted());
-        assert_eq!(person.facts()[0].r#type(), FactType::Birth);
+        assert_eq!(person.facts()[0].r#type(), &FactType::Birth);
+    }
+}
+
+mod dates {
+    use deserx::{DeserializeXml, SerializeXml};
+    use gedcomx_model::conclusion::{Date, Fact};
+    use gedcomx_model::date::{DateNormalizer, FormalDate, NormalizeError};
+    use gedcomx_model::types::FactType;
+
+    #[test]
+    fn formal_date_round_trips() -> Result<(), Box<dyn std::error::Error>> {
+        let date = Date::new()
+            .original("about 1843")
+            .formal("A+1843".parse::<FormalDate>()?);
+
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        date.serialize_xml(&mut writer)?;
+        let xml = String::from_utf8(writer.into_inner())?;
+        assert_eq!(
+            xml,
+            "<date><original>about 1843</original><formal>A+1843</formal></date>"
+        );
+        assert_eq!(
+            Date::deserialize_xml(&mut quick_xml::Reader::from_str(&xml))?,
+            date
+        );
+
+        let json = serde_json::to_string(&date)?;
+        assert_eq!(json, r#"{"original":"about 1843","formal":"A+1843"}"#);
+        assert_eq!(serde_json::from_str::<Date>(&json)?, date);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_formal_dates_are_errors() {
+        let xml = "<date><formal>1843</formal></date>";
+        let err = Date::deserialize_xml(&mut quick_xml::Reader::from_str(xml)).unwrap_err();
+        assert!(
+            matches!(err.kind(), deserx::ErrorKind::InvalidDate { value, .. } if value == "1843")
+        );
+        assert_eq!(err.path(), ["date", "formal"]);
+
+        assert!(serde_json::from_str::<Date>(r#"{"formal":"1843"}"#).is_err());
+    }
+
+    #[test]
+    fn facts_sort_chronologically() {
+        let fact = |original: &str, formal: Option<&str>| {
+            let mut date = Date::new().original(original);
+            date.set_formal(formal.map(|formal| formal.parse().unwrap()));
+            Fact::new(FactType::Birth).date(date)
+        };
+        let mut facts = [
+            fact("11 Feb 1901", Some("+1901-02-11")),
+            fact("unknown", None),
+            fact("abt 1843", Some("A+1843")),
+            fact("1862-1864", Some("+1862/+1864")),
+        ];
+        facts.sort_by(|a, b| {
+            a.get_date()
+                .unwrap()
+                .chronological_cmp(b.get_date().unwrap())
+        });
+        let originals: Vec<_> = facts
+            .iter()
+            .map(|fact| fact.get_date().unwrap().get_original())
+            .collect();
+        assert_eq!(
+            originals,
+            ["abt 1843", "1862-1864", "11 Feb 1901", "unknown"]
+        );
+    }
+
+    #[test]
+    fn normalize_sets_formal_from_original() {
+        let mut date = Date::new().original("bef. 3 MAR 1801");
+        assert_eq!(date.normalize().unwrap().to_string(), "/+1801-03-03");
+        assert_eq!(date.get_formal().unwrap().to_string(), "/+1801-03-03");
+
+        let mut date = Date::new().original("03/04/1850");
+        assert!(matches!(
+            date.normalize(),
+            Err(NormalizeError::Ambiguous { candidates, .. }) if candidates.len() == 2
+        ));
+        assert_eq!(date.get_formal(), None);
+        let day_first = DateNormalizer::new().day_first(true);
+        assert_eq!(
+            date.normalize_with(&day_first).unwrap().to_string(),
+            "+1850-04-03"
+        );
+    }
+}
+
+mod names {
+    use deserx::{DeserializeXml, SerializeXml};
+    use gedcomx_model::common::{Qualifier, Uri};
+    use gedcomx_model::conclusion::{Name, NameForm, NamePart};
+    use gedcomx_model::name::{NameParser, ParsedName};
+    use gedcomx_model::types::{NamePartQualifierType, NamePartType};
+
+    const XML: &str = r#"<name><nameForm><fullText>Anders Svensson</fullText><part type="http://gedcomx.org/Given" value="Anders"><qualifier name="http://gedcomx.org/Primary"/></part><part type="http://gedcomx.org/Surname" value="Svensson"><qualifier name="http://gedcomx.org/Patronymic"/><qualifier name="http://example.org/Father">Sven</qualifier></part></nameForm></name>"#;
+
+    fn anders() -> Name {
+        Name::new().name_form(
+            NameForm::new()
+                .full_text("Anders Svensson".into())
+                .part(
+                    NamePart::new(NamePartType::Given, "Anders".into())
+                        .qualifier(NamePartQualifierType::Primary),
+                )
+                .part(
+                    NamePart::new(NamePartType::Surname, "Svensson".into())
+                        .qualifier(NamePartQualifierType::Patronymic)
+                        .qualifier(
+                            Qualifier::new(Uri::new("http://example.org/Father".into()))
+                                .value("Sven"),
+                        ),
+                ),
+        )
+    }
+
+    #[test]
+    fn name_parts_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let name = anders();
+        assert_eq!(name.get_part(NamePartType::Given), Some("Anders"));
+        assert_eq!(name.get_part(NamePartType::Surname), Some("Svensson"));
+        let surname = &name.name_forms()[0].parts()[1];
+        assert!(surname.has_qualifier(&NamePartQualifierType::Patronymic));
+        assert!(!surname.has_qualifier(&NamePartQualifierType::Maiden));
+        assert_eq!(surname.qualifiers()[1].get_value(), Some("Sven"));
+
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        name.serialize_xml(&mut writer)?;
+        assert_eq!(String::from_utf8(writer.into_inner())?, XML);
+        assert_eq!(
+            Name::deserialize_xml(&mut quick_xml::Reader::from_str(XML))?,
+            name
+        );
+
+        let json = serde_json::to_value(&name)?;
+        assert_eq!(
+            json["nameForms"][0]["parts"][1],
+            serde_json::json!({
+                "type": "http://gedcomx.org/Surname",
+                "value": "Svensson",
+                "qualifiers": [
+                    {"name": "http://gedcomx.org/Patronymic"},
+                    {"name": "http://example.org/Father", "value": "Sven"},
+                ],
+            })
+        );
+        assert_eq!(serde_json::from_value::<Name>(json)?, name);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_parts_uses_the_lang_of_the_form() {
+        let mut form = NameForm::new()
+            .full_text("Anders Svensson".into())
+            .lang("sv".into());
+        assert!(form.parse_parts() > 0.5);
+        assert_eq!(form.get_part(&NamePartType::Given), Some("Anders"));
+        assert!(form.parts()[1].has_qualifier(&NamePartQualifierType::Patronymic));
+
+        let mut form = NameForm::new()
+            .full_text("Mao Zedong".into())
+            .lang("zh".into());
+        form.parse_parts();
+        assert_eq!(form.get_part(&NamePartType::Surname), Some("Mao"));
+    }
+
+    #[test]
+    fn parse_parts_with_custom_parser() {
+        struct Surnames;
+        impl NameParser for Surnames {
+            fn parse(&self, full_text: &str, _lang: Option<&str>) -> ParsedName {
+                let part = NamePart::new(NamePartType::Surname, full_text.into());
+                ParsedName::new(vec![part], 2.0)
+            }
+        }
+        let mut form = NameForm::new().full_text("Smith".into());
+        assert_eq!(form.parse_parts_with(&Surnames), 1.0);
+        assert_eq!(form.get_part(&NamePartType::Surname), Some("Smith"));
+    }
+}
+
+mod facts {
+    use deserx::{DeserializeXml, SerializeXml};
+    use gedcomx_model::common::{Qualifier, Uri};
+    use gedcomx_model::conclusion::Fact;
+    use gedcomx_model::types::{FactQualifierType, FactType};
+
+    const SHIP: &str = "http://example.org/Ship";
+
+    fn emigration() -> Fact {
+        Fact::new(FactType::Emigration)
+            .qualifier(Qualifier::from(FactQualifierType::Age).value("23"))
+            .qualifier(FactQualifierType::NonConsensual)
+            .qualifier(Qualifier::new(Uri::new(SHIP.into())).value("Vasa"))
+    }
+
+    #[test]
+    fn qualifiers_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let fact = emigration();
+
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        fact.serialize_xml(&mut writer)?;
+        let xml = String::from_utf8(writer.into_inner())?;
+        assert_eq!(
+            xml,
+            concat!(
+                r#"<fact type="http://gedcomx.org/Emigration">"#,
+                r#"<qualifier name="http://gedcomx.org/Age">23</qualifier>"#,
+                r#"<qualifier name="http://gedcomx.org/NonConsensual"/>"#,
+                r#"<qualifier name="http://example.org/Ship">Vasa</qualifier>"#,
+                "</fact>"
+            )
+        );
+        assert_eq!(
+            Fact::deserialize_xml(&mut quick_xml::Reader::from_str(&xml))?,
+            fact
+        );
+
+        let json = serde_json::to_string(&fact)?;
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"type":"http://gedcomx.org/Emigration","qualifiers":["#,
+                r#"{"name":"http://gedcomx.org/Age","value":"23"},"#,
+                r#"{"name":"http://gedcomx.org/NonConsensual"},"#,
+                r#"{"name":"http://example.org/Ship","value":"Vasa"}]}"#
+            )
+        );
+        assert_eq!(serde_json::from_str::<Fact>(&json)?, fact);
+        Ok(())
+    }
+
+    #[test]
+    fn typed_accessors() {
+        let fact = emigration();
+        assert_eq!(fact.age(), Some("23"));
+        assert_eq!(fact.cause(), None);
+        assert!(fact.is_non_consensual());
+        assert_eq!(
+            fact.qualifiers()[2].name_as::<FactQualifierType>(),
+            FactQualifierType::Custom(Uri::new(SHIP.into()))
+        );
+
+        let death = Fact::new(FactType::Death)
+            .qualifier(Qualifier::from(FactQualifierType::Cause).value("Consumption"));
+        assert_eq!(death.cause(), Some("Consumption"));
+        assert!(!death.is_non_consensual());
+    }
+}
+
+mod shared_fields {
+    use chrono::TimeZone;
+    use deserx::{DeserializeXml, SerializeXml};
+    use gedcomx_model::common::{Note, ResourceReference};
+    use gedcomx_model::conclusion::{Fact, Gender, Name, Person};
+    use gedcomx_model::gedcomx::Attribution;
+    use gedcomx_model::types::{ConfidenceLevel, FactType, GenderType};
+
+    const XML: &str = r##"<person confidence="http://gedcomx.org/Medium" id="#P-1"><attribution><contributor resource="#A-1"/><modified>2014-03-07T07:00:00+00:00</modified></attribution><note xml:lang="sv"><subject>Husförhör</subject><text>Not found in 1845.</text></note><gender confidence="http://gedcomx.org/High" type="http://gedcomx.org/Female"/><name xml:lang="en"><nameForm><fullText>Emma Bocock</fullText></nameForm></name><fact type="http://gedcomx.org/Birth"><note><text>Baptized the same day.</text></note><value>1843</value></fact></person>"##;
+
+    fn person() -> Result<Person, Box<dyn std::error::Error>> {
+        let attribution = Attribution::new()
+            .contributor(ResourceReference::try_new("#A-1".into())?)
+            .modified(chrono::Utc.with_ymd_and_hms(2014, 3, 7, 7, 0, 0).unwrap());
+        Ok(Person::with_id("#P-1")?
+            .confidence(ConfidenceLevel::Medium)
+            .attribution(attribution)
+            .note(
+                Note::new("Not found in 1845.")
+                    .subject("Husförhör")
+                    .lang("sv"),
+            )
+            .gender(Gender::new(GenderType::Female).confidence(ConfidenceLevel::High))
+            .name(Name::from("Emma Bocock").lang("en"))
+            .fact(
+                Fact::new(FactType::Birth)
+                    .value("1843")
+                    .note("Baptized the same day."),
+            ))
+    }
+
+    #[test]
+    fn round_trip_in_xml() -> Result<(), Box<dyn std::error::Error>> {
+        let person = person()?;
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        person.serialize_xml(&mut writer)?;
+        assert_eq!(String::from_utf8(writer.into_inner())?, XML);
+        assert_eq!(
+            Person::deserialize_xml(&mut quick_xml::Reader::from_str(XML))?,
+            person
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_in_json() -> Result<(), Box<dyn std::error::Error>> {
+        let person = person()?;
+        let json = serde_json::to_value(&person)?;
+        assert_eq!(json["confidence"], "http://gedcomx.org/Medium");
+        assert_eq!(
+            json["notes"][0],
+            serde_json::json!({
+                "lang": "sv",
+                "subject": "Husförhör",
+                "text": "Not found in 1845.",
+            })
+        );
+        assert_eq!(json["attribution"]["modified"], 1394175600000i64);
+        assert_eq!(json["gender"]["confidence"], "http://gedcomx.org/High");
+        assert_eq!(json["names"][0]["lang"], "en");
+        assert_eq!(
+            json["facts"][0]["notes"][0]["text"],
+            "Baptized the same day."
+        );
+        assert_eq!(serde_json::from_value::<Person>(json)?, person);
+        Ok(())
+    }
+
+    #[test]
+    fn accessors() -> Result<(), Box<dyn std::error::Error>> {
+        let person = person()?;
+        assert_eq!(
+            person.conclusion().get_confidence(),
+            Some(&ConfidenceLevel::Medium)
+        );
+        assert_eq!(
+            person.conclusion().notes()[0].get_subject(),
+            Some("Husförhör")
+        );
+        assert_eq!(
+            person
+                .conclusion()
+                .get_attribution()
+                .and_then(Attribution::get_contributor)
+                .map(ResourceReference::resource),
+            Some("#A-1")
+        );
+        let gender = person.get_gender().unwrap();
+        assert_eq!(gender.get_type(), &GenderType::Female);
+        assert_eq!(person.names()[0].conclusion().get_lang(), Some("en"));
+        Ok(())
     }
 }
@@ -0,0 +1,123 @@
+use std::error::Error;
+
+use deserx::{DeserializeXml, SerializeXml};
+use gedcomx_model::conclusion::Document;
+use gedcomx_model::types::{DocumentType, TextType};
+
+use crate::common::iri;
+
+const XHTML: &str =
+    r#"<p xmlns="http://www.w3.org/1999/xhtml">Born to <b>Jno. Pain</b> &amp; Mary</p>"#;
+
+fn transcription() -> Document {
+    Document::new(iri("#D-2"))
+        .r#type(DocumentType::Transcription)
+        .extracted(true)
+        .lang("en")
+        .xhtml(XHTML)
+}
+
+#[test]
+fn xhtml_is_written_as_markup() -> Result<(), Box<dyn Error>> {
+    let document = transcription();
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    document.serialize_xml(&mut writer)?;
+    let xml = String::from_utf8(writer.into_inner())?;
+    assert_eq!(
+        xml,
+        format!(
+            r##"<document xml:lang="en" textType="xhtml" type="http://gedcomx.org/Transcription" extracted="true" id="#D-2"><text>{}</text></document>"##,
+            XHTML
+        )
+    );
+
+    let read = Document::deserialize_xml(&mut quick_xml::Reader::from_str(&xml))?;
+    assert_eq!(read.get_text(), XHTML);
+    assert_eq!(read, document);
+    Ok(())
+}
+
+#[test]
+fn malformed_xhtml_is_not_written() -> Result<(), Box<dyn Error>> {
+    for malformed in [
+        "a < b",
+        "a & b",
+        "<p>open",
+        "</p>",
+        "<p>a</b>",
+        r#"<p class="a>b</p>"#,
+    ] {
+        let document = Document::new(iri("#D-3")).xhtml(malformed);
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        assert!(
+            document.serialize_xml(&mut writer).is_err(),
+            "{}",
+            malformed
+        );
+    }
+    for fragment in ["", "plain text", "<p>a</p><p>b &amp; c</p>", "<br/>"] {
+        let document = Document::new(iri("#D-3")).xhtml(fragment);
+        document.serialize_xml(&mut quick_xml::Writer::new(Vec::new()))?;
+    }
+
+    // Also when the XHTML comes from JSON.
+    let json = r##"{"id":"#D-3","textType":"xhtml","text":"a < b"}"##;
+    let document: Document = serde_json::from_str(json)?;
+    assert!(document
+        .serialize_xml(&mut quick_xml::Writer::new(Vec::new()))
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn plain_text_is_escaped() -> Result<(), Box<dyn Error>> {
+    let document = Document::new(iri("#D-1"))
+        .r#type(DocumentType::Analysis)
+        .text("<b> is not markup & neither is this");
+    assert_eq!(document.get_text_type(), TextType::Plain);
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    document.serialize_xml(&mut writer)?;
+    let xml = String::from_utf8(writer.into_inner())?;
+    assert!(xml.contains("<text>&lt;b&gt; is not markup &amp; neither is this</text>"));
+    assert_eq!(
+        Document::deserialize_xml(&mut quick_xml::Reader::from_str(&xml))?,
+        document
+    );
+    Ok(())
+}
+
+#[test]
+fn document_round_trips_as_json() -> Result<(), Box<dyn Error>> {
+    let document = transcription();
+
+    let json = serde_json::to_string(&document)?;
+    assert_eq!(
+        json,
+        format!(
+            r##"{{"lang":"en","textType":"xhtml","text":{:?},"type":"http://gedcomx.org/Transcription","extracted":true,"id":"#D-2"}}"##,
+            XHTML
+        )
+    );
+    let read: Document = serde_json::from_str(&json)?;
+    assert!(read.is_xhtml());
+    assert_eq!(read, document);
+    Ok(())
+}
+
+#[test]
+fn unknown_text_types_are_errors() {
+    let xml = r##"<document textType="markdown" id="#D-3"><text>*hi*</text></document>"##;
+    let err = Document::deserialize_xml(&mut quick_xml::Reader::from_str(xml)).unwrap_err();
+    assert!(
+        matches!(err.kind(), deserx::ErrorKind::InvalidValue { value, .. } if value == "markdown")
+    );
+}
+
+#[test]
+fn repeated_text_is_an_error() {
+    let xml = r##"<document id="#D-3"><text>a</text><text>b</text></document>"##;
+    let err = Document::deserialize_xml(&mut quick_xml::Reader::from_str(xml)).unwrap_err();
+    assert!(matches!(err.kind(), deserx::ErrorKind::UnexpectedElement(name) if name == "text"));
+}
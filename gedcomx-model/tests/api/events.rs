@@ -0,0 +1,120 @@
+use std::error::Error;
+
+use deserx::{DeserializeXml, SerializeXml};
+use gedcomx_model::common::Uri;
+use gedcomx_model::conclusion::{Date, Event, EventRole, Person, PlaceReference};
+use gedcomx_model::types::{EventRoleType, EventType};
+use gedcomx_model::GedcomX;
+
+use crate::common::{assert_json_round_trip, assert_xml_round_trip, iri, round_trip_document};
+
+fn marriage_example() -> GedcomX {
+    let groom = Person::new(iri("#P-1")).name("Samuel Ham");
+    let bride = Person::new(iri("#P-2")).name("Elizabeth Spiller");
+    let witness = Person::new(iri("#P-3")).name("Jno. Pain");
+    let marriage = Event::new(iri("#E-1"))
+        .r#type(EventType::Marriage)
+        .date(Date::new().original("3 November 1792"))
+        .place(PlaceReference::new().original("Luton, Bedfordshire, England"))
+        .role(EventRole::new(&groom).r#type(EventRoleType::Principal))
+        .role(EventRole::new(&bride).r#type(EventRoleType::Principal))
+        .role(
+            EventRole::new(&witness)
+                .r#type(EventRoleType::Witness)
+                .details("Signed the register"),
+        );
+    GedcomX::new()
+        .person(groom)
+        .person(bride)
+        .person(witness)
+        .event(marriage)
+}
+
+const MARRIAGE_XML: &str = r##"<event type="http://gedcomx.org/Marriage" id="#E-1">
+    <date>
+        <original>3 November 1792</original>
+    </date>
+    <place>
+        <original>Luton, Bedfordshire, England</original>
+    </place>
+    <role type="http://gedcomx.org/Principal">
+        <person resource="#P-1"/>
+    </role>
+    <role type="http://gedcomx.org/Principal">
+        <person resource="#P-2"/>
+    </role>
+    <role type="http://gedcomx.org/Witness">
+        <person resource="#P-3"/>
+        <details>Signed the register</details>
+    </role>
+</event>"##;
+
+const MARRIAGE_JSON: &str = r##"{
+  "type": "http://gedcomx.org/Marriage",
+  "date": {
+    "original": "3 November 1792"
+  },
+  "place": {
+    "original": "Luton, Bedfordshire, England"
+  },
+  "roles": [
+    {
+      "person": {
+        "resource": "#P-1"
+      },
+      "type": "http://gedcomx.org/Principal"
+    },
+    {
+      "person": {
+        "resource": "#P-2"
+      },
+      "type": "http://gedcomx.org/Principal"
+    },
+    {
+      "person": {
+        "resource": "#P-3"
+      },
+      "type": "http://gedcomx.org/Witness",
+      "details": "Signed the register"
+    }
+  ],
+  "id": "#E-1"
+}"##;
+
+#[test]
+fn event_round_trips_as_xml() -> Result<(), Box<dyn Error>> {
+    let gedcomx = marriage_example();
+    assert_xml_round_trip(&gedcomx.events()[0], MARRIAGE_XML)
+}
+
+#[test]
+fn event_round_trips_as_json() -> Result<(), Box<dyn Error>> {
+    let gedcomx = marriage_example();
+    assert_json_round_trip(&gedcomx.events()[0], MARRIAGE_JSON)
+}
+
+#[test]
+fn events_collection_round_trips() -> Result<(), Box<dyn Error>> {
+    let (xml, _) = round_trip_document(&marriage_example())?;
+    assert!(xml.contains(r##"<event type="http://gedcomx.org/Marriage" id="#E-1">"##));
+    Ok(())
+}
+
+#[test]
+fn unknown_event_types_are_kept() -> Result<(), Box<dyn Error>> {
+    let xml = r##"<event type="http://example.org/Feast" id="#E-2"><role><person resource="#P-1"/></role></event>"##;
+    let event = Event::deserialize_xml(&mut quick_xml::Reader::from_str(xml))?;
+    assert_eq!(
+        event.get_type(),
+        Some(&EventType::Custom(Uri::new(
+            "http://example.org/Feast".into()
+        )))
+    );
+    assert_eq!(event.roles()[0].get_type(), None);
+    assert_eq!(event.roles()[0].person().resource(), "#P-1");
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    event.serialize_xml(&mut writer)?;
+    assert_eq!(String::from_utf8(writer.into_inner())?, xml);
+    Ok(())
+}
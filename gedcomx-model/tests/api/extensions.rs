@@ -0,0 +1,123 @@
+use std::error::Error;
+
+use deserx::{DeserializeXml, SerializeXml};
+use gedcomx_model::conclusion::Person;
+use gedcomx_model::GedcomX;
+use serde_json::json;
+
+use crate::common::iri;
+
+const VENDOR_XML: &str = r##"<gedcomx xmlns="http://gedcomx.org/v1/" xmlns:ex="http://example.org/ext"><person id="#P-1" ex:status="verified"><fact type="http://gedcomx.org/Birth"><age>3 days</age><value>1843</value></fact><ex:tree><ex:branch id="b-1">Bocock</ex:branch></ex:tree></person><ex:header>Exported</ex:header></gedcomx>"##;
+
+#[test]
+fn unknown_xml_is_kept_and_written_back() -> Result<(), Box<dyn Error>> {
+    let mut reader = quick_xml::Reader::from_str(VENDOR_XML);
+    let mut gedcomx = GedcomX::deserialize_xml(&mut reader)?;
+
+    assert_eq!(
+        gedcomx.extensions().xml().get_attribute("xmlns:ex"),
+        Some("http://example.org/ext")
+    );
+    assert_eq!(
+        gedcomx.extensions().xml().elements(),
+        [r#"<ex:header xmlns:ex="http://example.org/ext">Exported</ex:header>"#]
+    );
+    let person = &gedcomx.persons()[0];
+    assert_eq!(
+        person.extensions().xml().get_attribute("ex:status"),
+        Some("verified")
+    );
+    assert_eq!(
+        person.extensions().xml().elements(),
+        [
+            r#"<ex:tree xmlns:ex="http://example.org/ext"><ex:branch id="b-1">Bocock</ex:branch></ex:tree>"#
+        ]
+    );
+    assert_eq!(
+        person.facts()[0].extensions().xml().elements(),
+        ["<age>3 days</age>"]
+    );
+
+    // Read-modify-write keeps the foreign data, after the known children.
+    gedcomx
+        .persons_mut()
+        .next()
+        .unwrap()
+        .add_name("Emma Bocock".into());
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    gedcomx.serialize_xml(&mut writer)?;
+    let xml = String::from_utf8(writer.into_inner())?;
+    assert_eq!(
+        xml,
+        r##"<gedcomx xmlns="http://gedcomx.org/v1/" xmlns:ex="http://example.org/ext"><person id="#P-1" ex:status="verified"><name><nameForm><fullText>Emma Bocock</fullText></nameForm></name><fact type="http://gedcomx.org/Birth"><value>1843</value><age>3 days</age></fact><ex:tree xmlns:ex="http://example.org/ext"><ex:branch id="b-1">Bocock</ex:branch></ex:tree></person><ex:header xmlns:ex="http://example.org/ext">Exported</ex:header></gedcomx>"##
+    );
+
+    let mut reader = quick_xml::Reader::from_str(&xml);
+    assert_eq!(GedcomX::deserialize_xml(&mut reader)?, gedcomx);
+    Ok(())
+}
+
+#[test]
+fn vendor_elements_with_known_names_are_extensions() -> Result<(), Box<dyn Error>> {
+    let xml = r#"<gedcomx xmlns="http://gedcomx.org/v1/" xmlns:ex="http://example.org/ext"><person id="P-1"><ex:name>Alias</ex:name><ex:fact type="custom"/><name><nameForm><fullText>Emma Bocock</fullText></nameForm></name></person></gedcomx>"#;
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let gedcomx = GedcomX::deserialize_xml(&mut reader)?;
+
+    let person = &gedcomx.persons()[0];
+    assert_eq!(person.names().len(), 1);
+    assert!(person.facts().is_empty());
+    assert_eq!(
+        person.extensions().xml().elements(),
+        [
+            r#"<ex:name xmlns:ex="http://example.org/ext">Alias</ex:name>"#,
+            r#"<ex:fact type="custom" xmlns:ex="http://example.org/ext"/>"#
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn unknown_json_is_kept_and_written_back() -> Result<(), Box<dyn Error>> {
+    let value = json!({
+        "id": "#P-1",
+        "extracted": true,
+        "notes": [{ "text": "Checked", "ex:reviewer": "kk" }],
+        "ex:status": "verified",
+        "ex:tree": { "branch": ["Bocock"] }
+    });
+    let person: Person = serde_json::from_value(value.clone())?;
+
+    assert!(person.is_extracted());
+    assert_eq!(person.extensions().json().len(), 2);
+    assert_eq!(person.extensions().json()["ex:status"], json!("verified"));
+    assert_eq!(
+        person.conclusion().notes()[0].extensions().json()["ex:reviewer"],
+        json!("kk")
+    );
+    assert!(person.extensions().xml().is_empty());
+
+    assert_eq!(serde_json::to_value(&person)?, value);
+    Ok(())
+}
+
+#[test]
+fn extensions_are_only_written_in_their_own_format() -> Result<(), Box<dyn Error>> {
+    let mut person = Person::new(iri("#P-1"));
+    person.extensions_mut().xml_mut().add_element("<ex:flag/>");
+    person
+        .extensions_mut()
+        .json_mut()
+        .insert("ex:flag".into(), json!(true));
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    person.serialize_xml(&mut writer)?;
+    assert_eq!(
+        String::from_utf8(writer.into_inner())?,
+        r##"<person id="#P-1"><ex:flag/></person>"##
+    );
+    assert_eq!(
+        serde_json::to_value(&person)?,
+        json!({ "id": "#P-1", "ex:flag": true })
+    );
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::BufReader;
+
+use gedcomx_model::conclusion::{Document, Person};
+use gedcomx_model::gedcomx::Record;
+use gedcomx_model::{Format, GedcomX, WriteOptions};
+
+use crate::common::{emma_bocock_example, iri};
+
+fn open(path: &str) -> Result<BufReader<File>, Box<dyn Error>> {
+    Ok(BufReader::new(File::open(path)?))
+}
+
+#[test]
+fn read_detects_the_format() -> Result<(), Box<dyn Error>> {
+    let from_xml = gedcomx_model::read(open("assets/data/emma-bocock.xml")?)?;
+    let from_json = gedcomx_model::read(open("assets/data/emma-bocock.json")?)?;
+    assert_eq!(from_xml, from_json);
+    assert_eq!(from_xml, emma_bocock_example());
+
+    let with_bom = b"\xEF\xBB\xBF \n {\"persons\": [{\"id\": \"P-1\"}]}";
+    assert_eq!(gedcomx_model::read(&with_bom[..])?.persons().len(), 1);
+
+    for invalid in ["", "  ", "[]", "gedcomx"] {
+        let err = gedcomx_model::read(invalid.as_bytes()).unwrap_err();
+        assert!(
+            matches!(err, gedcomx_model::Error::UnknownFormat),
+            "{}",
+            invalid
+        );
+    }
+    Ok(())
+}
+
+/// A writer whose disk is always full.
+struct FullDisk;
+
+impl std::io::Write for FullDisk {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("disk full"))
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn write_errors_show_their_cause() {
+    for format in [Format::Xml, Format::Json] {
+        let err = gedcomx_model::write(FullDisk, &emma_bocock_example(), format).unwrap_err();
+        assert!(err.to_string().contains("disk full"), "{}", err);
+    }
+}
+
+#[test]
+fn formats_are_known_by_media_type() -> Result<(), Box<dyn Error>> {
+    for format in [Format::Xml, Format::Json] {
+        assert_eq!(Format::from_media_type(format.media_type()), Some(format));
+    }
+    assert_eq!(
+        Format::from_media_type("Application/X-GEDCOMX-V1+JSON; charset=UTF-8"),
+        Some(Format::Json)
+    );
+    assert_eq!(Format::from_media_type("text/xml"), Some(Format::Xml));
+    assert_eq!(Format::from_media_type("text/plain"), None);
+
+    let format = Format::from_media_type("application/x-gedcomx-v1+xml").unwrap();
+    let gedcomx = gedcomx_model::read_as(open("assets/data/emma-bocock.xml")?, format)?;
+    assert_eq!(gedcomx, emma_bocock_example());
+    assert!(gedcomx_model::read_as(open("assets/data/emma-bocock.xml")?, Format::Json).is_err());
+    Ok(())
+}
+
+#[test]
+fn write_with_indentation_matches_the_examples() -> Result<(), Box<dyn Error>> {
+    let gedcomx = emma_bocock_example();
+
+    let mut xml = Vec::new();
+    gedcomx_model::write_with(
+        &mut xml,
+        &gedcomx,
+        &WriteOptions::new(Format::Xml).indent(4),
+    )?;
+    let expected = fs::read_to_string("assets/data/emma-bocock.xml")?;
+    assert_eq!(String::from_utf8(xml)?.trim_end(), expected.trim_end());
+
+    let mut json = Vec::new();
+    gedcomx_model::write_with(
+        &mut json,
+        &gedcomx,
+        &WriteOptions::new(Format::Json).indent(2),
+    )?;
+    let expected = fs::read_to_string("assets/data/emma-bocock.json")?;
+    assert_eq!(String::from_utf8(json)?.trim_end(), expected.trim_end());
+    Ok(())
+}
+
+#[test]
+fn write_round_trips_in_both_formats() -> Result<(), Box<dyn Error>> {
+    let gedcomx = emma_bocock_example();
+    for format in [Format::Xml, Format::Json] {
+        let mut output = Vec::new();
+        gedcomx_model::write(&mut output, &gedcomx, format)?;
+        assert_eq!(Format::sniff(&output), Some(format));
+        assert_eq!(gedcomx_model::read(output.as_slice())?, gedcomx);
+    }
+
+    let mut xml = Vec::new();
+    let options = WriteOptions::new(Format::Xml).declaration(false);
+    gedcomx_model::write_with(&mut xml, &GedcomX::new(), &options)?;
+    assert_eq!(
+        String::from_utf8(xml)?,
+        r#"<gedcomx xmlns="http://gedcomx.org/v1/"/>"#
+    );
+    Ok(())
+}
+
+#[test]
+fn namespace_prefix_is_added_to_gedcomx_elements_only() -> Result<(), Box<dyn Error>> {
+    let mut person = Person::new(iri("#P-1")).name("Emma Bocock");
+    person
+        .extensions_mut()
+        .xml_mut()
+        .add_element(r#"<tree xmlns="http://example.org/ext"><branch/></tree>"#);
+    let gedcomx = GedcomX::new().person(person);
+
+    let mut xml = Vec::new();
+    let options = WriteOptions::new(Format::Xml)
+        .declaration(false)
+        .namespace_prefix("gx");
+    gedcomx_model::write_with(&mut xml, &gedcomx, &options)?;
+    let xml = String::from_utf8(xml)?;
+    assert_eq!(
+        xml,
+        r##"<gx:gedcomx xmlns:gx="http://gedcomx.org/v1/"><gx:person id="#P-1"><gx:name><gx:nameForm><gx:fullText>Emma Bocock</gx:fullText></gx:nameForm></gx:name><tree xmlns="http://example.org/ext"><branch/></tree></gx:person></gx:gedcomx>"##
+    );
+    assert_eq!(gedcomx_model::read(xml.as_bytes())?, gedcomx);
+    Ok(())
+}
+
+#[test]
+fn namespace_prefix_is_not_added_to_xhtml() -> Result<(), Box<dyn Error>> {
+    let xhtml = r#"<p>Born to <b>Jno. Pain</b></p><br/>"#;
+    let gedcomx = GedcomX::new().document(Document::new(iri("#D-1")).xhtml(xhtml));
+
+    let mut xml = Vec::new();
+    let options = WriteOptions::new(Format::Xml)
+        .declaration(false)
+        .namespace_prefix("gx");
+    gedcomx_model::write_with(&mut xml, &gedcomx, &options)?;
+    let xml = String::from_utf8(xml)?;
+    assert_eq!(
+        xml,
+        format!(
+            r##"<gx:gedcomx xmlns:gx="http://gedcomx.org/v1/"><gx:document textType="xhtml" id="#D-1"><gx:text>{}</gx:text></gx:document></gx:gedcomx>"##,
+            xhtml
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn records_are_read_the_same_from_both_formats() -> Result<(), Box<dyn Error>> {
+    let xml = gedcomx_model::read_records(open("assets/data/emma-bocock.xml")?)?;
+    assert_eq!(xml.format(), Format::Xml);
+    let json = gedcomx_model::read_records(open("assets/data/emma-bocock.json")?)?;
+    assert_eq!(json.format(), Format::Json);
+
+    let xml = xml.collect::<Result<Vec<Record>, _>>()?;
+    let json = json.collect::<Result<Vec<Record>, _>>()?;
+    assert_eq!(xml, json);
+    Ok(())
+}
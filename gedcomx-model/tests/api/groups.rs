@@ -0,0 +1,101 @@
+use std::error::Error;
+
+use gedcomx_model::common::Uri;
+use gedcomx_model::conclusion::{Date, Group, GroupRole, Person, PlaceReference};
+use gedcomx_model::gedcomx::SubjectRef;
+use gedcomx_model::GedcomX;
+
+use crate::common::{assert_json_round_trip, assert_xml_round_trip, iri, round_trip_document};
+
+const HEAD: &str = "http://example.org/Head";
+
+fn household_example() -> GedcomX {
+    let head = Person::new(iri("#P-1")).name("Johan Persson");
+    let wife = Person::new(iri("#P-2")).name("Anna Olsdotter");
+    let household = Group::new(iri("#G-1"))
+        .name("Household of Johan Persson")
+        .date(Date::new().original("1 January 1880"))
+        .place(PlaceReference::new().original("Tuna, Uppsala län"))
+        .role(GroupRole::new(&head).r#type(Uri::new(HEAD.into())))
+        .role(GroupRole::new(&wife).details("Wife of the head"));
+    GedcomX::new().person(head).person(wife).group(household)
+}
+
+const HOUSEHOLD_XML: &str = r##"<group id="#G-1">
+    <name>Household of Johan Persson</name>
+    <date>
+        <original>1 January 1880</original>
+    </date>
+    <place>
+        <original>Tuna, Uppsala län</original>
+    </place>
+    <role type="http://example.org/Head">
+        <person resource="#P-1"/>
+    </role>
+    <role>
+        <person resource="#P-2"/>
+        <details>Wife of the head</details>
+    </role>
+</group>"##;
+
+const HOUSEHOLD_JSON: &str = r##"{
+  "names": [
+    {
+      "value": "Household of Johan Persson"
+    }
+  ],
+  "date": {
+    "original": "1 January 1880"
+  },
+  "place": {
+    "original": "Tuna, Uppsala län"
+  },
+  "roles": [
+    {
+      "person": {
+        "resource": "#P-1"
+      },
+      "type": "http://example.org/Head"
+    },
+    {
+      "person": {
+        "resource": "#P-2"
+      },
+      "details": "Wife of the head"
+    }
+  ],
+  "id": "#G-1"
+}"##;
+
+#[test]
+fn group_round_trips_as_xml() -> Result<(), Box<dyn Error>> {
+    let gedcomx = household_example();
+    assert_xml_round_trip(&gedcomx.groups()[0], HOUSEHOLD_XML)
+}
+
+#[test]
+fn group_round_trips_as_json() -> Result<(), Box<dyn Error>> {
+    let gedcomx = household_example();
+    assert_json_round_trip(&gedcomx.groups()[0], HOUSEHOLD_JSON)
+}
+
+#[test]
+fn groups_collection_round_trips() -> Result<(), Box<dyn Error>> {
+    let (xml, json) = round_trip_document(&household_example())?;
+    assert!(xml.contains(r##"<group id="#G-1">"##));
+    assert!(json.contains(r#""groups":[{"#));
+    Ok(())
+}
+
+#[test]
+fn groups_are_subjects() {
+    let gedcomx = household_example();
+    let groups: Vec<_> = gedcomx
+        .subjects()
+        .filter_map(|subject| match subject {
+            SubjectRef::Group(group) => Some(group.id().as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(groups, ["#G-1"]);
+}
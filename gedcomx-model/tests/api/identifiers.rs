@@ -0,0 +1,73 @@
+use std::error::Error;
+
+use gedcomx_model::common::{Identifier, Uri};
+use gedcomx_model::conclusion::{Event, Person};
+use gedcomx_model::gedcomx::SubjectRef;
+use gedcomx_model::types::{EventType, IdentifierType};
+use gedcomx_model::GedcomX;
+
+use crate::common::{assert_json_round_trip, assert_xml_round_trip, iri};
+
+fn identifier(value: &str) -> Identifier {
+    Identifier::new(Uri::new(value.into()))
+}
+
+fn example() -> GedcomX {
+    let person = Person::new(iri("#P-1"))
+        .identifier(
+            identifier("https://familysearch.org/ark:/61903/4:1:KWCH-T8S")
+                .r#type(IdentifierType::Primary),
+        )
+        .identifier(identifier("I0042"))
+        .identifier(
+            identifier("https://familysearch.org/ark:/61903/4:1:KWCH-OLD")
+                .r#type(IdentifierType::Deprecated),
+        )
+        .identifier(
+            identifier("https://familysearch.org/ark:/61903/4:1:KWCH-OLD2")
+                .r#type(IdentifierType::Deprecated),
+        );
+    let event = Event::with_id("#E-1")
+        .unwrap()
+        .r#type(EventType::Census)
+        .identifier(identifier("E0007"));
+    GedcomX::new().person(person).event(event)
+}
+
+const PERSON_XML: &str = r##"<person id="#P-1"><identifier type="http://gedcomx.org/Primary">https://familysearch.org/ark:/61903/4:1:KWCH-T8S</identifier><identifier>I0042</identifier><identifier type="http://gedcomx.org/Deprecated">https://familysearch.org/ark:/61903/4:1:KWCH-OLD</identifier><identifier type="http://gedcomx.org/Deprecated">https://familysearch.org/ark:/61903/4:1:KWCH-OLD2</identifier></person>"##;
+
+#[test]
+fn identifiers_round_trip_as_xml() -> Result<(), Box<dyn Error>> {
+    assert_xml_round_trip(&example().persons()[0], PERSON_XML)
+}
+
+#[test]
+fn identifiers_are_an_object_of_arrays_in_json() -> Result<(), Box<dyn Error>> {
+    assert_json_round_trip(
+        &example().persons()[0],
+        r##"{"identifiers":{"http://gedcomx.org/Primary":["https://familysearch.org/ark:/61903/4:1:KWCH-T8S"],"$":["I0042"],"http://gedcomx.org/Deprecated":["https://familysearch.org/ark:/61903/4:1:KWCH-OLD","https://familysearch.org/ark:/61903/4:1:KWCH-OLD2"]},"id":"#P-1"}"##,
+    )
+}
+
+#[test]
+fn find_subjects_by_identifier() {
+    let gedcomx = example();
+
+    let found = gedcomx.find_by_identifier(&identifier("I0042"));
+    assert!(matches!(found, Some(SubjectRef::Person(person)) if person.id().as_str() == "#P-1"));
+    let found = gedcomx.find_by_identifier(
+        &identifier("https://familysearch.org/ark:/61903/4:1:KWCH-OLD")
+            .r#type(IdentifierType::Deprecated),
+    );
+    assert!(matches!(found, Some(SubjectRef::Person(_))));
+    assert!(matches!(
+        gedcomx.find_by_identifier(&identifier("E0007")),
+        Some(SubjectRef::Event(_))
+    ));
+
+    // The type must match when it is given.
+    assert!(gedcomx
+        .find_by_identifier(&identifier("I0042").r#type(IdentifierType::Primary))
+        .is_none());
+    assert!(gedcomx.find_by_identifier(&identifier("I0043")).is_none());
+}
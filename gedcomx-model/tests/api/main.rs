@@ -1,3 +1,15 @@
+mod agents;
+mod collections;
 pub mod common;
 mod conclusions;
+mod documents;
+mod events;
+mod extensions;
+mod formats;
+mod groups;
+mod identifiers;
+mod places;
+mod relationships;
 mod ser_and_deser;
+mod sources;
+mod streaming;
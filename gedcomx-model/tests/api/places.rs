@@ -0,0 +1,71 @@
+use std::error::Error;
+
+use gedcomx_model::common::Uri;
+use gedcomx_model::conclusion::{Date, Fact, Person, PlaceDescription, PlaceReference};
+use gedcomx_model::types::FactType;
+use gedcomx_model::GedcomX;
+
+use crate::common::{assert_xml_round_trip, iri};
+
+fn gazetteer_example() -> GedcomX {
+    let england = PlaceDescription::new(iri("#PD-1"))
+        .name("England")
+        .r#type(Uri::new("http://example.org/Country".into()));
+    let ecclesall = PlaceDescription::new(iri("#PD-2"))
+        .name("Ecclesall-Bierlow")
+        .coordinates(53.36, -1.5)
+        .temporal_description(Date::new().original("1837-1974"))
+        .jurisdiction(&england);
+    let birth = Fact::new(FactType::Birth).place(PlaceReference::from(&ecclesall));
+    GedcomX::new()
+        .person(Person::new(iri("#P-1")).fact(birth))
+        .place(england)
+        .place(ecclesall)
+}
+
+const GAZETTEER_XML: &str = r##"<gedcomx xmlns="http://gedcomx.org/v1/"><person id="#P-1"><fact type="http://gedcomx.org/Birth"><place description="#PD-2"><original>Ecclesall-Bierlow</original></place></fact></person><place type="http://example.org/Country" id="#PD-1"><name>England</name></place><place id="#PD-2"><name>Ecclesall-Bierlow</name><temporalDescription><original>1837-1974</original></temporalDescription><latitude>53.36</latitude><longitude>-1.5</longitude><jurisdiction resource="#PD-1"/></place></gedcomx>"##;
+
+#[test]
+fn places_round_trip_as_xml() -> Result<(), Box<dyn Error>> {
+    assert_xml_round_trip(&gazetteer_example(), GAZETTEER_XML)
+}
+
+#[test]
+fn places_round_trip_as_json() -> Result<(), Box<dyn Error>> {
+    let gedcomx = gazetteer_example();
+
+    let json = serde_json::to_value(&gedcomx)?;
+    assert_eq!(json["places"][1]["latitude"], 53.36);
+    assert_eq!(
+        json["places"][1]["temporalDescription"]["original"],
+        "1837-1974"
+    );
+    assert_eq!(json["places"][1]["jurisdiction"]["resource"], "#PD-1");
+    assert_eq!(
+        json["persons"][0]["facts"][0]["place"]["description"],
+        "#PD-2"
+    );
+    assert_eq!(serde_json::from_value::<GedcomX>(json)?, gedcomx);
+    Ok(())
+}
+
+#[test]
+fn place_reference_resolves_to_description() {
+    let gedcomx = gazetteer_example();
+    let place = gedcomx.persons()[0].facts()[0].get_place().unwrap();
+
+    let description = gedcomx.resolve_place(place).unwrap();
+    assert_eq!(description.names()[0].value(), "Ecclesall-Bierlow");
+    assert_eq!(description.latitude(), Some(53.36));
+
+    let unknown = PlaceReference::new().description_ref(iri("#PD-3"));
+    assert!(gedcomx.resolve_place(&unknown).is_none());
+    assert!(gedcomx.resolve_place(&PlaceReference::new()).is_none());
+
+    let gedcomx = GedcomX::new().place(PlaceDescription::new(iri("PD-1")));
+    let local = PlaceReference::new().description_ref(iri("#PD-1"));
+    assert!(gedcomx.resolve_place(&local).is_some());
+    let foreign =
+        PlaceReference::new().description_ref(iri("http://gazetteer.example/places#PD-1"));
+    assert!(gedcomx.resolve_place(&foreign).is_none());
+}
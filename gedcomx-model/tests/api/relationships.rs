@@ -0,0 +1,191 @@
+use std::error::Error;
+
+use deserx::{DeserializeXml, SerializeXml};
+use gedcomx_model::common::{Identifier, ResourceReference, Uri};
+use gedcomx_model::conclusion::{Person, Relationship, RelationshipError};
+use gedcomx_model::types::RelationshipType;
+use gedcomx_model::GedcomX;
+
+use crate::common::{emma_bocock_example, iri};
+
+const COUPLE_XML: &str = r##"<relationship type="http://gedcomx.org/Couple"><note><text>Married in Sheffield</text></note><identifier>http://example.org/R-1</identifier><person1 resource="#P-2"/><person2 resource="#P-3"/></relationship>"##;
+
+fn couple() -> Relationship {
+    Relationship::couple(&Person::new(iri("#P-2")), &Person::new(iri("#P-3")))
+        .note("Married in Sheffield")
+        .identifier(Identifier::new(Uri::new("http://example.org/R-1".into())))
+}
+
+#[test]
+fn relationship_round_trips_with_subject_fields() -> Result<(), Box<dyn Error>> {
+    let relationship = couple();
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    relationship.serialize_xml(&mut writer)?;
+    assert_eq!(String::from_utf8(writer.into_inner())?, COUPLE_XML);
+    assert_eq!(
+        Relationship::deserialize_xml(&mut quick_xml::Reader::from_str(COUPLE_XML))?,
+        relationship
+    );
+
+    let json = serde_json::to_string(&relationship)?;
+    assert_eq!(serde_json::from_str::<Relationship>(&json)?, relationship);
+    Ok(())
+}
+
+#[test]
+fn participants_are_required() {
+    let xml = r##"<relationship type="http://gedcomx.org/Couple"><person1 resource="#P-2"/></relationship>"##;
+    let err = Relationship::deserialize_xml(&mut quick_xml::Reader::from_str(xml)).unwrap_err();
+    assert!(matches!(err.kind(), deserx::ErrorKind::MissingElement(name) if name == "person2"));
+
+    let json = r##"{"type":"http://gedcomx.org/Couple","person1":{"resource":"#P-2"}}"##;
+    assert!(serde_json::from_str::<Relationship>(json).is_err());
+}
+
+#[test]
+fn participants_by_type() {
+    let gedcomx = emma_bocock_example();
+    let relationship = &gedcomx.relationships()[0];
+    assert_eq!(relationship.get_type(), &RelationshipType::ParentChild);
+    assert_eq!(relationship.parent().map(|p| p.resource()), Some("#P-2"));
+    assert_eq!(relationship.child().map(|c| c.resource()), Some("#P-1"));
+    assert_eq!(relationship.partners(), None);
+    assert_eq!(
+        relationship.resolve_parent(&gedcomx).map(Person::id),
+        Some(&iri("#P-2"))
+    );
+    assert_eq!(
+        relationship.resolve_child(&gedcomx).map(Person::id),
+        Some(&iri("#P-1"))
+    );
+
+    let couple = couple();
+    assert_eq!(couple.parent(), None);
+    let [a, b] = couple.partners().unwrap();
+    assert_eq!((a.resource(), b.resource()), ("#P-2", "#P-3"));
+    assert_eq!(
+        couple.resolve_person2(&gedcomx).map(Person::id),
+        Some(&iri("#P-3"))
+    );
+}
+
+#[test]
+fn participants_are_validated() {
+    let gedcomx = emma_bocock_example();
+    for relationship in gedcomx.relationships() {
+        assert_eq!(relationship.validate_in(&gedcomx), Ok(()));
+    }
+
+    let emma = Person::new(iri("#P-1"));
+    assert_eq!(
+        Relationship::parent_child(&emma, &emma).validate(),
+        Err(RelationshipError::SameParticipant(iri("#P-1")))
+    );
+    assert_eq!(
+        Relationship::couple(&emma, &Person::new(iri("#P-9"))).validate_in(&gedcomx),
+        Err(RelationshipError::UnresolvedParticipant(iri("#P-9")))
+    );
+
+    let gedcomx = GedcomX::new()
+        .person(Person::new(iri("#P-1")))
+        .person(Person::new(iri("#P-2")))
+        .relationship(Relationship::parent_child(
+            &Person::new(iri("#P-1")),
+            &Person::new(iri("#P-2")),
+        ));
+    let reversed = Relationship::parent_child(&Person::new(iri("#P-2")), &Person::new(iri("#P-1")));
+    assert_eq!(
+        reversed.validate_in(&gedcomx),
+        Err(RelationshipError::Reversed(RelationshipType::ParentChild))
+    );
+    let couple = Relationship::couple(&Person::new(iri("#P-2")), &Person::new(iri("#P-1")));
+    assert_eq!(couple.validate_in(&gedcomx), Ok(()));
+}
+
+#[test]
+fn participants_are_compared_as_resolved() {
+    let gedcomx = GedcomX::new()
+        .person(Person::new(iri("P-1")))
+        .person(Person::new(iri("P-2")))
+        .relationship(Relationship::parent_child(
+            ResourceReference::new(iri("#P-1")),
+            ResourceReference::new(iri("P-2")),
+        ));
+
+    let same = Relationship::parent_child(
+        ResourceReference::new(iri("#P-1")),
+        ResourceReference::new(iri("P-1")),
+    );
+    assert_eq!(
+        same.validate(),
+        Err(RelationshipError::SameParticipant(iri("#P-1")))
+    );
+    assert_eq!(
+        same.validate_in(&gedcomx),
+        Err(RelationshipError::SameParticipant(iri("#P-1")))
+    );
+
+    let reversed = Relationship::parent_child(
+        ResourceReference::new(iri("P-2")),
+        ResourceReference::new(iri("P-1")),
+    );
+    assert_eq!(
+        reversed.validate_in(&gedcomx),
+        Err(RelationshipError::Reversed(RelationshipType::ParentChild))
+    );
+    let reversed = Relationship::parent_child(
+        ResourceReference::new(iri("#P-2")),
+        ResourceReference::new(iri("#P-1")),
+    );
+    assert_eq!(
+        reversed.validate_in(&gedcomx),
+        Err(RelationshipError::Reversed(RelationshipType::ParentChild))
+    );
+
+    // A fragment of another document is not a local person.
+    let foreign = Relationship::couple(
+        ResourceReference::new(iri("P-1")),
+        ResourceReference::new(iri("http://other.example/tree#P-2")),
+    );
+    assert_eq!(
+        foreign.validate_in(&gedcomx),
+        Err(RelationshipError::UnresolvedParticipant(iri(
+            "http://other.example/tree#P-2"
+        )))
+    );
+    assert!(
+        !gedcomx.persons()[1].is_referenced_by(&ResourceReference::new(iri(
+            "http://other.example/tree#P-2"
+        )))
+    );
+}
+
+#[test]
+fn relationship_id_round_trips_and_resolves() -> Result<(), Box<dyn Error>> {
+    let relationship = couple().id(iri("#R-1"));
+    assert_eq!(relationship.get_id(), Some(&iri("#R-1")));
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    relationship.serialize_xml(&mut writer)?;
+    let xml = String::from_utf8(writer.into_inner())?;
+    assert!(xml.starts_with(r##"<relationship type="http://gedcomx.org/Couple" id="#R-1">"##));
+    let read = Relationship::deserialize_xml(&mut quick_xml::Reader::from_str(&xml))?;
+    assert_eq!(read, relationship);
+    assert!(read.extensions().is_empty());
+
+    let json = serde_json::to_value(&relationship)?;
+    assert_eq!(json["id"], "#R-1");
+    let read: Relationship = serde_json::from_value(json)?;
+    assert_eq!(read, relationship);
+    assert!(read.extensions().is_empty());
+
+    let gedcomx = GedcomX::new().relationship(relationship);
+    let reference = ResourceReference::new(iri("#R-1"));
+    assert!(gedcomx.resolve_relationship(&reference).is_some());
+    let other = ResourceReference::new(iri("#R-2"));
+    assert!(gedcomx.resolve_relationship(&other).is_none());
+    let foreign = ResourceReference::new(iri("http://other.example/tree#R-1"));
+    assert!(gedcomx.resolve_relationship(&foreign).is_none());
+    Ok(())
+}
@@ -2,14 +2,12 @@ use std::error::Error;
 use std::io::Read;
 use std::{fs, io};
 
-
-use gedcomx_model::gedcomx::{verify_gedcomx};
-use gedcomx_model::ser::{serialize_to_xml};
+use gedcomx_model::gedcomx::verify_gedcomx;
+use gedcomx_model::ser::serialize_to_xml;
 
 use gedcomx_model::GedcomX;
 
-use deserx::DeserializeXml;
-
+use deserx::{DeserializeXml, ErrorKind};
 
 use crate::common::emma_bocock_example;
 
@@ -85,3 +83,101 @@ fn deserialize_from_xml() -> Result<(), Box<dyn Error>> {
     assert!(verified.is_ok());
     Ok(())
 }
+
+#[test]
+fn deserialize_from_xml_reports_missing_attribute() {
+    let xml = r##"<gedcomx xmlns="http://gedcomx.org/v1/">
+    <person id="#P-1">
+        <source/>
+    </person>
+</gedcomx>"##;
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let err = GedcomX::deserialize_xml(&mut reader).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::MissingAttribute(name) if name == "description"));
+    assert_eq!(err.path(), ["gedcomx", "person", "source"]);
+    assert_eq!(err.line_column(xml.as_bytes()), Some((3, 18)));
+}
+
+#[test]
+fn deserialize_from_xml_reports_invalid_values() {
+    let xml = r##"<gedcomx><person extracted="yes" id="#P-1"></person></gedcomx>"##;
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let err = GedcomX::deserialize_xml(&mut reader).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidBool(value) if value == "yes"));
+    assert_eq!(err.path(), ["gedcomx", "person"]);
+
+    let xml = r##"<gedcomx><agent id="#A 1"></agent></gedcomx>"##;
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let err = GedcomX::deserialize_xml(&mut reader).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidIri { value, .. } if value == "#A 1"));
+
+    let xml = r##"<gedcomx><person id="#P-1"><fact confidence="http://gedcomx.org/High"><value>1843</value></fact></person></gedcomx>"##;
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let err = GedcomX::deserialize_xml(&mut reader).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::MissingAttribute(name) if name == "type"));
+    assert_eq!(err.path(), ["gedcomx", "person", "fact"]);
+
+    let xml = r##"<gedcomx><person id="#P-1"><fact type="http://gedcomx.org/Birth"><date><formal>1843</formal></date></fact></person></gedcomx>"##;
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let err = GedcomX::deserialize_xml(&mut reader).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidDate { value, .. } if value == "1843"));
+    assert_eq!(err.path(), ["gedcomx", "person", "fact", "date", "formal"]);
+}
+
+#[test]
+fn xml_round_trip_is_lossless() -> Result<(), Box<dyn Error>> {
+    let expected = fs::read_to_string("assets/data/emma-bocock.xml")?;
+    let mut reader = quick_xml::Reader::from_str(&expected);
+    let gedcomx = GedcomX::deserialize_xml(&mut reader)?;
+
+    let mut writer = quick_xml::Writer::new_with_indent(Vec::new(), b' ', 4);
+    serialize_to_xml(&gedcomx, &mut writer)?;
+    let result = String::from_utf8(writer.into_inner())?;
+    assert_eq!(result.trim_end(), expected.trim_end());
+    Ok(())
+}
+
+#[test]
+fn standard_and_custom_fact_types_round_trip() -> Result<(), Box<dyn Error>> {
+    use deserx::SerializeXml;
+    use gedcomx_model::common::Uri;
+    use gedcomx_model::conclusion::Person;
+    use gedcomx_model::types::FactType;
+
+    let xml = r##"<person id="#P-1"><fact type="http://gedcomx.org/Death"/><fact type="http://gedcomx.org/Marriage"/><fact type="http://example.org/Confirmation"/></person>"##;
+    let person = Person::deserialize_xml(&mut quick_xml::Reader::from_str(xml))?;
+    let types: Vec<_> = person.facts().iter().map(|fact| fact.r#type()).collect();
+    let custom = FactType::Custom(Uri::new("http://example.org/Confirmation".into()));
+    assert_eq!(types, [&FactType::Death, &FactType::Marriage, &custom]);
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    person.serialize_xml(&mut writer)?;
+    assert_eq!(String::from_utf8(writer.into_inner())?, xml);
+
+    let json = serde_json::to_value(&person)?;
+    assert_eq!(json["facts"][2]["type"], "http://example.org/Confirmation");
+    assert_eq!(serde_json::from_value::<Person>(json)?, person);
+    Ok(())
+}
+
+#[test]
+fn custom_genders_round_trip() -> Result<(), Box<dyn Error>> {
+    use deserx::SerializeXml;
+    use gedcomx_model::conclusion::{Gender, Person};
+    use gedcomx_model::types::GenderType;
+
+    let xml = r##"<person id="#P-1"><gender type="http://example.org/TwoSpirit"/></person>"##;
+    let person = Person::deserialize_xml(&mut quick_xml::Reader::from_str(xml))?;
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    person.serialize_xml(&mut writer)?;
+    assert_eq!(String::from_utf8(writer.into_inner())?, xml);
+
+    let json = serde_json::to_value(&person)?;
+    assert_eq!(json["gender"]["type"], "http://example.org/TwoSpirit");
+    assert_eq!(serde_json::from_value::<Person>(json)?, person);
+    assert_eq!(
+        serde_json::from_str::<Gender>(r#"{"type":"http://gedcomx.org/Male"}"#)?,
+        Gender::new(GenderType::Male)
+    );
+    Ok(())
+}
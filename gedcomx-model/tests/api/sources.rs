@@ -0,0 +1,164 @@
+use std::error::Error;
+
+use deserx::{DeserializeXml, SerializeXml};
+use gedcomx_model::agent::Agent;
+use gedcomx_model::common::{DateTime, Identifier, Note, Uri};
+use gedcomx_model::conclusion::{Date, PlaceReference};
+use gedcomx_model::source::{Coverage, SourceDescription};
+use gedcomx_model::types::{IdentifierType, ResourceType};
+
+use crate::common::iri;
+
+fn timestamp(s: &str) -> DateTime {
+    s.parse().expect("timestamp")
+}
+
+fn scanned_page() -> SourceDescription {
+    let archive = Agent::new(iri("#A-1"));
+    let book = SourceDescription::new(iri("#S-1")).title("Ecclesall-Bierlow births 1843");
+    SourceDescription::new(iri("#S-2"))
+        .resource_type(ResourceType::DigitalArtifact)
+        .citation("Ecclesall-Bierlow births 1843, page 303.")
+        .media_type("image/jpeg")
+        .about(Uri::new("https://example.org/images/303.jpg".into()))
+        .mediator(&archive)
+        .publisher(&archive)
+        .author(&archive)
+        .source(&book)
+        .component_of(&book)
+        .title("Page 303")
+        .note(Note::new("Water damage in the margin."))
+        .rights(iri("https://creativecommons.org/licenses/by/4.0/"))
+        .coverage(
+            Coverage::new()
+                .spatial(PlaceReference::new().original("Ecclesall-Bierlow"))
+                .temporal(Date::new().original("1843")),
+        )
+        .description("A scan of the register page.")
+        .identifier(
+            Identifier::new(Uri::new("https://example.org/images/303".into()))
+                .r#type(IdentifierType::Persistent),
+        )
+        .created(timestamp("2014-03-07T07:00:00+00:00"))
+        .modified(timestamp("2015-01-01T00:00:00+00:00"))
+        .published(timestamp("2014-04-01T00:00:00+00:00"))
+        .repository(&archive)
+        .descriptor_ref(&archive)
+        .sort_key("0303")
+        .lang("en")
+}
+
+const PAGE_XML: &str = r##"<sourceDescription resourceType="http://gedcomx.org/DigitalArtifact" mediaType="image/jpeg" about="https://example.org/images/303.jpg" sortKey="0303" xml:lang="en" id="#S-2"><citation><value>Ecclesall-Bierlow births 1843, page 303.</value></citation><mediator resource="#A-1"/><publisher resource="#A-1"/><author resource="#A-1"/><source description="#S-1"/><componentOf description="#S-1"/><title>Page 303</title><note><text>Water damage in the margin.</text></note><rights resource="https://creativecommons.org/licenses/by/4.0/"/><coverage><spatial><original>Ecclesall-Bierlow</original></spatial><temporal><original>1843</original></temporal></coverage><description>A scan of the register page.</description><identifier type="http://gedcomx.org/Persistent">https://example.org/images/303</identifier><created>2014-03-07T07:00:00+00:00</created><modified>2015-01-01T00:00:00+00:00</modified><published>2014-04-01T00:00:00+00:00</published><repository resource="#A-1"/><descriptor resource="#A-1"/></sourceDescription>"##;
+
+#[test]
+fn source_description_round_trips_as_xml() -> Result<(), Box<dyn Error>> {
+    let page = scanned_page();
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    page.serialize_xml(&mut writer)?;
+    assert_eq!(String::from_utf8(writer.into_inner())?, PAGE_XML);
+
+    let mut reader = quick_xml::Reader::from_str(PAGE_XML);
+    assert_eq!(SourceDescription::deserialize_xml(&mut reader)?, page);
+    Ok(())
+}
+
+#[test]
+fn source_description_round_trips_as_json() -> Result<(), Box<dyn Error>> {
+    let page = scanned_page();
+
+    let json = serde_json::to_value(&page)?;
+    assert_eq!(json["mediaType"], "image/jpeg");
+    assert_eq!(json["componentOf"]["description"], "#S-1");
+    assert_eq!(json["authors"][0]["resource"], "#A-1");
+    assert_eq!(json["coverage"][0]["temporal"]["original"], "1843");
+    assert_eq!(
+        json["descriptions"][0]["value"],
+        "A scan of the register page."
+    );
+    assert_eq!(json["modified"], 1420070400000i64);
+    assert_eq!(json["descriptor"]["resource"], "#A-1");
+    assert_eq!(json["sortKey"], "0303");
+    assert_eq!(json["lang"], "en");
+    assert_eq!(serde_json::from_value::<SourceDescription>(json)?, page);
+    Ok(())
+}
+
+#[test]
+fn unset_fields_are_left_out_of_json() -> Result<(), Box<dyn Error>> {
+    let json = serde_json::to_value(SourceDescription::new(iri("#S-1")))?;
+    assert_eq!(
+        json,
+        serde_json::json!({"citations": [], "titles": [], "id": "#S-1"})
+    );
+    Ok(())
+}
+
+mod references {
+    use super::*;
+    use gedcomx_model::common::{Qualifier, ResourceReference};
+    use gedcomx_model::conclusion::Person;
+    use gedcomx_model::gedcomx::Attribution;
+    use gedcomx_model::source::{RectangleRegion, SourceReference};
+    use gedcomx_model::types::SourceReferenceQualifierType;
+
+    const PERSON_XML: &str = r##"<person id="#P-1"><source description="#S-2" descriptionId="S-2"><attribution><contributor resource="#A-1"/><modified>2014-03-07T07:00:00+00:00</modified></attribution><qualifier name="http://gedcomx.org/RectangleRegion">0.1,0.25,0.5,0.3</qualifier><qualifier name="http://gedcomx.org/Page">303</qualifier></source></person>"##;
+
+    fn person() -> Result<Person, Box<dyn Error>> {
+        let source = SourceReference::from(&scanned_page())
+            .description_id("S-2")
+            .attribution(
+                Attribution::new()
+                    .contributor(ResourceReference::try_new("#A-1".into())?)
+                    .modified(timestamp("2014-03-07T07:00:00+00:00")),
+            )
+            .rectangle_region(RectangleRegion::new(0.1, 0.25, 0.5, 0.3))
+            .qualifier(Qualifier::from(SourceReferenceQualifierType::Page).value("303"));
+        Ok(Person::new(iri("#P-1")).source(source))
+    }
+
+    #[test]
+    fn source_references_round_trip_as_xml() -> Result<(), Box<dyn Error>> {
+        let person = person()?;
+
+        let mut writer = quick_xml::Writer::new(Vec::new());
+        person.serialize_xml(&mut writer)?;
+        assert_eq!(String::from_utf8(writer.into_inner())?, PERSON_XML);
+
+        let mut reader = quick_xml::Reader::from_str(PERSON_XML);
+        assert_eq!(Person::deserialize_xml(&mut reader)?, person);
+        Ok(())
+    }
+
+    #[test]
+    fn source_references_round_trip_as_json() -> Result<(), Box<dyn Error>> {
+        let person = person()?;
+
+        let json = serde_json::to_value(&person)?;
+        assert_eq!(
+            json["sources"][0]["qualifiers"],
+            serde_json::json!([
+                {"name": "http://gedcomx.org/RectangleRegion", "value": "0.1,0.25,0.5,0.3"},
+                {"name": "http://gedcomx.org/Page", "value": "303"},
+            ])
+        );
+        assert_eq!(json["sources"][0]["descriptionId"], "S-2");
+        assert_eq!(serde_json::from_value::<Person>(json)?, person);
+        Ok(())
+    }
+
+    #[test]
+    fn typed_qualifier_access() -> Result<(), Box<dyn Error>> {
+        let person = person()?;
+        let source = &person.conclusion().sources()[0];
+        assert_eq!(source.get_description_id(), Some("S-2"));
+        assert_eq!(
+            source.get_qualifier(&SourceReferenceQualifierType::Page),
+            Some("303")
+        );
+        let regions: Vec<_> = source.rectangle_regions().collect();
+        assert_eq!(regions, [RectangleRegion::new(0.1, 0.25, 0.5, 0.3)]);
+        assert!(regions[0].is_relative());
+        Ok(())
+    }
+}
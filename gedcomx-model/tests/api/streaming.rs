@@ -0,0 +1,358 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+
+use deserx::{DeserializeXml, ErrorKind};
+use gedcomx_model::conclusion::Person;
+use gedcomx_model::de::{JsonRecordReader, XmlRecordReader};
+use gedcomx_model::gedcomx::{Attribution, Record};
+use gedcomx_model::ser::{JsonRecordWriter, XmlRecordWriter};
+use gedcomx_model::GedcomX;
+
+use crate::common::iri;
+
+#[test]
+fn records_are_read_one_at_a_time() -> Result<(), Box<dyn Error>> {
+    let file = BufReader::new(File::open("assets/data/emma-bocock.xml")?);
+    let records = XmlRecordReader::new(quick_xml::Reader::from_reader(file))?;
+    let mut gedcomx = records.header().clone();
+    let mut kinds = Vec::new();
+    for record in records {
+        let record = record?;
+        kinds.push(match &record {
+            Record::Attribution(_) => "attribution",
+            Record::Person(_) => "person",
+            Record::Relationship(_) => "relationship",
+            Record::SourceDescription(_) => "sourceDescription",
+            Record::Agent(_) => "agent",
+            Record::Document(_) => "document",
+            _ => "other",
+        });
+        gedcomx.add_record(record);
+    }
+    assert_eq!(
+        kinds,
+        [
+            "attribution",
+            "person",
+            "person",
+            "person",
+            "person",
+            "relationship",
+            "relationship",
+            "sourceDescription",
+            "agent",
+            "agent",
+            "document"
+        ]
+    );
+
+    let file = BufReader::new(File::open("assets/data/emma-bocock.xml")?);
+    let expected = GedcomX::deserialize_xml(&mut quick_xml::Reader::from_reader(file))?;
+    assert_eq!(gedcomx, expected);
+    Ok(())
+}
+
+#[test]
+fn root_attributes_and_extensions_are_kept() -> Result<(), Box<dyn Error>> {
+    let xml = r##"<gedcomx xmlns="http://gedcomx.org/v1/" id="#doc" xml:lang="en"><ex:header xmlns:ex="http://example.org/ext"/><agent id="A-1"/></gedcomx>"##;
+    let mut records = XmlRecordReader::new(quick_xml::Reader::from_str(xml))?;
+    assert_eq!(
+        records.header().get_id().map(|id| id.as_str()),
+        Some("#doc")
+    );
+    assert_eq!(records.header().get_lang(), Some("en"));
+
+    assert_eq!(
+        records.next().transpose()?,
+        Some(Record::Extension(
+            r#"<ex:header xmlns:ex="http://example.org/ext"/>"#.into()
+        ))
+    );
+    assert!(matches!(
+        records.next().transpose()?,
+        Some(Record::Agent(_))
+    ));
+    assert_eq!(records.next().transpose()?, None);
+
+    let gedcomx: GedcomX =
+        XmlRecordReader::new(quick_xml::Reader::from_str(xml))?.collect::<Result<_, _>>()?;
+    assert_eq!(gedcomx.extensions().xml().elements().len(), 1);
+    assert_eq!(gedcomx.agents().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn extensions_keep_the_namespaces_of_the_root() -> Result<(), Box<dyn Error>> {
+    let xml = r#"<gedcomx xmlns="http://gedcomx.org/v1/" xmlns:ex="http://example.org/ext"><ex:person ex:rank="1"/><person id="P-1"/></gedcomx>"#;
+    let mut records = XmlRecordReader::new(quick_xml::Reader::from_str(xml))?;
+    assert_eq!(
+        records.next().transpose()?,
+        Some(Record::Extension(
+            r#"<ex:person ex:rank="1" xmlns:ex="http://example.org/ext"/>"#.into()
+        ))
+    );
+    assert!(matches!(
+        records.next().transpose()?,
+        Some(Record::Person(_))
+    ));
+    assert_eq!(records.next().transpose()?, None);
+    Ok(())
+}
+
+#[test]
+fn empty_documents_have_no_records() -> Result<(), Box<dyn Error>> {
+    let mut records = XmlRecordReader::new(quick_xml::Reader::from_str("<gedcomx/>"))?;
+    assert!(records.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn errors_end_the_records() {
+    let xml = r##"<gedcomx><person id="#P-1"/><person extracted="yes" id="#P-2"/><person id="#P-3"/></gedcomx>"##;
+    let mut records = XmlRecordReader::new(quick_xml::Reader::from_str(xml)).unwrap();
+    assert!(matches!(records.next(), Some(Ok(Record::Person(_)))));
+    let err = records.next().unwrap().unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidBool(value) if value == "yes"));
+    assert_eq!(err.path(), ["gedcomx", "person"]);
+    assert!(records.next().is_none());
+
+    let err = XmlRecordReader::new(quick_xml::Reader::from_str("<person/>"))
+        .err()
+        .unwrap();
+    assert!(matches!(err.kind(), ErrorKind::UnexpectedElement(name) if name == "person"));
+}
+
+fn emma_bocock_records() -> Result<(GedcomX, Vec<Record>), Box<dyn Error>> {
+    let file = BufReader::new(File::open("assets/data/emma-bocock.xml")?);
+    let records = XmlRecordReader::new(quick_xml::Reader::from_reader(file))?;
+    let header = records.header().clone();
+    Ok((header, records.collect::<Result<_, _>>()?))
+}
+
+/// The records with the first of each type moved last, so that the
+/// collections are interleaved.
+fn interleaved(mut records: Vec<Record>) -> Vec<Record> {
+    let mut seen = Vec::new();
+    let mut firsts = Vec::new();
+    records.retain(|record| {
+        let kind = std::mem::discriminant(record);
+        if seen.contains(&kind) {
+            true
+        } else {
+            seen.push(kind);
+            firsts.push(record.clone());
+            false
+        }
+    });
+    records.extend(firsts.into_iter().rev());
+    records
+}
+
+#[test]
+fn records_are_written_as_xml_in_any_order() -> Result<(), Box<dyn Error>> {
+    let (header, records) = emma_bocock_records()?;
+    let expected: GedcomX = records.iter().cloned().collect();
+
+    let mut writer = XmlRecordWriter::new(quick_xml::Writer::new(Vec::new()), &header)?;
+    for record in &records {
+        writer.write_record(record)?;
+    }
+    let xml = writer.finish()?.into_inner();
+    let mut reader = quick_xml::Reader::from_reader(xml.as_slice());
+    assert_eq!(GedcomX::deserialize_xml(&mut reader)?, expected);
+
+    // The attribution must come before the items in XML.
+    let (attribution, items): (Vec<_>, Vec<_>) = interleaved(records.clone())
+        .into_iter()
+        .partition(|record| matches!(record, Record::Attribution(_)));
+    let mut writer = XmlRecordWriter::new(quick_xml::Writer::new(Vec::new()), &header)?;
+    for record in attribution.iter().chain(&items) {
+        writer.write_record(record)?;
+    }
+    let xml = writer.finish()?.into_inner();
+    let mut reader = quick_xml::Reader::from_reader(xml.as_slice());
+    let result = GedcomX::deserialize_xml(&mut reader)?;
+    assert_eq!(result.persons().len(), expected.persons().len());
+    assert_eq!(result.agents().len(), expected.agents().len());
+    assert_eq!(result.get_attribution(), expected.get_attribution());
+    Ok(())
+}
+
+#[test]
+fn xml_attribution_is_written_once_before_the_items() -> Result<(), Box<dyn Error>> {
+    let attribution = Attribution::new();
+    let person = Person::new(iri("#P-1"));
+
+    let writer = quick_xml::Writer::new(Vec::new());
+    let mut records = XmlRecordWriter::new_without_declaration(writer, &GedcomX::new())?;
+    records.write_attribution(&attribution)?;
+    assert!(records.write_attribution(&attribution).is_err());
+    records.write_person(&person)?;
+    let xml = String::from_utf8(records.finish()?.into_inner())?;
+    assert!(xml.starts_with(r#"<gedcomx xmlns="http://gedcomx.org/v1/"><attribution>"#));
+    assert!(xml.ends_with(r##"</attribution><person id="#P-1"/></gedcomx>"##));
+
+    let writer = quick_xml::Writer::new(Vec::new());
+    let mut records = XmlRecordWriter::new(writer, &GedcomX::new())?;
+    records.write_person(&person)?;
+    assert!(records.write_attribution(&attribution).is_err());
+
+    let header = GedcomX::new().attribution(attribution.clone());
+    let writer = quick_xml::Writer::new(Vec::new());
+    let mut records = XmlRecordWriter::new(writer, &header)?;
+    assert!(records.write_attribution(&attribution).is_err());
+    let xml = String::from_utf8(records.finish()?.into_inner())?;
+    assert!(xml.starts_with("<?xml"));
+    Ok(())
+}
+
+#[test]
+fn records_are_grouped_into_arrays_in_json() -> Result<(), Box<dyn Error>> {
+    let (header, records) = emma_bocock_records()?;
+    let header = header.id(iri("#doc")).lang("en");
+
+    let mut writer = JsonRecordWriter::new(Vec::new(), &header)?;
+    for record in interleaved(records.clone()) {
+        writer.write_record(&record)?;
+    }
+    let json = writer.finish()?;
+    let result: GedcomX = serde_json::from_slice(&json)?;
+    assert_eq!(result.get_id(), header.get_id());
+    assert_eq!(result.get_lang(), Some("en"));
+    assert!(result.get_attribution().is_some());
+    assert_eq!(result.persons().len(), 4);
+    assert_eq!(result.relationships().len(), 2);
+    assert_eq!(result.agents().len(), 2);
+    assert_eq!(result.documents().len(), 1);
+
+    // In order, each collection is written directly.
+    let mut writer = JsonRecordWriter::new(Vec::new(), &header)?;
+    let mut previous = None;
+    for record in &records {
+        let kind = std::mem::discriminant(record);
+        if previous.is_some_and(|previous| previous != kind) {
+            writer.end_collection()?;
+        }
+        previous = Some(kind);
+        writer.write_record(record)?;
+    }
+    let json = writer.finish()?;
+    let expected = records
+        .into_iter()
+        .collect::<GedcomX>()
+        .id(iri("#doc"))
+        .lang("en");
+    assert_eq!(serde_json::from_slice::<GedcomX>(&json)?, expected);
+    Ok(())
+}
+
+#[test]
+fn ended_collections_can_not_be_written_to() -> Result<(), Box<dyn Error>> {
+    let person = Person::new(iri("#P-1"));
+    let mut writer = JsonRecordWriter::new(Vec::new(), &GedcomX::new())?;
+    writer.write_person(&person)?;
+    writer.end_collection()?;
+    assert!(writer.write_person(&person).is_err());
+
+    let attribution = Attribution::new();
+    let header = GedcomX::new().attribution(attribution.clone());
+    let mut writer = JsonRecordWriter::new(Vec::new(), &header)?;
+    assert!(writer.write_attribution(&attribution).is_err());
+
+    let extension = Record::Extension(r#"<ex:header xmlns:ex="http://example.org/ext"/>"#.into());
+    let err = writer.write_record(&extension).unwrap_err();
+    assert_eq!(err.io_error_kind(), Some(std::io::ErrorKind::InvalidInput));
+    Ok(())
+}
+
+#[test]
+fn json_records_are_read_one_at_a_time() -> Result<(), Box<dyn Error>> {
+    let file = BufReader::new(File::open("assets/data/emma-bocock.json")?);
+    let mut records = JsonRecordReader::new(file)?;
+    let items = (&mut records).collect::<Result<Vec<_>, _>>()?;
+    let mut gedcomx = records.header().clone();
+    gedcomx.extend(items.clone());
+
+    let file = BufReader::new(File::open("assets/data/emma-bocock.json")?);
+    let expected: GedcomX = serde_json::from_reader(file)?;
+    assert_eq!(gedcomx, expected);
+
+    // The same records as from XML, in the same order.
+    let (_, xml_items) = emma_bocock_records()?;
+    assert_eq!(items, xml_items);
+    Ok(())
+}
+
+#[test]
+fn json_header_fields_can_be_anywhere() -> Result<(), Box<dyn Error>> {
+    let json = r##"{
+        "ex:count": 1,
+        "persons": [],
+        "id": "#doc",
+        "places": [ {"id": "PD-1"} , {"id": "PD-2"} ],
+        "ex:exported": {"by": "tool"},
+        "attribution": {"modified": 1394175600000},
+        "groups": [{"id": "G-1"}],
+        "ex:ratio":-0.5e3,"ex:flags": [1, true, null, "a \" ]"],
+        "ex:size": 12
+    }"##;
+    let mut records = JsonRecordReader::new(json.as_bytes())?;
+    assert!(matches!(
+        records.next().transpose()?,
+        Some(Record::Place(_))
+    ));
+    assert!(records.header().get_id().is_some());
+    assert!(matches!(
+        records.next().transpose()?,
+        Some(Record::Place(_))
+    ));
+    assert!(matches!(
+        records.next().transpose()?,
+        Some(Record::Attribution(_))
+    ));
+    assert!(matches!(
+        records.next().transpose()?,
+        Some(Record::Group(_))
+    ));
+    assert!(records.next().is_none());
+    assert_eq!(
+        records.header().extensions().json()["ex:exported"]["by"],
+        "tool"
+    );
+    let extensions = records.header().extensions().json();
+    assert_eq!(extensions["ex:count"], 1);
+    assert_eq!(extensions["ex:ratio"], -500.0);
+    assert_eq!(extensions["ex:flags"][3], "a \" ]");
+    assert_eq!(extensions["ex:size"], 12);
+
+    // A number that ends the root object.
+    let json = r#"{"persons": [{"id": "P-1"}], "ex:size": 12}"#;
+    let records = JsonRecordReader::new(json.as_bytes())?;
+    assert_eq!(records.collect::<Result<Vec<_>, _>>()?.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn json_errors_end_the_records() -> Result<(), Box<dyn Error>> {
+    let json = r#"{"persons": [{"id": "P-1"}, {"id": 2}, {"id": "P-3"}]}"#;
+    let mut records = JsonRecordReader::new(json.as_bytes())?;
+    assert!(matches!(records.next(), Some(Ok(Record::Person(_)))));
+    assert!(matches!(records.next(), Some(Err(_))));
+    assert!(records.next().is_none());
+
+    let mut records = JsonRecordReader::new(r#"{"persons": {}}"#.as_bytes())?;
+    assert!(records.next().unwrap().is_err());
+    assert!(JsonRecordReader::new(r#"[]"#.as_bytes()).is_err());
+
+    let mut records = JsonRecordReader::new(r#"{"persons": [{"id": "P-1"}"#.as_bytes())?;
+    assert!(matches!(records.next(), Some(Ok(Record::Person(_)))));
+    assert!(records.next().unwrap().is_err());
+
+    // A header field is checked when it is read.
+    let json = r#"{"lang": "en", "id": 5, "persons": [{"id": "P-1"}]}"#;
+    let mut records = JsonRecordReader::new(json.as_bytes())?;
+    assert!(records.next().unwrap().is_err());
+    assert_eq!(records.header().get_lang(), Some("en"));
+    Ok(())
+}
@@ -0,0 +1,369 @@
+use proc_macro2::Span;
+use syn::{
+    spanned::Spanned, Data, DeriveInput, Error, ExprPath, Fields, GenericArgument, Ident, LitStr,
+    Member, PathArguments, Result, Type,
+};
+
+/// How a field is represented in XML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    /// `<person id="..">`, the field is `XmlValue`.
+    Attribute,
+    /// `<person><name>..</name></person>`, the field is `SerializeXml`/`DeserializeXml`.
+    Element,
+    /// `<fact><value>..</value></fact>`, the field is `XmlValue`.
+    TextElement,
+    /// The text content of the element itself, the field is `XmlValue`.
+    Text,
+    /// The attributes and children of the field are those of the element.
+    Flatten,
+    /// Not represented, the field is `Default`.
+    Skip,
+    /// Unknown attributes and child elements, the field is `AsXmlExtensions`.
+    Extensions,
+}
+
+/// Whether a field is `T`, `Option<T>` or `Vec<T>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Repetition {
+    One,
+    Optional,
+    Many,
+}
+
+pub(crate) struct Field {
+    /// The field in the struct, `self.#member`.
+    pub member: Member,
+    /// The field in the generated builder.
+    pub builder: Ident,
+    pub ty: Type,
+    /// `T` in `Option<T>` and `Vec<T>`, otherwise the type itself.
+    pub inner: Type,
+    pub repetition: Repetition,
+    pub kind: Kind,
+    /// The attribute or element name.
+    pub name: String,
+    pub default: bool,
+    pub skip_serializing_if: Option<ExprPath>,
+}
+
+impl Field {
+    /// Whether the field is written as content of the element.
+    pub fn is_child(&self) -> bool {
+        matches!(
+            self.kind,
+            Kind::Element | Kind::TextElement | Kind::Text | Kind::Flatten
+        )
+    }
+}
+
+pub(crate) struct Container {
+    pub ident: Ident,
+    /// The element name.
+    pub tag: String,
+    /// Written as `xmlns` on the element.
+    pub namespace: Option<String>,
+    pub fields: Vec<Field>,
+    /// Indices into `fields` of the children, in the order they are written.
+    pub children: Vec<usize>,
+}
+
+impl Container {
+    pub fn from_derive_input(input: &DeriveInput) -> Result<Self> {
+        if !input.generics.params.is_empty() {
+            return Err(Error::new(
+                input.generics.span(),
+                "deserx: generic structs are not supported",
+            ));
+        }
+        let data = match &input.data {
+            Data::Struct(data) => data,
+            _ => {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "deserx: only structs can be derived",
+                ))
+            }
+        };
+
+        let mut tag = lower_camel_case(&input.ident.to_string());
+        let mut namespace = None;
+        let mut order: Option<Vec<Ident>> = None;
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("xml"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    tag = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("namespace") {
+                    namespace = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("order") {
+                    let mut idents = Vec::new();
+                    meta.parse_nested_meta(|meta| {
+                        idents.push(meta.path.require_ident()?.clone());
+                        Ok(())
+                    })?;
+                    order = Some(idents);
+                } else {
+                    return Err(meta.error("deserx: unknown container attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        let fields = match &data.fields {
+            Fields::Named(fields) => fields.named.iter().collect::<Vec<_>>(),
+            Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+            Fields::Unit => Vec::new(),
+        };
+        let fields = fields
+            .into_iter()
+            .enumerate()
+            .map(|(index, field)| parse_field(index, field))
+            .collect::<Result<Vec<_>>>()?;
+        if fields
+            .iter()
+            .filter(|field| field.kind == Kind::Text)
+            .count()
+            > 1
+        {
+            return Err(Error::new(
+                input.ident.span(),
+                "deserx: at most one field can be `text`",
+            ));
+        }
+        if fields
+            .iter()
+            .filter(|field| field.kind == Kind::Extensions)
+            .count()
+            > 1
+        {
+            return Err(Error::new(
+                input.ident.span(),
+                "deserx: at most one field can be `extensions`",
+            ));
+        }
+        let children = children_in_order(&fields, order)?;
+        Ok(Self {
+            ident: input.ident.clone(),
+            tag,
+            namespace,
+            fields,
+            children,
+        })
+    }
+
+    /// The field that keeps unknown attributes and child elements.
+    pub fn extensions(&self) -> Option<&Field> {
+        self.fields
+            .iter()
+            .find(|field| field.kind == Kind::Extensions)
+    }
+
+    pub fn flattened(&self) -> impl Iterator<Item = &Field> {
+        self.fields
+            .iter()
+            .filter(|field| field.kind == Kind::Flatten)
+    }
+}
+
+fn parse_field(index: usize, field: &syn::Field) -> Result<Field> {
+    let (member, builder) = match &field.ident {
+        Some(ident) => (Member::Named(ident.clone()), ident.clone()),
+        None => (
+            Member::Unnamed(index.into()),
+            Ident::new(&format!("field{}", index), field.span()),
+        ),
+    };
+    let mut kind = Kind::Element;
+    let mut name = None;
+    let mut default = false;
+    let mut skip_serializing_if = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("xml"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let path = &meta.path;
+            if path.is_ident("attribute") {
+                kind = Kind::Attribute;
+            } else if path.is_ident("element") {
+                kind = Kind::Element;
+            } else if path.is_ident("text_element") {
+                kind = Kind::TextElement;
+            } else if path.is_ident("text") {
+                kind = Kind::Text;
+            } else if path.is_ident("flatten") {
+                kind = Kind::Flatten;
+            } else if path.is_ident("skip") {
+                kind = Kind::Skip;
+            } else if path.is_ident("extensions") {
+                kind = Kind::Extensions;
+            } else if path.is_ident("rename") {
+                name = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if path.is_ident("default") {
+                default = true;
+            } else if path.is_ident("skip_serializing_if") {
+                skip_serializing_if = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+            } else {
+                return Err(meta.error("deserx: unknown field attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let (repetition, inner) = repetition(&field.ty);
+    let name = match (name, &field.ident) {
+        (Some(name), _) => name,
+        (None, Some(ident)) => lower_camel_case(&ident.to_string()),
+        (None, None)
+            if matches!(
+                kind,
+                Kind::Flatten | Kind::Skip | Kind::Text | Kind::Extensions
+            ) =>
+        {
+            String::new()
+        }
+        (None, None) => {
+            return Err(Error::new(
+                field.span(),
+                "deserx: tuple struct fields need a `rename`",
+            ))
+        }
+    };
+    match (kind, repetition) {
+        (Kind::Attribute | Kind::Text, Repetition::Many) => {
+            return Err(Error::new(
+                field.ty.span(),
+                "deserx: `attribute` and `text` fields cannot be a `Vec`",
+            ))
+        }
+        (Kind::Flatten | Kind::Extensions, Repetition::Optional | Repetition::Many) => {
+            return Err(Error::new(
+                field.ty.span(),
+                "deserx: `flatten` and `extensions` fields cannot be an `Option` or a `Vec`",
+            ))
+        }
+        _ => {}
+    }
+    Ok(Field {
+        member,
+        builder,
+        ty: field.ty.clone(),
+        inner,
+        repetition,
+        kind,
+        name,
+        default,
+        skip_serializing_if,
+    })
+}
+
+fn children_in_order(fields: &[Field], order: Option<Vec<Ident>>) -> Result<Vec<usize>> {
+    let children = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.is_child())
+        .map(|(index, _)| index);
+    let order = match order {
+        Some(order) => order,
+        None => return Ok(children.collect()),
+    };
+    let mut ordered = Vec::new();
+    for ident in &order {
+        let index = fields
+            .iter()
+            .position(|field| matches!(&field.member, Member::Named(name) if name == ident))
+            .filter(|index| fields[*index].is_child())
+            .ok_or_else(|| Error::new(ident.span(), "deserx: `order` must name child fields"))?;
+        if ordered.contains(&index) {
+            return Err(Error::new(ident.span(), "deserx: field listed twice"));
+        }
+        ordered.push(index);
+    }
+    if let Some(missing) = children.into_iter().find(|index| !ordered.contains(index)) {
+        return Err(Error::new(
+            fields[missing].member.span(),
+            "deserx: `order` must list every child field",
+        ));
+    }
+    Ok(ordered)
+}
+
+fn repetition(ty: &Type) -> (Repetition, Type) {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            let repetition = if segment.ident == "Option" {
+                Repetition::Optional
+            } else if segment.ident == "Vec" {
+                Repetition::Many
+            } else {
+                return (Repetition::One, ty.clone());
+            };
+            if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                if let Some(GenericArgument::Type(inner)) = args.args.first() {
+                    return (repetition, inner.clone());
+                }
+            }
+        }
+    }
+    (Repetition::One, ty.clone())
+}
+
+/// `source_descriptions` and `SourceDescription` become `sourceDescriptions`
+/// and `sourceDescription`.
+fn lower_camel_case(ident: &str) -> String {
+    let ident = ident.trim_start_matches("r#");
+    let mut result = String::with_capacity(ident.len());
+    let mut upper = false;
+    for (index, c) in ident.chars().enumerate() {
+        if c == '_' {
+            upper = index > 0;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else if index == 0 {
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lower_camel_case_of_fields_and_types() {
+        assert_eq!(
+            lower_camel_case("source_descriptions"),
+            "sourceDescriptions"
+        );
+        assert_eq!(lower_camel_case("SourceDescription"), "sourceDescription");
+        assert_eq!(lower_camel_case("r#type"), "type");
+        assert_eq!(lower_camel_case("person1"), "person1");
+    }
+
+    #[test]
+    fn order_must_list_every_child() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[xml(order(facts))]
+            struct Person {
+                #[xml(attribute)]
+                id: String,
+                names: Vec<Name>,
+                facts: Vec<Fact>,
+            }
+        };
+        let err = Container::from_derive_input(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "deserx: `order` must list every child field"
+        );
+    }
+}
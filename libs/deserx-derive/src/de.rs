@@ -0,0 +1,288 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::LitByteStr;
+
+use crate::attr::{Container, Field, Kind, Repetition};
+
+pub(crate) fn expand(container: &Container) -> TokenStream {
+    let ident = &container.ident;
+    let tag = &container.tag;
+
+    let builder_fields = container.fields.iter().filter_map(builder_field);
+    let attributes = container
+        .fields
+        .iter()
+        .filter(|field| field.kind == Kind::Attribute)
+        .map(|field| {
+            let name = byte_str(&field.name);
+            let builder = &field.builder;
+            let inner = &field.inner;
+            quote! {
+                #name => {
+                    builder.#builder = ::std::option::Option::Some(
+                        <#inner as ::deserx::XmlValue>::from_xml_value(value)?,
+                    );
+                    ::std::result::Result::Ok(true)
+                }
+            }
+        });
+    let flattened_attributes = container.flattened().map(|field| {
+        let ty = &field.ty;
+        let builder = &field.builder;
+        quote! {
+            if <#ty as ::deserx::de::DeserializeXmlFields>::deserialize_attribute(
+                &mut builder.#builder, name, value,
+            )? {
+                return ::std::result::Result::Ok(true);
+            }
+        }
+    });
+    // The default namespace is written by the container itself, also when
+    // the input declares it with a prefix.
+    let skip_xmlns = container.namespace.as_ref().map(|namespace| {
+        quote! {
+            if name == b"xmlns" || (name.starts_with(b"xmlns:") && value == #namespace) {
+                return ::std::result::Result::Ok(false);
+            }
+        }
+    });
+    let unknown_attribute = match container.extensions() {
+        Some(field) => {
+            let builder = &field.builder;
+            quote! {
+                #skip_xmlns
+                ::deserx::AsXmlExtensions::as_xml_extensions_mut(&mut builder.#builder)
+                    .add_attribute(::std::string::String::from_utf8_lossy(name), value);
+                ::std::result::Result::Ok(true)
+            }
+        }
+        None => quote! { ::std::result::Result::Ok(false) },
+    };
+    let unknown_child = match container.extensions() {
+        Some(field) => {
+            let builder = &field.builder;
+            quote! {
+                let xml = ::deserx::de::read_element_xml(deserializer, start, empty)?;
+                ::deserx::AsXmlExtensions::as_xml_extensions_mut(&mut builder.#builder)
+                    .add_element(xml);
+                ::std::result::Result::Ok(true)
+            }
+        }
+        None => quote! { ::std::result::Result::Ok(false) },
+    };
+    let children = container
+        .fields
+        .iter()
+        .filter(|field| matches!(field.kind, Kind::Element | Kind::TextElement))
+        .map(|field| {
+            let name = byte_str(&field.name);
+            let builder = &field.builder;
+            let value = match field.kind {
+                Kind::Element => quote! {
+                    ::deserx::de::deserialize_child(deserializer, start, empty)?
+                },
+                _ => quote! {
+                    ::deserx::de::deserialize_value(deserializer, start, empty)?
+                },
+            };
+            let store = match field.repetition {
+                Repetition::Many => quote! { builder.#builder.push(#value) },
+                // A second element for a single field would replace the
+                // first one.
+                _ => quote! {
+                    if builder.#builder.is_some() {
+                        return ::std::result::Result::Err(
+                            ::deserx::Error::unexpected_element(start.name()),
+                        );
+                    }
+                    builder.#builder = ::std::option::Option::Some(#value)
+                },
+            };
+            quote! {
+                #name if known => {
+                    #store;
+                    ::std::result::Result::Ok(true)
+                }
+            }
+        });
+    let flattened_children = container.flattened().map(|field| {
+        let ty = &field.ty;
+        let builder = &field.builder;
+        quote! {
+            if <#ty as ::deserx::de::DeserializeXmlFields>::deserialize_child(
+                &mut builder.#builder, deserializer, start, empty,
+            )? {
+                return ::std::result::Result::Ok(true);
+            }
+        }
+    });
+    let text = container
+        .fields
+        .iter()
+        .find(|field| field.kind == Kind::Text)
+        .map(|field| {
+            let builder = &field.builder;
+            quote! {
+                builder.#builder.push_str(text);
+                true
+            }
+        })
+        .unwrap_or_else(|| {
+            let flattened = container.flattened().map(|field| {
+                let ty = &field.ty;
+                let builder = &field.builder;
+                quote! {
+                    if <#ty as ::deserx::de::DeserializeXmlFields>::deserialize_text(
+                        &mut builder.#builder, text,
+                    ) {
+                        return true;
+                    }
+                }
+            });
+            quote! {
+                #(#flattened)*
+                false
+            }
+        });
+    let build = container.fields.iter().map(|field| {
+        let member = &field.member;
+        let value = build_field(field);
+        quote! { #member: #value }
+    });
+
+    quote! {
+        const _: () = {
+            #[doc(hidden)]
+            #[derive(Default)]
+            pub struct Builder {
+                #(#builder_fields,)*
+            }
+
+            impl ::deserx::de::DeserializeXmlFields for #ident {
+                type Builder = Builder;
+
+                #[allow(unused_variables)]
+                fn deserialize_attribute(
+                    builder: &mut Self::Builder,
+                    name: &[u8],
+                    value: &str,
+                ) -> ::deserx::Result<bool> {
+                    match name {
+                        #(#attributes)*
+                        _ => {
+                            #(#flattened_attributes)*
+                            #unknown_attribute
+                        }
+                    }
+                }
+
+                #[allow(unused_variables)]
+                fn deserialize_child<R: ::std::io::BufRead>(
+                    builder: &mut Self::Builder,
+                    deserializer: &mut ::deserx::__private::quick_xml::Reader<R>,
+                    start: &::deserx::__private::quick_xml::events::BytesStart<'_>,
+                    empty: bool,
+                ) -> ::deserx::Result<bool> {
+                    // Elements in another namespace are never known children,
+                    // e.g. a vendor's `<v:name>`.
+                    let known = ::deserx::de::is_in_parent_namespace(start)?;
+                    match start.local_name().as_ref() {
+                        #(#children)*
+                        _ => {
+                            #(#flattened_children)*
+                            #unknown_child
+                        }
+                    }
+                }
+
+                #[allow(unused_variables)]
+                fn deserialize_text(builder: &mut Self::Builder, text: &str) -> bool {
+                    #text
+                }
+
+                #[allow(unused_variables)]
+                fn build(builder: Self::Builder) -> ::deserx::Result<Self> {
+                    ::std::result::Result::Ok(Self {
+                        #(#build,)*
+                    })
+                }
+            }
+
+            impl ::deserx::DeserializeXml for #ident {
+                fn deserialize_xml<R: ::std::io::BufRead>(
+                    deserializer: &mut ::deserx::__private::quick_xml::Reader<R>,
+                ) -> ::deserx::Result<Self> {
+                    ::deserx::de::deserialize_root(deserializer, #tag)
+                }
+
+                fn deserialize_xml_with_start<'de, R: ::std::io::BufRead>(
+                    deserializer: &mut ::deserx::__private::quick_xml::Reader<R>,
+                    start: &::deserx::__private::quick_xml::events::BytesStart<'de>,
+                ) -> ::deserx::Result<Self> {
+                    ::deserx::de::deserialize_fields(deserializer, start)
+                }
+
+                fn deserialize_xml_with_empty(
+                    start: &::deserx::__private::quick_xml::events::BytesStart<'_>,
+                ) -> ::deserx::Result<Self> {
+                    ::deserx::de::deserialize_empty_fields(start)
+                }
+            }
+        };
+    }
+}
+
+fn builder_field(field: &Field) -> Option<TokenStream> {
+    let builder = &field.builder;
+    let ty = &field.ty;
+    let inner = &field.inner;
+    let ty = match (field.kind, field.repetition) {
+        (Kind::Skip, _) => return None,
+        (Kind::Flatten, _) => quote! { <#ty as ::deserx::de::DeserializeXmlFields>::Builder },
+        (Kind::Extensions, _) => quote! { #ty },
+        (Kind::Text, _) => quote! { ::std::string::String },
+        (_, Repetition::Many) => quote! { ::std::vec::Vec<#inner> },
+        _ => quote! { ::std::option::Option<#inner> },
+    };
+    Some(quote! { #builder: #ty })
+}
+
+fn build_field(field: &Field) -> TokenStream {
+    let builder = &field.builder;
+    let ty = &field.ty;
+    let inner = &field.inner;
+    let name = &field.name;
+    match (field.kind, field.repetition) {
+        (Kind::Skip, _) => quote! { ::std::default::Default::default() },
+        (Kind::Flatten, _) => quote! {
+            <#ty as ::deserx::de::DeserializeXmlFields>::build(builder.#builder)?
+        },
+        (Kind::Extensions, _) => quote! { builder.#builder },
+        (Kind::Text, Repetition::Optional) => quote! {
+            if builder.#builder.is_empty() {
+                ::std::option::Option::None
+            } else {
+                ::std::option::Option::Some(
+                    <#inner as ::deserx::XmlValue>::from_xml_value(&builder.#builder)?,
+                )
+            }
+        },
+        (Kind::Text, _) => quote! {
+            <#inner as ::deserx::XmlValue>::from_xml_value(&builder.#builder)?
+        },
+        (_, Repetition::Many | Repetition::Optional) => quote! { builder.#builder },
+        (_, Repetition::One) if field.default => quote! {
+            builder.#builder.unwrap_or_default()
+        },
+        (Kind::Attribute, Repetition::One) => quote! {
+            builder.#builder.ok_or_else(|| ::deserx::Error::missing_attribute(#name))?
+        },
+        (_, Repetition::One) => quote! {
+            builder.#builder.ok_or_else(|| ::deserx::Error::missing_element(#name))?
+        },
+    }
+}
+
+fn byte_str(name: &str) -> LitByteStr {
+    LitByteStr::new(name.as_bytes(), Span::call_site())
+}
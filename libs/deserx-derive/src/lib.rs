@@ -0,0 +1,61 @@
+//! Derive macros for `deserx::DeserializeXml` and `deserx::SerializeXml`.
+//!
+//! ```ignore
+//! #[derive(DeserializeXml, SerializeXml)]
+//! #[xml(rename = "fact", order(date, value))]
+//! pub struct Fact {
+//!     #[xml(attribute, rename = "type")]
+//!     r#type: FactType,
+//!     #[xml(text_element, default, skip_serializing_if = "String::is_empty")]
+//!     value: String,
+//!     date: Option<Date>,
+//! }
+//! ```
+//!
+//! Container attributes:
+//! - `rename = "name"`: the element name, defaults to the struct name in camelCase.
+//! - `namespace = "uri"`: written as `xmlns` on the element.
+//! - `order(a, b, ..)`: the order of the child fields in XML, defaults to
+//!   declaration order.
+//!
+//! Field attributes:
+//! - `attribute`: an `XmlValue` written as an attribute.
+//! - `element` (the default): a `SerializeXml`/`DeserializeXml` child element.
+//! - `text_element`: an `XmlValue` written as the text of a child element.
+//! - `text`: an `XmlValue` written as the text of the element itself.
+//! - `flatten`: a type whose attributes and children are part of this element.
+//! - `skip`: not read or written, filled with `Default::default()`.
+//! - `extensions`: an `AsXmlExtensions` that keeps unknown attributes and child
+//!   elements, which would otherwise be ignored and an error respectively.
+//!   Unknown elements are written after the known children. Put it on the
+//!   outermost type, not on a type that is flattened.
+//! - `rename = "name"`: the attribute or element name, defaults to the field
+//!   name in camelCase.
+//! - `default`: use `Default::default()` if the field is missing.
+//! - `skip_serializing_if = "path"`: don't write the field if `path(&field)`.
+//!
+//! `Option<T>` fields are optional and `Vec<T>` fields are repeated elements.
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attr;
+mod de;
+mod ser;
+
+#[proc_macro_derive(DeserializeXml, attributes(xml))]
+pub fn derive_deserialize_xml(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    attr::Container::from_derive_input(&input)
+        .map(|container| de::expand(&container))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(SerializeXml, attributes(xml))]
+pub fn derive_serialize_xml(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    attr::Container::from_derive_input(&input)
+        .map(|container| ser::expand(&container))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,198 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::attr::{Container, Field, Kind, Repetition};
+
+pub(crate) fn expand(container: &Container) -> TokenStream {
+    let ident = &container.ident;
+    let tag = &container.tag;
+
+    let namespace = container.namespace.as_ref().map(|namespace| {
+        quote! { elem.push_attribute(("xmlns", #namespace)); }
+    });
+    let attributes = container
+        .fields
+        .iter()
+        .filter_map(|field| match field.kind {
+            Kind::Attribute => {
+                let name = &field.name;
+                Some(for_each_value(field, |value| {
+                    quote! {
+                        elem.push_attribute((
+                            #name,
+                            ::deserx::XmlValue::to_xml_value(#value).as_ref(),
+                        ));
+                    }
+                }))
+            }
+            Kind::Flatten => {
+                let member = &field.member;
+                Some(quote! {
+                    ::deserx::ser::SerializeXmlFields::serialize_attributes(&self.#member, elem);
+                })
+            }
+            _ => None,
+        });
+    let extension_attributes = container.extensions().map(|field| {
+        let member = &field.member;
+        quote! {
+            ::deserx::AsXmlExtensions::as_xml_extensions(&self.#member)
+                .serialize_attributes(elem);
+        }
+    });
+    let children = container
+        .children
+        .iter()
+        .map(|index| &container.fields[*index]);
+    let mut has_children = children.clone().map(has_child).collect::<Vec<_>>();
+    let mut serialize_children = children.map(serialize_child).collect::<Vec<_>>();
+    // Unknown elements are written after the known ones.
+    if let Some(field) = container.extensions() {
+        let member = &field.member;
+        has_children.push(quote! {
+            ::deserx::AsXmlExtensions::as_xml_extensions(&self.#member).has_elements()
+        });
+        serialize_children.push(quote! {
+            ::deserx::AsXmlExtensions::as_xml_extensions(&self.#member)
+                .serialize_elements(ser)?;
+        });
+    }
+    let has_children = if has_children.is_empty() {
+        quote! { false }
+    } else {
+        quote! { #(#has_children)||* }
+    };
+
+    quote! {
+        impl ::deserx::ser::SerializeXml for #ident {
+            fn tag(&self) -> &str {
+                #tag
+            }
+
+            fn serialize_xml_with_tag<W: ::std::io::Write>(
+                &self,
+                tag: &str,
+                ser: &mut ::deserx::ser::XmlSerializer<W>,
+            ) -> ::std::result::Result<(), ::deserx::ser::SerError> {
+                ::deserx::ser::serialize_fields(self, tag, ser)
+            }
+        }
+
+        impl ::deserx::ser::SerializeXmlFields for #ident {
+            #[allow(unused_variables)]
+            fn serialize_attributes(
+                &self,
+                elem: &mut ::deserx::__private::quick_xml::events::BytesStart<'_>,
+            ) {
+                #namespace
+                #(#attributes)*
+                #extension_attributes
+            }
+
+            fn has_children(&self) -> bool {
+                #has_children
+            }
+
+            #[allow(unused_variables)]
+            fn serialize_children<W: ::std::io::Write>(
+                &self,
+                ser: &mut ::deserx::ser::XmlSerializer<W>,
+            ) -> ::std::result::Result<(), ::deserx::ser::SerError> {
+                #(#serialize_children)*
+                ::std::result::Result::Ok(())
+            }
+        }
+    }
+}
+
+/// Runs `f` on a reference to each value of `field`, honouring
+/// `skip_serializing_if`.
+fn for_each_value(field: &Field, f: impl Fn(TokenStream) -> TokenStream) -> TokenStream {
+    let member = &field.member;
+    let body = match field.repetition {
+        Repetition::One => f(quote! { &self.#member }),
+        Repetition::Optional => {
+            let body = f(quote! { value });
+            quote! {
+                if let ::std::option::Option::Some(value) = &self.#member {
+                    #body
+                }
+            }
+        }
+        Repetition::Many => {
+            let body = f(quote! { value });
+            quote! {
+                for value in &self.#member {
+                    #body
+                }
+            }
+        }
+    };
+    match &field.skip_serializing_if {
+        Some(path) => quote! {
+            if !#path(&self.#member) {
+                #body
+            }
+        },
+        None => body,
+    }
+}
+
+fn has_child(field: &Field) -> TokenStream {
+    let member = &field.member;
+    let has_value = match (field.kind, field.repetition) {
+        (Kind::Flatten, _) => Some(quote! {
+            ::deserx::ser::SerializeXmlFields::has_children(&self.#member)
+        }),
+        (Kind::Text, Repetition::One) => Some(quote! {
+            !::deserx::XmlValue::to_xml_value(&self.#member).is_empty()
+        }),
+        (_, Repetition::One) => None,
+        (_, Repetition::Optional) => Some(quote! { self.#member.is_some() }),
+        (_, Repetition::Many) => Some(quote! { !self.#member.is_empty() }),
+    };
+    let skip = field
+        .skip_serializing_if
+        .as_ref()
+        .map(|path| quote! { !#path(&self.#member) });
+    match (skip, has_value) {
+        (Some(skip), Some(has_value)) => quote! { (#skip && #has_value) },
+        (Some(condition), None) | (None, Some(condition)) => condition,
+        (None, None) => quote! { true },
+    }
+}
+
+fn serialize_child(field: &Field) -> TokenStream {
+    let name = &field.name;
+    match field.kind {
+        Kind::Element => for_each_value(field, |value| {
+            quote! {
+                ::deserx::ser::SerializeXml::serialize_xml_with_tag(#value, #name, ser)?;
+            }
+        }),
+        Kind::TextElement => for_each_value(field, |value| {
+            quote! {
+                ::deserx::ser::write_text_element(
+                    ser,
+                    #name,
+                    &::deserx::XmlValue::to_xml_value(#value),
+                )?;
+            }
+        }),
+        Kind::Text => for_each_value(field, |value| {
+            quote! {
+                let text = ::deserx::XmlValue::to_xml_value(#value);
+                if !text.is_empty() {
+                    ::deserx::ser::write_text(ser, &text)?;
+                }
+            }
+        }),
+        Kind::Flatten => {
+            let member = &field.member;
+            quote! {
+                ::deserx::ser::SerializeXmlFields::serialize_children(&self.#member, ser)?;
+            }
+        }
+        Kind::Attribute | Kind::Skip | Kind::Extensions => TokenStream::new(),
+    }
+}
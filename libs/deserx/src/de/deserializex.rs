@@ -1,13 +1,304 @@
+use std::io::BufRead;
+
+use quick_xml::events::{BytesStart, Event};
+
+use super::namespaces::{attribute_prefix, element_prefix, missing_declarations};
+use crate::error::{Error, Result};
+use crate::value::XmlValue;
+
 pub trait DeserializeXml: Sized {
-    fn deserialize_xml<R: std::io::BufRead>(
-        _deserializer: &mut quick_xml::Reader<R>,
-    ) -> Result<Self, quick_xml::Error> {
-        todo!("return error")
+    fn deserialize_xml<R: BufRead>(deserializer: &mut quick_xml::Reader<R>) -> Result<Self> {
+        let mut buf = Vec::new();
+        loop {
+            match deserializer.read_event_into(&mut buf)? {
+                Event::Start(e) => return deserialize_element(deserializer, &e),
+                Event::Empty(e) => return deserialize_empty_element(deserializer, &e),
+                Event::Eof => return Err(Error::unexpected_eof()),
+                _ => {}
+            }
+        }
     }
-    fn deserialize_xml_with_start<'de, R: std::io::BufRead>(
+    fn deserialize_xml_with_start<'de, R: BufRead>(
         _deserializer: &mut quick_xml::Reader<R>,
-        _start: &quick_xml::events::BytesStart<'de>,
-    ) -> Result<Self, quick_xml::Error> {
-        todo!("return error")
+        _start: &BytesStart<'de>,
+    ) -> Result<Self> {
+        Err(Error::unsupported("deserializing from a start element"))
+    }
+    /// Deserializes from an element without content, e.g. `<evidence resource="#P-1"/>`.
+    fn deserialize_xml_with_empty(_start: &BytesStart<'_>) -> Result<Self> {
+        Err(Error::unsupported("deserializing from an empty element"))
+    }
+}
+
+/// Deserializes `T` from the element opened by `start`.
+///
+/// Any error is annotated with the element name and the current reader
+/// position, so that errors from nested elements carry their full path.
+pub fn deserialize_element<'de, T: DeserializeXml, R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+    start: &BytesStart<'de>,
+) -> Result<T> {
+    T::deserialize_xml_with_start(deserializer, start)
+        .map_err(|err| err.within(start.name()).at(deserializer.buffer_position()))
+}
+
+/// Deserializes `T` from the empty element `start`, see [`deserialize_element`].
+pub fn deserialize_empty_element<T: DeserializeXml, R: BufRead>(
+    deserializer: &quick_xml::Reader<R>,
+    start: &BytesStart<'_>,
+) -> Result<T> {
+    T::deserialize_xml_with_empty(start)
+        .map_err(|err| err.within(start.name()).at(deserializer.buffer_position()))
+}
+
+/// Deserializes `T` from the first element in the input, which must be named `tag`.
+pub fn deserialize_root<T: DeserializeXml, R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+    tag: &str,
+) -> Result<T> {
+    let mut buf = Vec::new();
+    loop {
+        match deserializer.read_event_into(&mut buf)? {
+            Event::Start(e) if e.local_name().as_ref() == tag.as_bytes() => {
+                return deserialize_element(deserializer, &e)
+            }
+            Event::Empty(e) if e.local_name().as_ref() == tag.as_bytes() => {
+                return deserialize_empty_element(deserializer, &e)
+            }
+            Event::Start(e) | Event::Empty(e) => {
+                return Err(Error::unexpected_element(e.name()).at(deserializer.buffer_position()))
+            }
+            Event::Eof => return Err(Error::unexpected_eof()),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the unescaped value of the attribute `name`, if present.
+pub fn attribute(start: &BytesStart<'_>, name: &str) -> Result<Option<String>> {
+    match start.try_get_attribute(name)? {
+        Some(attr) => Ok(Some(attr.unescape_value()?.into_owned())),
+        None => Ok(None),
+    }
+}
+
+/// Returns the unescaped value of the attribute `name`.
+pub fn required_attribute(start: &BytesStart<'_>, name: &str) -> Result<String> {
+    attribute(start, name)?.ok_or_else(|| Error::missing_attribute(name))
+}
+
+/// Parses the attribute `name` as a `xsd:boolean`, if present.
+pub fn bool_attribute(start: &BytesStart<'_>, name: &str) -> Result<Option<bool>> {
+    attribute(start, name)?
+        .map(|value| bool::from_xml_value(&value))
+        .transpose()
+}
+
+/// Reads the text content of the element `start`, consuming its end tag.
+pub fn read_text<R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+    start: &BytesStart<'_>,
+) -> Result<String> {
+    read_text_content(deserializer)
+        .map_err(|err| err.within(start.name()).at(deserializer.buffer_position()))
+}
+
+pub(crate) fn read_text_content<R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+) -> Result<String> {
+    let mut buf = Vec::new();
+    let mut text = String::new();
+    loop {
+        match deserializer.read_event_into(&mut buf)? {
+            Event::Text(e) => text.push_str(&e.unescape()?),
+            Event::CData(e) => text.push_str(&String::from_utf8_lossy(&e.into_inner())),
+            Event::End(_) => return Ok(text),
+            Event::Start(e) | Event::Empty(e) => return Err(Error::unexpected_element(e.name())),
+            Event::Eof => return Err(Error::unexpected_eof()),
+            _ => {}
+        }
+    }
+}
+
+/// Reads the content of the element `start` as markup, consuming its end tag.
+///
+/// Child elements and entities are kept as they are in the input, e.g. the
+/// XHTML in `<text><p>a &amp; b</p></text>` is read as `<p>a &amp; b</p>`.
+pub fn read_inner_xml<R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+    start: &BytesStart<'_>,
+) -> Result<String> {
+    read_inner_xml_content(deserializer)
+        .map_err(|err| err.within(start.name()).at(deserializer.buffer_position()))
+}
+
+/// Reads the element `start` as an XML fragment, including its start and end
+/// tags, e.g. to keep an element that is not known.
+///
+/// The namespace declarations in scope that the fragment uses are copied onto
+/// its start tag, so it keeps its meaning on its own.
+pub fn read_element_xml<R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+    start: &BytesStart<'_>,
+    empty: bool,
+) -> Result<String> {
+    let mut events = Vec::new();
+    if !empty {
+        read_inner_events(deserializer, &mut events)
+            .map_err(|err| err.within(start.name()).at(deserializer.buffer_position()))?;
+    }
+    let mut prefixes = Vec::new();
+    for element in std::iter::once(start).chain(events.iter().filter_map(|event| match event {
+        Event::Start(e) | Event::Empty(e) => Some(e),
+        _ => None,
+    })) {
+        add_prefixes(&mut prefixes, element)?;
+    }
+    let mut root = start.to_owned();
+    for (key, value) in missing_declarations(start, &prefixes)? {
+        root.push_attribute((key.as_str(), value.as_str()));
+    }
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    if empty {
+        writer.write_event(Event::Empty(root))?;
+    } else {
+        let end = root.to_end().into_owned();
+        writer.write_event(Event::Start(root))?;
+        for event in events {
+            writer.write_event(event)?;
+        }
+        writer.write_event(Event::End(end))?;
+    }
+    Ok(String::from_utf8_lossy(&writer.into_inner()).into_owned())
+}
+
+/// Adds the namespace prefixes used by the name and attributes of `element`.
+fn add_prefixes(prefixes: &mut Vec<Vec<u8>>, element: &BytesStart<'_>) -> Result<()> {
+    let mut add = |prefix: &[u8]| {
+        if !prefixes.iter().any(|known| known == prefix) {
+            prefixes.push(prefix.to_vec());
+        }
+    };
+    add(element_prefix(element.name()));
+    for attr in element.attributes() {
+        if let Some(prefix) = attribute_prefix(attr?.key) {
+            add(prefix);
+        }
+    }
+    Ok(())
+}
+
+/// Reads the events up to the end of the current element, consuming its end
+/// tag.
+fn read_inner_events<R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+    events: &mut Vec<Event<'static>>,
+) -> Result<()> {
+    let mut buf = Vec::new();
+    let mut depth = 0usize;
+    loop {
+        let event = deserializer.read_event_into(&mut buf)?;
+        match &event {
+            Event::Start(_) => depth += 1,
+            Event::End(_) if depth == 0 => break,
+            Event::End(_) => depth -= 1,
+            Event::Eof => return Err(Error::unexpected_eof()),
+            _ => {}
+        }
+        events.push(event.into_owned());
+        buf.clear();
+    }
+    Ok(())
+}
+
+fn read_inner_xml_content<R: BufRead>(deserializer: &mut quick_xml::Reader<R>) -> Result<String> {
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    write_inner_xml(deserializer, &mut writer)?;
+    Ok(String::from_utf8_lossy(&writer.into_inner()).into_owned())
+}
+
+fn write_inner_xml<R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+    writer: &mut quick_xml::Writer<Vec<u8>>,
+) -> Result<()> {
+    let mut buf = Vec::new();
+    let mut depth = 0usize;
+    loop {
+        let event = deserializer.read_event_into(&mut buf)?;
+        match &event {
+            Event::Start(_) => depth += 1,
+            Event::End(_) if depth == 0 => break,
+            Event::End(_) => depth -= 1,
+            Event::Eof => return Err(Error::unexpected_eof()),
+            _ => {}
+        }
+        writer.write_event(event)?;
+        buf.clear();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    struct Empty;
+
+    impl DeserializeXml for Empty {}
+
+    #[test]
+    fn default_methods_return_errors() {
+        let mut reader = quick_xml::Reader::from_str("<a></a>");
+        let err = Empty::deserialize_xml(&mut reader).err().unwrap();
+        assert!(matches!(err.kind(), ErrorKind::Unsupported(_)));
+        assert_eq!(err.path(), ["a"]);
+
+        let mut reader = quick_xml::Reader::from_str("<b/>");
+        let err = Empty::deserialize_xml(&mut reader).err().unwrap();
+        assert!(matches!(err.kind(), ErrorKind::Unsupported(_)));
+        assert_eq!(err.path(), ["b"]);
+
+        let mut reader = quick_xml::Reader::from_str("");
+        let err = Empty::deserialize_xml(&mut reader).err().unwrap();
+        assert!(matches!(err.kind(), ErrorKind::UnexpectedEof));
+    }
+
+    #[test]
+    fn bool_attribute_rejects_garbage() {
+        let start = BytesStart::from_content(r#"person extracted="yes""#, 6);
+        let err = bool_attribute(&start, "extracted").err().unwrap();
+        assert!(matches!(err.kind(), ErrorKind::InvalidBool(v) if v == "yes"));
+        assert_eq!(bool_attribute(&start, "other").unwrap(), None);
+    }
+
+    #[test]
+    fn read_text_stops_at_end() {
+        let mut reader = quick_xml::Reader::from_str("<a>x &amp; y</a><b/>");
+        let mut buf = Vec::new();
+        let start = match reader.read_event_into(&mut buf).unwrap() {
+            Event::Start(e) => e.into_owned(),
+            e => panic!("unexpected {:?}", e),
+        };
+        assert_eq!(read_text(&mut reader, &start).unwrap(), "x & y");
+    }
+
+    #[test]
+    fn read_inner_xml_keeps_markup() {
+        let xml = r#"<text><p class="x">a &amp; <b>b</b></p><br/></text><next/>"#;
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut buf = Vec::new();
+        let start = match reader.read_event_into(&mut buf).unwrap() {
+            Event::Start(e) => e.into_owned(),
+            e => panic!("unexpected {:?}", e),
+        };
+        assert_eq!(
+            read_inner_xml(&mut reader, &start).unwrap(),
+            r#"<p class="x">a &amp; <b>b</b></p><br/>"#
+        );
+        assert!(
+            matches!(reader.read_event_into(&mut buf).unwrap(), Event::Empty(e) if e.name().as_ref() == b"next")
+        );
     }
 }
@@ -0,0 +1,126 @@
+use std::io::BufRead;
+
+use quick_xml::events::{BytesStart, Event};
+
+use crate::de::deserializex::{
+    deserialize_element, deserialize_empty_element, read_text_content, DeserializeXml,
+};
+use crate::de::namespaces::enter_scope;
+use crate::error::{Error, Result};
+use crate::value::XmlValue;
+
+/// Deserializes the attributes, children and text of an element one at a
+/// time.
+///
+/// This is what `#[derive(DeserializeXml)]` implements; a type flattened into
+/// another with `#[xml(flatten)]` only needs this trait.
+pub trait DeserializeXmlFields: Sized {
+    /// The fields read so far.
+    type Builder: Default;
+
+    /// Reads the attribute `name`, returns `false` if it is not a field.
+    fn deserialize_attribute(builder: &mut Self::Builder, name: &[u8], value: &str)
+        -> Result<bool>;
+
+    /// Reads the child element `start`, returns `false` if it is not a field.
+    fn deserialize_child<R: BufRead>(
+        builder: &mut Self::Builder,
+        deserializer: &mut quick_xml::Reader<R>,
+        start: &BytesStart<'_>,
+        empty: bool,
+    ) -> Result<bool>;
+
+    /// Reads text content, returns `false` if the element has no text field.
+    fn deserialize_text(_builder: &mut Self::Builder, _text: &str) -> bool {
+        false
+    }
+
+    /// Checks that all required fields were read.
+    fn build(builder: Self::Builder) -> Result<Self>;
+}
+
+/// Deserializes `T` field by field from the element opened by `start`,
+/// consuming its end tag.
+///
+/// Unknown attributes are ignored, unknown child elements are an error.
+pub fn deserialize_fields<T: DeserializeXmlFields, R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+    start: &BytesStart<'_>,
+) -> Result<T> {
+    let mut builder = deserialize_attributes::<T>(start)?;
+    let _scope = enter_scope(start)?;
+    let mut buf = Vec::new();
+    loop {
+        match deserializer.read_event_into(&mut buf)? {
+            Event::Start(e) => deserialize_field::<T, R>(&mut builder, deserializer, &e, false)?,
+            Event::Empty(e) => deserialize_field::<T, R>(&mut builder, deserializer, &e, true)?,
+            Event::Text(e) => {
+                T::deserialize_text(&mut builder, &e.unescape()?);
+            }
+            Event::CData(e) => {
+                T::deserialize_text(&mut builder, &String::from_utf8_lossy(&e.into_inner()));
+            }
+            Event::End(_) => break,
+            Event::Eof => return Err(Error::unexpected_eof()),
+            _ => {}
+        }
+        buf.clear();
+    }
+    T::build(builder)
+}
+
+fn deserialize_field<T: DeserializeXmlFields, R: BufRead>(
+    builder: &mut T::Builder,
+    deserializer: &mut quick_xml::Reader<R>,
+    start: &BytesStart<'_>,
+    empty: bool,
+) -> Result<()> {
+    if T::deserialize_child(builder, deserializer, start, empty)? {
+        Ok(())
+    } else {
+        Err(Error::unexpected_element(start.name()))
+    }
+}
+
+/// Deserializes `T` field by field from the empty element `start`.
+pub fn deserialize_empty_fields<T: DeserializeXmlFields>(start: &BytesStart<'_>) -> Result<T> {
+    T::build(deserialize_attributes::<T>(start)?)
+}
+
+fn deserialize_attributes<T: DeserializeXmlFields>(start: &BytesStart<'_>) -> Result<T::Builder> {
+    let mut builder = T::Builder::default();
+    for attr in start.attributes() {
+        let attr = attr?;
+        let value = attr.unescape_value()?;
+        T::deserialize_attribute(&mut builder, attr.key.as_ref(), &value)?;
+    }
+    Ok(builder)
+}
+
+/// Deserializes the child element `start`, which may be empty.
+pub fn deserialize_child<T: DeserializeXml, R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+    start: &BytesStart<'_>,
+    empty: bool,
+) -> Result<T> {
+    if empty {
+        deserialize_empty_element(deserializer, start)
+    } else {
+        deserialize_element(deserializer, start)
+    }
+}
+
+/// Parses the text content of the child element `start`, e.g. `<created>`.
+pub fn deserialize_value<T: XmlValue, R: BufRead>(
+    deserializer: &mut quick_xml::Reader<R>,
+    start: &BytesStart<'_>,
+    empty: bool,
+) -> Result<T> {
+    let text = if empty {
+        Ok(String::new())
+    } else {
+        read_text_content(deserializer)
+    };
+    text.and_then(|text| T::from_xml_value(&text))
+        .map_err(|err| err.within(start.name()).at(deserializer.buffer_position()))
+}
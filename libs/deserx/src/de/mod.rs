@@ -1,4 +1,14 @@
 mod deserializerx;
 mod deserializex;
+mod fields;
+mod namespaces;
 
-pub use deserializex::DeserializeXml;
+pub use deserializex::{
+    attribute, bool_attribute, deserialize_element, deserialize_empty_element, deserialize_root,
+    read_element_xml, read_inner_xml, read_text, required_attribute, DeserializeXml,
+};
+pub use fields::{
+    deserialize_child, deserialize_empty_fields, deserialize_fields, deserialize_value,
+    DeserializeXmlFields,
+};
+pub use namespaces::{enter_scope, is_in_parent_namespace, NamespaceScope};
@@ -0,0 +1,188 @@
+//! The namespace declarations in scope while deserializing.
+//!
+//! `quick_xml::Reader` does not resolve namespaces, so the declarations of
+//! the elements being read are kept on a stack, see [`enter_scope`].
+
+use std::cell::RefCell;
+
+use quick_xml::events::BytesStart;
+use quick_xml::name::QName;
+
+use crate::error::Result;
+
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+struct Scope {
+    /// Prefixes, empty for the default namespace, and their namespaces, empty
+    /// if undeclared.
+    bindings: Vec<(Vec<u8>, String)>,
+    /// The namespace of the element itself.
+    namespace: Option<String>,
+}
+
+thread_local! {
+    static SCOPES: RefCell<Vec<Scope>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The namespace declarations of an element, in scope until dropped.
+#[must_use]
+pub struct NamespaceScope {
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl Drop for NamespaceScope {
+    fn drop(&mut self) {
+        SCOPES.with(|scopes| scopes.borrow_mut().pop());
+    }
+}
+
+/// Puts the namespace declarations of the element `start` in scope for its
+/// children, until the returned guard is dropped.
+///
+/// [`deserialize_fields`](crate::de::deserialize_fields) does this for every
+/// element it reads; a reader that reads the children of an element itself,
+/// e.g. the items of a document one at a time, must do it for that element.
+pub fn enter_scope(start: &BytesStart<'_>) -> Result<NamespaceScope> {
+    let bindings = declarations(start)?;
+    let namespace = resolve(&bindings, element_prefix(start.name()));
+    SCOPES.with(|scopes| {
+        scopes.borrow_mut().push(Scope {
+            bindings,
+            namespace,
+        })
+    });
+    Ok(NamespaceScope {
+        _not_send: std::marker::PhantomData,
+    })
+}
+
+/// Whether the element `start` is in the namespace of the element in scope,
+/// or in no namespace, i.e. whether it can be one of its known children.
+pub fn is_in_parent_namespace(start: &BytesStart<'_>) -> Result<bool> {
+    let namespace = resolve(&declarations(start)?, element_prefix(start.name()));
+    Ok(namespace.is_none() || namespace == parent_namespace())
+}
+
+/// The namespace of the innermost element in scope.
+fn parent_namespace() -> Option<String> {
+    SCOPES.with(|scopes| {
+        scopes
+            .borrow()
+            .last()
+            .and_then(|scope| scope.namespace.clone())
+    })
+}
+
+/// The declarations an XML fragment that starts with `start` needs to keep
+/// its meaning outside of the current scope, for `prefixes` used in it.
+///
+/// Prefixes declared by `start` itself are left out. The default namespace
+/// is only declared if it differs from the namespace of the element in
+/// scope, since the fragment is written back as one of its children.
+pub(crate) fn missing_declarations(
+    start: &BytesStart<'_>,
+    prefixes: &[Vec<u8>],
+) -> Result<Vec<(String, String)>> {
+    let declared = declarations(start)?;
+    let mut missing = Vec::new();
+    for prefix in prefixes {
+        if declared.iter().any(|(declared, _)| declared == prefix) {
+            continue;
+        }
+        if prefix.is_empty() {
+            let namespace = lookup(prefix);
+            if namespace != parent_namespace() {
+                missing.push(("xmlns".into(), namespace.unwrap_or_default()));
+            }
+        } else if let Some(namespace) = lookup(prefix) {
+            let prefix = String::from_utf8_lossy(prefix);
+            missing.push((format!("xmlns:{}", prefix), namespace));
+        }
+    }
+    Ok(missing)
+}
+
+/// The prefix of an element name, empty for the default namespace.
+pub(crate) fn element_prefix(name: QName<'_>) -> &[u8] {
+    name.prefix().map_or(&[][..], |prefix| prefix.into_inner())
+}
+
+/// The prefix of an attribute name, if it is in a namespace.
+pub(crate) fn attribute_prefix(name: QName<'_>) -> Option<&[u8]> {
+    match name.prefix() {
+        Some(prefix) if !matches!(prefix.as_ref(), b"xmlns" | b"xml") => Some(prefix.into_inner()),
+        _ => None,
+    }
+}
+
+fn declarations(start: &BytesStart<'_>) -> Result<Vec<(Vec<u8>, String)>> {
+    let mut bindings = Vec::new();
+    for attr in start.attributes() {
+        let attr = attr?;
+        let prefix = match attr.key.as_ref() {
+            b"xmlns" => &[][..],
+            key => match key.strip_prefix(b"xmlns:") {
+                Some(prefix) => prefix,
+                None => continue,
+            },
+        };
+        bindings.push((prefix.to_vec(), attr.unescape_value()?.into_owned()));
+    }
+    Ok(bindings)
+}
+
+/// The namespace of `prefix` with `bindings` in scope innermost.
+fn resolve(bindings: &[(Vec<u8>, String)], prefix: &[u8]) -> Option<String> {
+    match bindings.iter().rev().find(|(bound, _)| bound == prefix) {
+        Some((_, namespace)) if namespace.is_empty() => None,
+        Some((_, namespace)) => Some(namespace.clone()),
+        None => lookup(prefix),
+    }
+}
+
+/// The namespace of `prefix` in the current scope.
+fn lookup(prefix: &[u8]) -> Option<String> {
+    if prefix == b"xml" {
+        return Some(XML_NAMESPACE.into());
+    }
+    SCOPES.with(|scopes| {
+        let scopes = scopes.borrow();
+        let found = scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.bindings.iter().rev())
+            .find(|(bound, _)| bound == prefix);
+        match found {
+            Some((_, namespace)) if !namespace.is_empty() => Some(namespace.clone()),
+            _ => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn children_are_resolved_in_scope() {
+        let root = BytesStart::from_content(r#"doc xmlns="urn:a" xmlns:v="urn:v""#, 3);
+        let _scope = enter_scope(&root).unwrap();
+        let known = BytesStart::new("name");
+        let vendor = BytesStart::new("v:name");
+        let redeclared = BytesStart::from_content(r#"v:name xmlns:v="urn:a""#, 6);
+        let unqualified = BytesStart::from_content(r#"name xmlns="""#, 4);
+        assert!(is_in_parent_namespace(&known).unwrap());
+        assert!(!is_in_parent_namespace(&vendor).unwrap());
+        assert!(is_in_parent_namespace(&redeclared).unwrap());
+        assert!(is_in_parent_namespace(&unqualified).unwrap());
+
+        {
+            let child = BytesStart::from_content(r#"v:thing xmlns:w="urn:w""#, 7);
+            let _scope = enter_scope(&child).unwrap();
+            assert_eq!(lookup(b"w").as_deref(), Some("urn:w"));
+            assert_eq!(parent_namespace().as_deref(), Some("urn:v"));
+        }
+        assert_eq!(lookup(b"w"), None);
+        assert_eq!(parent_namespace().as_deref(), Some("urn:a"));
+    }
+}
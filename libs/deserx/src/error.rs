@@ -0,0 +1,225 @@
+use std::{error::Error as StdError, fmt};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The kind of failure that stopped deserialization.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The underlying XML reader failed.
+    Xml(quick_xml::Error),
+    /// The document ended before the current element was closed.
+    UnexpectedEof,
+    /// A required attribute was not present on the element.
+    MissingAttribute(String),
+    /// A required child element was not present.
+    MissingElement(String),
+    /// A child element that the current element does not allow.
+    UnexpectedElement(String),
+    /// An attribute value that is not a valid IRI.
+    InvalidIri { value: String, reason: String },
+    /// An attribute value that is not a valid boolean.
+    InvalidBool(String),
+    /// A text value that is not a valid date.
+    InvalidDate { value: String, reason: String },
+    /// Any other value that could not be parsed.
+    InvalidValue { value: String, reason: String },
+    /// The type does not support deserializing in this way.
+    Unsupported(&'static str),
+}
+
+/// Error returned when deserializing XML.
+///
+/// Besides the [`ErrorKind`], the error records the path of elements leading
+/// to the failure, e.g. `gedcomx/person/fact`, and the byte offset in the input
+/// where the reader stopped.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    path: Vec<String>,
+    position: Option<usize>,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            path: Vec::new(),
+            position: None,
+        }
+    }
+
+    pub fn missing_attribute<S: Into<String>>(name: S) -> Self {
+        Self::new(ErrorKind::MissingAttribute(name.into()))
+    }
+
+    pub fn missing_element<S: Into<String>>(name: S) -> Self {
+        Self::new(ErrorKind::MissingElement(name.into()))
+    }
+
+    pub fn unexpected_element<S: AsRef<[u8]>>(name: S) -> Self {
+        Self::new(ErrorKind::UnexpectedElement(
+            String::from_utf8_lossy(name.as_ref()).into_owned(),
+        ))
+    }
+
+    pub fn unexpected_eof() -> Self {
+        Self::new(ErrorKind::UnexpectedEof)
+    }
+
+    pub fn invalid_iri<S: Into<String>>(value: S, reason: impl fmt::Display) -> Self {
+        Self::new(ErrorKind::InvalidIri {
+            value: value.into(),
+            reason: reason.to_string(),
+        })
+    }
+
+    pub fn invalid_bool<S: Into<String>>(value: S) -> Self {
+        Self::new(ErrorKind::InvalidBool(value.into()))
+    }
+
+    pub fn invalid_date<S: Into<String>>(value: S, reason: impl fmt::Display) -> Self {
+        Self::new(ErrorKind::InvalidDate {
+            value: value.into(),
+            reason: reason.to_string(),
+        })
+    }
+
+    pub fn invalid_value<S: Into<String>>(value: S, reason: impl fmt::Display) -> Self {
+        Self::new(ErrorKind::InvalidValue {
+            value: value.into(),
+            reason: reason.to_string(),
+        })
+    }
+
+    pub fn unsupported(what: &'static str) -> Self {
+        Self::new(ErrorKind::Unsupported(what))
+    }
+
+    /// Records that the error happened inside the element `name`.
+    pub fn within<S: AsRef<[u8]>>(mut self, name: S) -> Self {
+        self.path
+            .insert(0, String::from_utf8_lossy(name.as_ref()).into_owned());
+        self
+    }
+
+    /// Sets the byte offset of the error, unless it is already set.
+    pub fn at(mut self, position: usize) -> Self {
+        self.position.get_or_insert(position);
+        self
+    }
+}
+
+impl Error {
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// The elements enclosing the failure, outermost first.
+    pub fn path(&self) -> &[String] {
+        self.path.as_slice()
+    }
+
+    /// Byte offset into the input where the reader stopped.
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Line and column (both 1-based) of the error in `input`, the text
+    /// that was deserialized.
+    pub fn line_column(&self, input: &[u8]) -> Option<(usize, usize)> {
+        let position = self.position?.min(input.len());
+        let before = &input[..position];
+        let line = before.iter().filter(|b| **b == b'\n').count() + 1;
+        let column = match before.iter().rposition(|b| *b == b'\n') {
+            Some(newline) => position - newline,
+            None => position + 1,
+        };
+        Some((line, column))
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Xml(err) => write!(f, "XML error: {}", err),
+            Self::UnexpectedEof => write!(f, "unexpected end of input"),
+            Self::MissingAttribute(name) => write!(f, "missing attribute '{}'", name),
+            Self::MissingElement(name) => write!(f, "missing element '{}'", name),
+            Self::UnexpectedElement(name) => write!(f, "unexpected element '{}'", name),
+            Self::InvalidIri { value, reason } => {
+                write!(f, "invalid IRI '{}': {}", value, reason)
+            }
+            Self::InvalidBool(value) => write!(f, "invalid boolean '{}'", value),
+            Self::InvalidDate { value, reason } => {
+                write!(f, "invalid date '{}': {}", value, reason)
+            }
+            Self::InvalidValue { value, reason } => {
+                write!(f, "invalid value '{}': {}", value, reason)
+            }
+            Self::Unsupported(what) => write!(f, "unsupported: {}", what),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if !self.path.is_empty() {
+            write!(f, " in '{}'", self.path.join("/"))?;
+        }
+        if let Some(position) = self.position {
+            write!(f, " at byte {}", position)?;
+        }
+        Ok(())
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.kind {
+            ErrorKind::Xml(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<quick_xml::Error> for Error {
+    fn from(err: quick_xml::Error) -> Self {
+        Self::new(ErrorKind::Xml(err))
+    }
+}
+
+impl From<quick_xml::events::attributes::AttrError> for Error {
+    fn from(err: quick_xml::events::attributes::AttrError) -> Self {
+        Self::new(ErrorKind::Xml(err.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_prepends_path_and_keeps_innermost_position() {
+        let err = Error::missing_attribute("id")
+            .within("person")
+            .at(40)
+            .within("gedcomx")
+            .at(50);
+        assert_eq!(err.path(), ["gedcomx", "person"]);
+        assert_eq!(err.position(), Some(40));
+        assert_eq!(
+            err.to_string(),
+            "missing attribute 'id' in 'gedcomx/person' at byte 40"
+        );
+    }
+
+    #[test]
+    fn line_column_counts_from_one() {
+        let input = b"<a>\n  <b>\n</a>";
+        let err = Error::unexpected_element("b").at(8);
+        assert_eq!(err.line_column(input), Some((2, 5)));
+        assert_eq!(Error::unexpected_eof().line_column(input), None);
+    }
+}
@@ -0,0 +1,82 @@
+use std::io;
+
+use quick_xml::events::{BytesStart, BytesText, Event};
+
+use crate::ser::{SerError, XmlSerializer};
+
+/// The attributes and child elements of an element that its type does not
+/// know, e.g. vendor extensions, kept so that they are written back.
+///
+/// Attributes are kept as name and unescaped value, elements as the XML
+/// fragment they were read from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct XmlExtensions {
+    attributes: Vec<(String, String)>,
+    elements: Vec<String>,
+}
+
+impl XmlExtensions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_attribute<N: Into<String>, V: Into<String>>(&mut self, name: N, value: V) {
+        self.attributes.push((name.into(), value.into()));
+    }
+    /// Adds a child element, `xml` must be a well-formed fragment.
+    pub fn add_element<S: Into<String>>(&mut self, xml: S) {
+        self.elements.push(xml.into());
+    }
+
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attributes
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+    pub fn get_attribute(&self, name: &str) -> Option<&str> {
+        self.attributes()
+            .find(|(attribute, _)| *attribute == name)
+            .map(|(_, value)| value)
+    }
+    pub fn elements(&self) -> &[String] {
+        self.elements.as_slice()
+    }
+    pub fn has_elements(&self) -> bool {
+        !self.elements.is_empty()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.elements.is_empty()
+    }
+
+    pub fn serialize_attributes(&self, elem: &mut BytesStart<'_>) {
+        for (name, value) in self.attributes() {
+            elem.push_attribute((name, value));
+        }
+    }
+
+    pub fn serialize_elements<W: io::Write>(
+        &self,
+        ser: &mut XmlSerializer<W>,
+    ) -> Result<(), SerError> {
+        for element in &self.elements {
+            ser.write_event(Event::Text(BytesText::from_escaped(element.as_str())))?;
+        }
+        Ok(())
+    }
+}
+
+/// A field marked `#[xml(extensions)]`, which holds [`XmlExtensions`] and
+/// possibly more, e.g. the unknown fields of another format.
+pub trait AsXmlExtensions: Default {
+    fn as_xml_extensions(&self) -> &XmlExtensions;
+    fn as_xml_extensions_mut(&mut self) -> &mut XmlExtensions;
+}
+
+impl AsXmlExtensions for XmlExtensions {
+    fn as_xml_extensions(&self) -> &XmlExtensions {
+        self
+    }
+    fn as_xml_extensions_mut(&mut self) -> &mut XmlExtensions {
+        self
+    }
+}
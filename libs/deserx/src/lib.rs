@@ -1,5 +1,19 @@
 pub mod de;
 mod error;
+mod extensions;
 pub mod ser;
+mod value;
 
 pub use de::DeserializeXml;
+pub use error::{Error, ErrorKind, Result};
+pub use extensions::{AsXmlExtensions, XmlExtensions};
+pub use ser::SerializeXml;
+pub use value::XmlValue;
+
+#[cfg(feature = "derive")]
+pub use deserx_derive::{DeserializeXml, SerializeXml};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use quick_xml;
+}
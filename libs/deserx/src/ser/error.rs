@@ -9,8 +9,8 @@ pub enum SerError {
 impl fmt::Display for SerError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::IoError(_) => write!(f, "I/O error"),
-            Self::XmlError(_) => write!(f, "XML error"),
+            Self::IoError(err) => write!(f, "I/O error: {}", err),
+            Self::XmlError(err) => write!(f, "XML error: {}", err),
         }
     }
 }
@@ -0,0 +1,10 @@
+mod error;
+mod serialize_xml;
+
+pub use error::SerError;
+pub use serialize_xml::{
+    serialize_fields, write_raw_text_element, write_text, write_text_element, SerializeXml,
+    SerializeXmlFields,
+};
+
+pub use quick_xml::Writer as XmlSerializer;
@@ -0,0 +1,144 @@
+use std::io;
+
+use quick_xml::escape::partial_escape;
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+
+use crate::ser::{SerError, XmlSerializer};
+
+pub trait SerializeXml {
+    fn tag(&self) -> &str;
+    fn serialize_xml<W: io::Write>(&self, ser: &mut XmlSerializer<W>) -> Result<(), SerError> {
+        self.serialize_xml_with_tag(self.tag(), ser)
+    }
+    /// Serializes as the element `tag`, e.g. a `ResourceReference` as `<analysis>`.
+    fn serialize_xml_with_tag<W: io::Write>(
+        &self,
+        tag: &str,
+        ser: &mut XmlSerializer<W>,
+    ) -> Result<(), SerError>;
+}
+
+impl<T> SerializeXml for Option<T>
+where
+    T: SerializeXml,
+{
+    fn tag(&self) -> &str {
+        match self {
+            None => "",
+            Some(t) => t.tag(),
+        }
+    }
+    fn serialize_xml_with_tag<W: io::Write>(
+        &self,
+        tag: &str,
+        ser: &mut XmlSerializer<W>,
+    ) -> Result<(), SerError> {
+        match self {
+            None => Ok(()),
+            Some(t) => t.serialize_xml_with_tag(tag, ser),
+        }
+    }
+}
+
+/// Serializes the attributes and the content of an element separately.
+///
+/// This is what `#[derive(SerializeXml)]` implements; a type flattened into
+/// another with `#[xml(flatten)]` only needs this trait.
+pub trait SerializeXmlFields {
+    fn serialize_attributes(&self, elem: &mut BytesStart<'_>);
+    /// Whether [`serialize_children`](Self::serialize_children) writes anything.
+    fn has_children(&self) -> bool;
+    fn serialize_children<W: io::Write>(&self, ser: &mut XmlSerializer<W>) -> Result<(), SerError>;
+}
+
+/// Serializes `value` as the element `tag`, written as an empty element if
+/// it has no children.
+pub fn serialize_fields<T: SerializeXmlFields + ?Sized, W: io::Write>(
+    value: &T,
+    tag: &str,
+    ser: &mut XmlSerializer<W>,
+) -> Result<(), SerError> {
+    let mut elem = BytesStart::new(tag);
+    value.serialize_attributes(&mut elem);
+    if value.has_children() {
+        ser.write_event(Event::Start(elem))?;
+        value.serialize_children(ser)?;
+        ser.write_event(Event::End(BytesEnd::new(tag)))?;
+    } else {
+        ser.write_event(Event::Empty(elem))?;
+    }
+    Ok(())
+}
+
+/// Writes `text` as character data, escaping only `<`, `>` and `&`.
+pub fn write_text<W: io::Write>(ser: &mut XmlSerializer<W>, text: &str) -> Result<(), SerError> {
+    ser.write_event(Event::Text(BytesText::from_escaped(partial_escape(text))))?;
+    Ok(())
+}
+
+/// Writes `<tag>text</tag>`.
+pub fn write_text_element<W: io::Write>(
+    ser: &mut XmlSerializer<W>,
+    tag: &str,
+    text: &str,
+) -> Result<(), SerError> {
+    ser.write_event(Event::Start(BytesStart::new(tag)))?;
+    write_text(ser, text)?;
+    ser.write_event(Event::End(BytesEnd::new(tag)))?;
+    Ok(())
+}
+
+/// Writes `<tag>markup</tag>` with `markup` as it is, e.g. XHTML.
+///
+/// Fails without writing anything if `markup` is not a well-formed XML
+/// fragment, e.g. `a < b`.
+pub fn write_raw_text_element<W: io::Write>(
+    ser: &mut XmlSerializer<W>,
+    tag: &str,
+    markup: &str,
+) -> Result<(), SerError> {
+    check_well_formed(markup)?;
+    ser.write_event(Event::Start(BytesStart::new(tag)))?;
+    ser.write_event(Event::Text(BytesText::from_escaped(markup)))?;
+    ser.write_event(Event::End(BytesEnd::new(tag)))?;
+    Ok(())
+}
+
+/// Reads `markup` as an XML fragment: balanced elements, valid attributes
+/// and entities, but any number of top-level elements and text.
+fn check_well_formed(markup: &str) -> Result<(), quick_xml::Error> {
+    let mut reader = quick_xml::Reader::from_str(markup);
+    let mut depth = 0usize;
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) | Event::Empty(e) if e.name().as_ref().is_empty() => {
+                return Err(quick_xml::Error::UnexpectedToken("<".into()));
+            }
+            Event::Start(e) => {
+                check_attributes(&e)?;
+                depth += 1;
+            }
+            Event::Empty(e) => check_attributes(&e)?,
+            Event::End(e) if depth == 0 => {
+                return Err(quick_xml::Error::EndEventMismatch {
+                    expected: String::new(),
+                    found: String::from_utf8_lossy(e.name().as_ref()).into_owned(),
+                });
+            }
+            Event::End(_) => depth -= 1,
+            Event::Text(e) => {
+                e.unescape()?;
+            }
+            Event::Eof if depth == 0 => return Ok(()),
+            Event::Eof => return Err(quick_xml::Error::UnexpectedEof("element".into())),
+            _ => {}
+        }
+    }
+}
+
+fn check_attributes(start: &BytesStart<'_>) -> Result<(), quick_xml::Error> {
+    for attr in start.attributes() {
+        attr?.unescape_value()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use std::borrow::Cow;
+
+use crate::error::{Error, Result};
+
+/// A value that is written as the text of an attribute or an element,
+/// e.g. the `id` in `<person id="P-1">` or the text in `<value>Farmer</value>`.
+pub trait XmlValue: Sized {
+    fn from_xml_value(value: &str) -> Result<Self>;
+    fn to_xml_value(&self) -> Cow<'_, str>;
+}
+
+impl XmlValue for String {
+    fn from_xml_value(value: &str) -> Result<Self> {
+        Ok(value.to_owned())
+    }
+
+    fn to_xml_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
+}
+
+/// Parses a `xsd:boolean`.
+impl XmlValue for bool {
+    fn from_xml_value(value: &str) -> Result<Self> {
+        match value {
+            "true" | "1" => Ok(true),
+            "false" | "0" => Ok(false),
+            _ => Err(Error::invalid_bool(value)),
+        }
+    }
+
+    fn to_xml_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(if *self { "true" } else { "false" })
+    }
+}
+
+macro_rules! impl_xml_value_from_str {
+    ($($ty:ty),*) => {
+        $(
+            impl XmlValue for $ty {
+                fn from_xml_value(value: &str) -> Result<Self> {
+                    value
+                        .trim()
+                        .parse()
+                        .map_err(|err| Error::invalid_value(value, err))
+                }
+
+                fn to_xml_value(&self) -> Cow<'_, str> {
+                    Cow::Owned(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_xml_value_from_str!(i32, i64, u32, u64, usize, f32, f64);
+
+#[cfg(feature = "oxiri")]
+impl XmlValue for oxiri::IriRef<String> {
+    fn from_xml_value(value: &str) -> Result<Self> {
+        oxiri::IriRef::parse(value.to_owned()).map_err(|err| Error::invalid_iri(value, err))
+    }
+
+    fn to_xml_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
+}
+
+#[cfg(feature = "oxiri")]
+impl XmlValue for oxiri::Iri<String> {
+    fn from_xml_value(value: &str) -> Result<Self> {
+        oxiri::Iri::parse(value.to_owned()).map_err(|err| Error::invalid_iri(value, err))
+    }
+
+    fn to_xml_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
+}
+
+/// Reads and writes a `xsd:dateTime`, e.g. `2014-03-07T07:00:00+00:00`.
+#[cfg(feature = "chrono")]
+impl XmlValue for chrono::DateTime<chrono::Utc> {
+    fn from_xml_value(value: &str) -> Result<Self> {
+        value
+            .trim()
+            .parse()
+            .map_err(|err| Error::invalid_date(value, err))
+    }
+
+    fn to_xml_value(&self) -> Cow<'_, str> {
+        Cow::Owned(self.to_rfc3339())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn bool_round_trips_and_rejects_garbage() {
+        assert!(bool::from_xml_value("1").unwrap());
+        assert_eq!(false.to_xml_value(), "false");
+        let err = bool::from_xml_value("yes").unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::InvalidBool(v) if v == "yes"));
+    }
+
+    #[test]
+    fn numbers_report_invalid_values() {
+        assert_eq!(i32::from_xml_value(" 42 ").unwrap(), 42);
+        let err = f64::from_xml_value("north").unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::InvalidValue { value, .. } if value == "north"));
+    }
+}
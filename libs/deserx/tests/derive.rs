@@ -0,0 +1,214 @@
+use deserx::ErrorKind;
+use deserx_derive::{DeserializeXml, SerializeXml};
+
+#[derive(Debug, Default, PartialEq, DeserializeXml, SerializeXml)]
+struct Common {
+    #[xml(attribute, default, skip_serializing_if = "is_false")]
+    extracted: bool,
+    #[xml(rename = "note")]
+    notes: Vec<Note>,
+}
+
+fn is_false(b: &bool) -> bool {
+    !b
+}
+
+#[derive(Debug, PartialEq, DeserializeXml, SerializeXml)]
+struct Note {
+    #[xml(attribute, rename = "xml:lang")]
+    lang: Option<String>,
+    #[xml(text)]
+    text: String,
+}
+
+#[derive(Debug, PartialEq, DeserializeXml, SerializeXml)]
+#[xml(
+    rename = "record",
+    namespace = "urn:test",
+    order(common, place, values)
+)]
+struct Record {
+    #[xml(flatten)]
+    common: Common,
+    #[xml(text_element, rename = "value")]
+    values: Vec<u32>,
+    place: Option<Place>,
+    #[xml(attribute)]
+    id: String,
+    #[xml(skip)]
+    cache: Option<String>,
+}
+
+#[derive(Debug, PartialEq, DeserializeXml, SerializeXml)]
+struct Place(
+    #[xml(
+        text_element,
+        rename = "original",
+        default,
+        skip_serializing_if = "String::is_empty"
+    )]
+    String,
+);
+
+fn to_xml<T: deserx::SerializeXml>(value: &T) -> String {
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    value.serialize_xml(&mut writer).unwrap();
+    String::from_utf8(writer.into_inner()).unwrap()
+}
+
+fn from_xml<T: deserx::DeserializeXml>(xml: &str) -> deserx::Result<T> {
+    T::deserialize_xml(&mut quick_xml::Reader::from_str(xml))
+}
+
+#[test]
+fn round_trips_children_in_given_order() {
+    let record = Record {
+        common: Common {
+            extracted: true,
+            notes: vec![Note {
+                lang: Some("en".into()),
+                text: "a < b".into(),
+            }],
+        },
+        values: vec![1, 2],
+        place: Some(Place(String::new())),
+        id: "R-1".into(),
+        cache: None,
+    };
+    let xml = to_xml(&record);
+    assert_eq!(
+        xml,
+        r#"<record xmlns="urn:test" extracted="true" id="R-1"><note xml:lang="en">a &lt; b</note><place/><value>1</value><value>2</value></record>"#
+    );
+    assert_eq!(from_xml::<Record>(&xml).unwrap(), record);
+}
+
+#[test]
+fn missing_and_unknown_fields_are_errors() {
+    let err = from_xml::<Record>("<record><place><original/></place></record>").unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::MissingAttribute(name) if name == "id"));
+    assert_eq!(err.path(), ["record"]);
+
+    let err = from_xml::<Record>(r#"<record id="R-1"><value>x</value></record>"#).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidValue { value, .. } if value == "x"));
+    assert_eq!(err.path(), ["record", "value"]);
+
+    let err = from_xml::<Record>(r#"<record id="R-1"><date/></record>"#).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UnexpectedElement(name) if name == "date"));
+
+    let err = from_xml::<Record>(r#"<other id="R-1"/>"#).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UnexpectedElement(name) if name == "other"));
+}
+
+#[test]
+fn repeated_single_fields_are_errors() {
+    let xml = r#"<record id="R-1"><place><original>A</original></place><place><original>B</original></place></record>"#;
+    let err = from_xml::<Record>(xml).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UnexpectedElement(name) if name == "place"));
+    assert_eq!(err.path(), ["record"]);
+
+    let xml =
+        r#"<record id="R-1"><place><original>A</original><original>B</original></place></record>"#;
+    let err = from_xml::<Record>(xml).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UnexpectedElement(name) if name == "original"));
+    assert_eq!(err.path(), ["record", "place"]);
+
+    let xml = r#"<doc xmlns="urn:test" id="D-1"><title>A</title><title>B</title></doc>"#;
+    let err = from_xml::<Extended>(xml).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UnexpectedElement(name) if name == "title"));
+}
+
+#[test]
+fn empty_elements_and_unknown_attributes() {
+    let record = from_xml::<Record>(r#"<record id="R-1" unknown="x"/>"#).unwrap();
+    assert_eq!(record.id, "R-1");
+    assert_eq!(record.common, Common::default());
+    assert_eq!(record.place, None);
+    assert_eq!(to_xml(&record), r#"<record xmlns="urn:test" id="R-1"/>"#);
+}
+
+#[derive(Debug, PartialEq, DeserializeXml, SerializeXml)]
+#[xml(rename = "doc", namespace = "urn:test")]
+struct Extended {
+    #[xml(flatten)]
+    common: Common,
+    #[xml(text_element)]
+    title: Option<String>,
+    #[xml(attribute)]
+    id: String,
+    #[xml(extensions)]
+    extensions: deserx::XmlExtensions,
+}
+
+#[test]
+fn unknown_fields_are_kept_as_extensions() {
+    let xml = r#"<doc xmlns="urn:test" xmlns:v="urn:vendor" id="D-1" v:rank="2"><note>n</note><v:tag key="a &amp; b"><v:inner>x</v:inner></v:tag><title>T</title><v:flag/></doc>"#;
+    let doc = from_xml::<Extended>(xml).unwrap();
+    assert_eq!(doc.common.notes.len(), 1);
+    assert_eq!(doc.title.as_deref(), Some("T"));
+    assert_eq!(doc.extensions.get_attribute("v:rank"), Some("2"));
+    assert_eq!(doc.extensions.get_attribute("xmlns"), None);
+    assert_eq!(
+        doc.extensions.elements(),
+        [
+            r#"<v:tag key="a &amp; b" xmlns:v="urn:vendor"><v:inner>x</v:inner></v:tag>"#,
+            r#"<v:flag xmlns:v="urn:vendor"/>"#
+        ]
+    );
+    assert_eq!(
+        to_xml(&doc),
+        r#"<doc xmlns="urn:test" id="D-1" xmlns:v="urn:vendor" v:rank="2"><note>n</note><title>T</title><v:tag key="a &amp; b" xmlns:v="urn:vendor"><v:inner>x</v:inner></v:tag><v:flag xmlns:v="urn:vendor"/></doc>"#
+    );
+    assert_eq!(from_xml::<Extended>(&to_xml(&doc)).unwrap(), doc);
+}
+
+#[test]
+fn prefixed_declarations_of_the_namespace_are_not_extensions() {
+    // The container writes its namespace as the default namespace itself, so
+    // a prefix for it is dropped; prefixes for other namespaces are kept.
+    let doc = from_xml::<Extended>(
+        r#"<t:doc xmlns:t="urn:test" xmlns:v="urn:vendor" id="D-2"><t:title>T</t:title></t:doc>"#,
+    )
+    .unwrap();
+    assert_eq!(doc.title.as_deref(), Some("T"));
+    assert_eq!(doc.extensions.get_attribute("xmlns:t"), None);
+    assert_eq!(doc.extensions.get_attribute("xmlns:v"), Some("urn:vendor"));
+    assert_eq!(
+        to_xml(&doc),
+        r#"<doc xmlns="urn:test" id="D-2" xmlns:v="urn:vendor"><title>T</title></doc>"#
+    );
+}
+
+#[test]
+fn children_in_other_namespaces_are_extensions() {
+    let xml = r#"<doc xmlns="urn:test" xmlns:v="urn:vendor" id="D-1"><v:title>V</v:title><title>T</title><v:note/><note xmlns="urn:other">o</note><n:note xmlns:n="urn:test">n</n:note></doc>"#;
+    let doc = from_xml::<Extended>(xml).unwrap();
+    assert_eq!(doc.title.as_deref(), Some("T"));
+    assert_eq!(doc.common.notes.len(), 1);
+    assert_eq!(doc.common.notes[0].text, "n");
+    assert_eq!(
+        doc.extensions.elements(),
+        [
+            r#"<v:title xmlns:v="urn:vendor">V</v:title>"#,
+            r#"<v:note xmlns:v="urn:vendor"/>"#,
+            r#"<note xmlns="urn:other">o</note>"#
+        ]
+    );
+
+    let err = from_xml::<Record>(
+        r#"<record xmlns:v="urn:vendor" id="R-1"><v:value>1</v:value></record>"#,
+    )
+    .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UnexpectedElement(name) if name == "v:value"));
+}
+
+#[test]
+fn unknown_elements_keep_the_default_namespace() {
+    let xml = r#"<t:doc xmlns:t="urn:test" xmlns="urn:other" id="D-1"><v:tag xmlns:v="urn:vendor"><plain/></v:tag><t:title>T</t:title></t:doc>"#;
+    let doc = from_xml::<Extended>(xml).unwrap();
+    assert_eq!(doc.title.as_deref(), Some("T"));
+    assert_eq!(
+        doc.extensions.elements(),
+        [r#"<v:tag xmlns:v="urn:vendor" xmlns="urn:other"><plain/></v:tag>"#]
+    );
+}